@@ -0,0 +1,82 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! WIT packages embedded in the binary, so that they do not have to be copied around
+
+/// A WIT package embedded in the binary
+pub struct BundledPackage {
+    /// The path the package is reported under in errors
+    pub path: &'static str,
+
+    /// The WIT source of the package
+    pub source: &'static str,
+
+    /// The interfaces of the package that are always generated once it is bundled,
+    /// the other ones being generated only when a world imports them
+    pub generated: &'static [&'static str],
+}
+
+/// `wasi:io@0.2.0`
+pub const WASI_IO: BundledPackage = BundledPackage {
+    path: "wit/wasi/io.wit",
+    source: include_str!("../wit/wasi/io.wit"),
+    generated: &[],
+};
+
+/// `wasi:clocks@0.2.0`
+pub const WASI_CLOCKS: BundledPackage = BundledPackage {
+    path: "wit/wasi/clocks.wit",
+    source: include_str!("../wit/wasi/clocks.wit"),
+    generated: &[],
+};
+
+/// `wasi:random@0.2.0`
+pub const WASI_RANDOM: BundledPackage = BundledPackage {
+    path: "wit/wasi/random.wit",
+    source: include_str!("../wit/wasi/random.wit"),
+    generated: &[],
+};
+
+/// `wasi:http@0.2.0`, with the `types` and `outgoing-handler` interfaces
+pub const WASI_HTTP: BundledPackage = BundledPackage {
+    path: "wit/wasi/http.wit",
+    source: include_str!("../wit/wasi/http.wit"),
+    generated: &[],
+};
+
+/// `golem:api@0.2.0`, the host API of Golem 1.0
+pub const GOLEM_API: BundledPackage = BundledPackage {
+    path: "wit/golem/api.wit",
+    source: include_str!("../wit/golem/api.wit"),
+    generated: &["host"],
+};
+
+/// `golem:rpc@0.1.0`, restricted to the types wasm-rpc stubs expose to clients
+pub const GOLEM_RPC: BundledPackage = BundledPackage {
+    path: "wit/golem/rpc.wit",
+    source: include_str!("../wit/golem/rpc.wit"),
+    generated: &["types"],
+};
+
+/// The Golem release targeted by the bundled Golem host API
+pub const GOLEM_RELEASE: &str = "1.0";
+
+/// The packages making up the Golem host API, dependencies first
+pub const GOLEM_HOST: &[&BundledPackage] = &[&WASI_IO, &WASI_CLOCKS, &GOLEM_API];
+
+/// The WASI 0.2 packages, dependencies first
+pub const WASI: &[&BundledPackage] = &[&WASI_IO, &WASI_CLOCKS, &WASI_RANDOM, &WASI_HTTP];
+
+/// The packages needed by clients of wasm-rpc stubs, dependencies first
+pub const GOLEM_RPC_CLIENT: &[&BundledPackage] = &[&WASI_IO, &GOLEM_RPC];
@@ -0,0 +1,149 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use color_eyre::Result;
+use id_arena::{Arena, Id};
+use wit_parser::{Type as WitType, TypeDef, TypeDefKind};
+
+use super::ast::{Def, Expr, Param, Stat, Template, TypeAlias};
+use super::docs::scaladoc;
+use super::{Encoding, Layer, Render};
+use crate::types::{Constructor, Type, TypeMap, TypeName};
+
+/// Represents a type alias in Scala
+pub struct Alias {
+    /// The alias name
+    name: TypeName,
+
+    /// The aliased Scala type
+    target: Type,
+
+    /// The Scaladoc of the alias
+    doc: Option<String>,
+
+    /// The implicit instances declared in the companion object, which is only generated if there are any
+    instances: Vec<Stat>,
+}
+
+impl Alias {
+    /// Constructs an `Alias` from WIT, if the type is one that is represented by an alias
+    pub fn from_wit(
+        name: TypeName,
+        id: Id<TypeDef>,
+        types: &Arena<TypeDef>,
+        type_map: &TypeMap,
+    ) -> Result<Option<Self>> {
+        let target = match &types[id].kind {
+            TypeDefKind::Type(ty) => Type::from_wit(Self::resolve_use(id, *ty, types), type_map)?,
+            kind => match Constructor::from_kind(kind, type_map) {
+                Some(constructor) => Type::Custom(TypeName::Constructor(constructor?)),
+                None => return Ok(None),
+            },
+        };
+
+        Ok(Some(Self {
+            name,
+            target,
+            doc: scaladoc(&types[id].docs),
+            instances: Vec::new(),
+        }))
+    }
+
+    /// The aliased type
+    pub fn target(&self) -> &Type {
+        &self.target
+    }
+
+    /// Declares the codec of the alias in its companion object, which is the one of the aliased type
+    pub fn codec(mut self, encoding: Encoding) -> Self {
+        let instance = self.instance(encoding);
+        self.instances.push(instance);
+        self
+    }
+
+    /// The implicit codec of the alias
+    pub fn instance(&self, encoding: Encoding) -> Stat {
+        encoding.instance(
+            &self.name.to_string(),
+            Expr::raw(encoding.codec(&self.target)),
+        )
+    }
+
+    /// The idiomatic counterpart of the alias, which aliases the idiomatic counterpart of the
+    /// aliased type, with an object converting it from and to the JS representation
+    pub fn idiomatic(&self, layer: &Layer) -> Vec<Stat> {
+        let name = self.name.to_string();
+        if !layer.converts(&name) {
+            return Vec::new();
+        }
+
+        let layer = layer.within(&name);
+
+        let js = layer.js_name(&name);
+
+        vec![
+            Stat::TypeAlias(
+                TypeAlias::new(&name)
+                    .doc(self.doc.clone())
+                    .rhs(layer.ty(&self.target)),
+            ),
+            Stat::Object(Template::new(&name).body(vec![
+                Stat::Def(
+                    Def::new("toJS")
+                        .doc(Some(format!("Converts `{name}` to its JS representation")))
+                        .params(vec![Param::new("value", &name)])
+                        .returns(&js)
+                        .body(Expr::raw(layer.to_js(&self.target, "value"))),
+                ),
+                Stat::Def(
+                    Def::new("fromJS")
+                        .doc(Some(format!("Converts the JS representation of `{name}`")))
+                        .params(vec![Param::new("value", &js)])
+                        .returns(&name)
+                        .body(Expr::raw(layer.to_idiomatic(&self.target, "value"))),
+                ),
+            ])),
+        ]
+    }
+
+    /// Follows the types brought in by `use` from other interfaces, which are not generated,
+    /// so that `use foo.{the-type}` does not become `type TheType = TheType`
+    fn resolve_use(id: Id<TypeDef>, mut ty: WitType, types: &Arena<TypeDef>) -> WitType {
+        while let WitType::Id(target) = ty {
+            match types[target].kind {
+                TypeDefKind::Type(inner) if types[target].owner != types[id].owner => ty = inner,
+                _ => break,
+            }
+        }
+
+        ty
+    }
+}
+
+impl Render for Alias {
+    fn render(self) -> Result<Vec<Stat>> {
+        let name = self.name.to_string();
+        let mut stats = vec![Stat::TypeAlias(
+            TypeAlias::new(&name)
+                .doc(self.doc)
+                .rhs(self.target.to_string()),
+        )];
+
+        if !self.instances.is_empty() {
+            stats.push(Stat::Object(Template::new(name).body(self.instances)));
+        }
+
+        Ok(stats)
+    }
+}
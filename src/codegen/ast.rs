@@ -0,0 +1,491 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A small syntax tree covering the subset of Scala emitted by the generator.
+//! Types are kept as plain strings, since they are always rendered from `Type`.
+
+/// Represents a Scala source file
+pub struct File {
+    /// The comment at the top of the file
+    pub header: Option<String>,
+
+    /// The package of the file
+    pub package: String,
+
+    /// The imported paths, without the `import` keyword
+    pub imports: Vec<String>,
+
+    /// The members of the package object, which is only generated if there are any,
+    /// since Scala only allows type aliases there rather than at the top level
+    pub package_object: Vec<Stat>,
+
+    /// The top-level statements of the file
+    pub stats: Vec<Stat>,
+}
+
+/// Represents a Scala statement (a definition or an expression)
+pub enum Stat {
+    Class(Template),
+    Trait(Template),
+    Object(Template),
+    Def(Def),
+    Val(Val),
+    Var(Val),
+    TypeAlias(TypeAlias),
+    Expr(Expr),
+}
+
+/// Represents a Scala expression
+pub enum Expr {
+    /// An expression that fits on a single line, such as `a + b` or `foo(bar)`
+    Raw(String),
+
+    /// A block `{ ... }` whose value is its last statement
+    Block(Vec<Stat>),
+
+    /// An instance of an anonymous class, `new A with B { ... }`
+    New {
+        parents: Vec<String>,
+        body: Vec<Stat>,
+    },
+
+    /// A pattern match, `scrutinee match { case pattern => expr }`
+    Match {
+        scrutinee: String,
+        cases: Vec<(String, Expr)>,
+    },
+
+    /// A call `fun(args)`, or `fun { ... }` when the only arg is a lambda in braces
+    Call { fun: Box<Expr>, args: Vec<Expr> },
+
+    /// A member of the value of an expression, `qualifier.name`, the name possibly
+    /// selecting several members in a row
+    Select { qualifier: Box<Expr>, name: String },
+
+    /// A lambda `params => body`, or `{ params => body }` in braces, which the params
+    /// of pattern-matching lambdas such as `case (a, b)` require
+    Lambda {
+        params: String,
+        body: Box<Expr>,
+        braces: bool,
+    },
+
+    /// Operands joined by an infix operator, `a && b && c`, which are not parenthesized
+    Infix { op: String, operands: Vec<Expr> },
+
+    /// A conditional, `if (cond) then else otherwise`
+    If {
+        cond: Box<Expr>,
+        then: Box<Expr>,
+        otherwise: Box<Expr>,
+    },
+
+    /// A type ascription, `expr: ty`, such as `values: _*`
+    Ascribe { expr: Box<Expr>, ty: String },
+}
+
+impl Expr {
+    /// Creates a single-line expression, which the printer never breaks
+    pub fn raw(expr: impl Into<String>) -> Self {
+        Self::Raw(expr.into())
+    }
+
+    /// Calls this with `args`
+    pub fn call(self, args: Vec<Expr>) -> Self {
+        Self::Call {
+            fun: Box::new(self),
+            args,
+        }
+    }
+
+    /// Creates a tuple, `(items)`, which is printed as a call without a function
+    pub fn tuple(items: Vec<Expr>) -> Self {
+        Self::raw("").call(items)
+    }
+
+    /// Selects the member `name` of this
+    pub fn select(self, name: impl Into<String>) -> Self {
+        Self::Select {
+            qualifier: Box::new(self),
+            name: name.into(),
+        }
+    }
+
+    /// Calls the method `name` of this with `args`
+    pub fn method(self, name: impl Into<String>, args: Vec<Expr>) -> Self {
+        self.select(name).call(args)
+    }
+
+    /// Creates a lambda, `params => body`
+    pub fn lambda(params: impl Into<String>, body: Expr) -> Self {
+        Self::Lambda {
+            params: params.into(),
+            body: Box::new(body),
+            braces: false,
+        }
+    }
+
+    /// Creates a lambda in braces, `{ params => body }`
+    pub fn braced(params: impl Into<String>, body: Expr) -> Self {
+        Self::Lambda {
+            params: params.into(),
+            body: Box::new(body),
+            braces: true,
+        }
+    }
+
+    /// Joins `operands` with the infix operator `op`
+    pub fn infix(op: impl Into<String>, operands: Vec<Expr>) -> Self {
+        Self::Infix {
+            op: op.into(),
+            operands,
+        }
+    }
+
+    /// Creates a conditional, `if (cond) then else otherwise`
+    pub fn if_else(cond: Expr, then: Expr, otherwise: Expr) -> Self {
+        Self::If {
+            cond: Box::new(cond),
+            then: Box::new(then),
+            otherwise: Box::new(otherwise),
+        }
+    }
+
+    /// Ascribes the type `ty` to this
+    pub fn ascribe(self, ty: impl Into<String>) -> Self {
+        Self::Ascribe {
+            expr: Box::new(self),
+            ty: ty.into(),
+        }
+    }
+}
+
+/// Represents a trait, an object or a class
+pub struct Template {
+    /// The Scaladoc of the item, without the comment delimiters
+    pub doc: Option<String>,
+
+    /// The annotations, such as `@JSExportTopLevel("foo")`
+    pub annotations: Vec<String>,
+
+    /// The modifiers, such as `sealed` or `final case`
+    pub modifiers: Vec<String>,
+
+    /// The name of the template
+    pub name: String,
+
+    /// The type params, such as `+A`
+    pub tparams: Vec<String>,
+
+    /// The constructor params, only relevant to classes
+    pub params: Option<Vec<Param>>,
+
+    /// The parents, the first one being extended and the others mixed in
+    pub parents: Vec<String>,
+
+    /// The name given to `this` in the body, as in `{ self => ... }`
+    pub self_alias: Option<String>,
+
+    /// The members of the template
+    pub body: Vec<Stat>,
+}
+
+impl Template {
+    /// Creates a template with no parents and no members
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            doc: None,
+            annotations: Vec::new(),
+            modifiers: Vec::new(),
+            name: name.into(),
+            tparams: Vec::new(),
+            params: None,
+            parents: Vec::new(),
+            self_alias: None,
+            body: Vec::new(),
+        }
+    }
+
+    /// Sets the Scaladoc
+    pub fn doc(mut self, doc: Option<String>) -> Self {
+        self.doc = doc;
+        self
+    }
+
+    /// Adds an annotation
+    pub fn annotation(mut self, annotation: impl Into<String>) -> Self {
+        self.annotations.push(annotation.into());
+        self
+    }
+
+    /// Adds a modifier
+    pub fn modifier(mut self, modifier: impl Into<String>) -> Self {
+        self.modifiers.push(modifier.into());
+        self
+    }
+
+    /// Sets the type params
+    pub fn tparams(mut self, tparams: Vec<String>) -> Self {
+        self.tparams = tparams;
+        self
+    }
+
+    /// Sets the constructor params
+    pub fn params(mut self, params: Vec<Param>) -> Self {
+        self.params = Some(params);
+        self
+    }
+
+    /// Adds a parent
+    pub fn extends(mut self, parent: impl Into<String>) -> Self {
+        self.parents.push(parent.into());
+        self
+    }
+
+    /// Sets the name given to `this` in the body
+    pub fn self_alias(mut self, self_alias: impl Into<String>) -> Self {
+        self.self_alias = Some(self_alias.into());
+        self
+    }
+
+    /// Sets the members
+    pub fn body(mut self, body: Vec<Stat>) -> Self {
+        self.body = body;
+        self
+    }
+}
+
+/// Represents a method definition or declaration
+pub struct Def {
+    /// The Scaladoc of the item, without the comment delimiters
+    pub doc: Option<String>,
+
+    /// The annotations, such as `@JSName("foo")`
+    pub annotations: Vec<String>,
+
+    /// The modifiers, such as `override`
+    pub modifiers: Vec<String>,
+
+    /// The name of the method
+    pub name: String,
+
+    /// The type params, such as `A`
+    pub tparams: Vec<String>,
+
+    /// The param lists, where no list means no parentheses at all
+    pub params: Vec<Vec<Param>>,
+
+    /// The result type, inferred when missing
+    pub ret: Option<String>,
+
+    /// The body of the method, abstract when missing
+    pub body: Option<Expr>,
+}
+
+impl Def {
+    /// Creates an abstract method with no params
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            doc: None,
+            annotations: Vec::new(),
+            modifiers: Vec::new(),
+            name: name.into(),
+            tparams: Vec::new(),
+            params: Vec::new(),
+            ret: None,
+            body: None,
+        }
+    }
+
+    /// Sets the Scaladoc
+    pub fn doc(mut self, doc: Option<String>) -> Self {
+        self.doc = doc;
+        self
+    }
+
+    /// Adds an annotation
+    pub fn annotation(mut self, annotation: impl Into<String>) -> Self {
+        self.annotations.push(annotation.into());
+        self
+    }
+
+    /// Adds a modifier
+    pub fn modifier(mut self, modifier: impl Into<String>) -> Self {
+        self.modifiers.push(modifier.into());
+        self
+    }
+
+    /// Sets the type params
+    pub fn tparams(mut self, tparams: Vec<String>) -> Self {
+        self.tparams = tparams;
+        self
+    }
+
+    /// Adds a param list
+    pub fn params(mut self, params: Vec<Param>) -> Self {
+        self.params.push(params);
+        self
+    }
+
+    /// Sets the result type
+    pub fn returns(mut self, ret: impl Into<String>) -> Self {
+        self.ret = Some(ret.into());
+        self
+    }
+
+    /// Sets the body
+    pub fn body(mut self, body: Expr) -> Self {
+        self.body = Some(body);
+        self
+    }
+}
+
+/// Represents a value definition or declaration
+pub struct Val {
+    /// The Scaladoc of the item, without the comment delimiters
+    pub doc: Option<String>,
+
+    /// The annotations, such as `@JSName("foo")`
+    pub annotations: Vec<String>,
+
+    /// The modifiers, such as `override` or `lazy`
+    pub modifiers: Vec<String>,
+
+    /// The name of the value
+    pub name: String,
+
+    /// The type of the value, inferred when missing
+    pub ty: Option<String>,
+
+    /// The value itself, abstract when missing
+    pub rhs: Option<Expr>,
+}
+
+impl Val {
+    /// Creates an abstract value with no type
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            doc: None,
+            annotations: Vec::new(),
+            modifiers: Vec::new(),
+            name: name.into(),
+            ty: None,
+            rhs: None,
+        }
+    }
+
+    /// Sets the Scaladoc
+    pub fn doc(mut self, doc: Option<String>) -> Self {
+        self.doc = doc;
+        self
+    }
+
+    /// Adds an annotation
+    pub fn annotation(mut self, annotation: impl Into<String>) -> Self {
+        self.annotations.push(annotation.into());
+        self
+    }
+
+    /// Adds a modifier
+    pub fn modifier(mut self, modifier: impl Into<String>) -> Self {
+        self.modifiers.push(modifier.into());
+        self
+    }
+
+    /// Sets the type
+    pub fn ty(mut self, ty: impl Into<String>) -> Self {
+        self.ty = Some(ty.into());
+        self
+    }
+
+    /// Sets the value
+    pub fn rhs(mut self, rhs: Expr) -> Self {
+        self.rhs = Some(rhs);
+        self
+    }
+}
+
+/// Represents a type alias or an abstract type member
+pub struct TypeAlias {
+    /// The Scaladoc of the item, without the comment delimiters
+    pub doc: Option<String>,
+
+    /// The name of the type
+    pub name: String,
+
+    /// The type params, such as `+A`
+    pub tparams: Vec<String>,
+
+    /// The aliased type, abstract when missing
+    pub rhs: Option<String>,
+}
+
+impl TypeAlias {
+    /// Creates an abstract type member
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            doc: None,
+            name: name.into(),
+            tparams: Vec::new(),
+            rhs: None,
+        }
+    }
+
+    /// Sets the Scaladoc
+    pub fn doc(mut self, doc: Option<String>) -> Self {
+        self.doc = doc;
+        self
+    }
+
+    /// Sets the type params
+    pub fn tparams(mut self, tparams: Vec<String>) -> Self {
+        self.tparams = tparams;
+        self
+    }
+
+    /// Sets the aliased type
+    pub fn rhs(mut self, rhs: impl Into<String>) -> Self {
+        self.rhs = Some(rhs.into());
+        self
+    }
+}
+
+/// Represents a param of a method or of a class
+pub struct Param {
+    /// The name of the param
+    pub name: String,
+
+    /// The type of the param
+    pub ty: String,
+
+    /// The default value of the param
+    pub default: Option<String>,
+}
+
+impl Param {
+    /// Creates a param with no default value
+    pub fn new(name: impl Into<String>, ty: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ty: ty.into(),
+            default: None,
+        }
+    }
+
+    /// Sets the default value
+    pub fn default(mut self, default: impl Into<String>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+}
@@ -0,0 +1,423 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::{eyre::eyre, Result, Section};
+use id_arena::Id;
+use wit_parser::{Interface as WitInterface, PackageId, PackageName, Resolve, WorldItem};
+
+use super::ast::File;
+use super::rest::Codecs;
+use super::{golem, scalacheck, structural, Encoding, Interface, Printer};
+use crate::generator::Options;
+use crate::naming::{Collisions, Locator};
+use crate::types::TypeMap;
+
+/// The interface implemented by the component, which is always generated
+const MAIN_INTERFACE: &str = "api";
+
+/// What the generated code does with the interfaces of the package
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum Target {
+    /// The bindings of the component implementing the main interface
+    #[default]
+    Component,
+
+    /// Clients calling the interfaces exported by the worlds of the package on other workers,
+    /// through the stubs generated by wasm-rpc
+    RpcClient,
+
+    /// A client invoking the main interface on a worker through the Golem REST API
+    RestClient,
+}
+
+/// The Scala files generated for a WIT package
+pub struct Sources {
+    /// The bindings, compiled with the main sources
+    pub main: String,
+
+    /// The generators, properties and test doubles compiled with the test sources,
+    /// if the options ask for any
+    pub test: Option<String>,
+}
+
+/// Represents the Scala.js bindings of a WIT package
+pub struct Bindings {
+    /// The interfaces to generate, the main one coming first
+    interfaces: Vec<Interface>,
+
+    /// The names the idiomatic wrappers around the Golem host API refer to, when they are generated
+    golem_host: Option<golem::Host>,
+
+    /// What the generated code does with the interfaces
+    target: Target,
+
+    /// The encodings the generated types have codecs for
+    encodings: Vec<Encoding>,
+
+    /// The name of the WIT package, such as `shop` for `golem:shop`
+    name: String,
+
+    /// The world exporting the main interface, if any
+    world: Option<String>,
+}
+
+impl Bindings {
+    /// Constructs the `Bindings` of a WIT package, which are the main interface, a facade for every
+    /// interface imported by a world of the package, and a facade for every `bundled` interface.
+    /// As an RPC client, the interfaces exported by the worlds of the package are called on other
+    /// workers instead, with facades for the interfaces they depend on.
+    /// Each package is looked up in its own locator, falling back to the one of the main package.
+    pub fn from_wit(
+        resolve: &Resolve,
+        package_id: PackageId,
+        bundled: &[Id<WitInterface>],
+        locators: &HashMap<PackageId, Locator>,
+        collisions: &Collisions,
+        options: &Options,
+    ) -> Result<Self> {
+        let package = &resolve.packages[package_id];
+        let worlds: Vec<_> = package
+            .worlds
+            .values()
+            .map(|world| &resolve.worlds[*world])
+            .collect();
+
+        let mut exports = Vec::new();
+        for world in &worlds {
+            for item in world.exports.values() {
+                match item {
+                    WorldItem::Interface { id, .. } if resolve.interfaces[*id].name.is_some() => {
+                        exports.push((*id, world.name.as_str()))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let roots: Vec<Id<WitInterface>> = if options.target == Target::RpcClient {
+            if exports.is_empty() {
+                return Err(eyre!("No world of the package exports an interface"))
+                    .with_suggestion(|| "Export the interfaces to call from a world");
+            }
+
+            exports.iter().map(|(id, _)| *id).collect()
+        } else {
+            vec![*package
+                .interfaces
+                .get(MAIN_INTERFACE)
+                .ok_or(eyre!("Interface {MAIN_INTERFACE} not found"))?]
+        };
+
+        // Worlds are elaborated, so that they import the interfaces their interfaces depend on
+        let mut imported = Vec::new();
+        let dependencies: Vec<Id<WitInterface>> = if options.target == Target::RpcClient {
+            roots
+                .iter()
+                .flat_map(|id| resolve.interface_direct_deps(*id))
+                .collect()
+        } else {
+            worlds
+                .iter()
+                .flat_map(|world| world.imports.values())
+                .filter_map(|item| match item {
+                    WorldItem::Interface { id, .. } => Some(*id),
+                    _ => None,
+                })
+                .collect()
+        };
+        for id in dependencies.iter().chain(bundled) {
+            Self::import(*id, &roots, resolve, &mut imported);
+        }
+
+        let generated: HashSet<_> = imported.iter().chain(&roots).copied().collect();
+
+        // Map types are looked up by their WIT name among the generated interfaces
+        let mut map_types: Vec<_> = options.map_types.keys().collect();
+        map_types.sort();
+        if let Some(unknown) = map_types.into_iter().find(|name| {
+            !generated
+                .iter()
+                .any(|id| resolve.interfaces[*id].types.contains_key(name.as_str()))
+        }) {
+            return Err(eyre!(
+                "Map type {unknown} is not declared by any generated interface"
+            ))
+            .with_suggestion(|| "Give the WIT name of an alias of list<tuple<string, V>>");
+        }
+        let type_map = TypeMap::new(resolve, collisions, &roots, &generated)?;
+
+        let interface = |id: Id<WitInterface>| {
+            let locator = resolve.interfaces[id]
+                .package
+                .and_then(|package| locators.get(&package))
+                .or_else(|| locators.get(&package_id))
+                .ok_or(eyre!("No locator for the package of interface {id:?}"))?;
+
+            Interface::from_wit(
+                resolve, id, &type_map, collisions, locator, options, &generated,
+            )
+        };
+
+        let mut interfaces = Vec::new();
+        for id in &roots {
+            let root = interface(*id)?;
+            let export = exports.iter().find(|(export, _)| export == id);
+            let world = export.map(|(_, world)| *world);
+
+            interfaces.push(match export {
+                Some((_, world)) if options.target == Target::RpcClient => {
+                    let specifier =
+                        Self::path(&options.import_specifier, resolve, *id, Some(world))?;
+                    root.rpc_client(specifier)
+                }
+                _ if options.target == Target::RestClient => {
+                    let path = Self::id_of(resolve, *id, world)?;
+                    let codecs = Codecs::from_wit(resolve, *id, &type_map, path)?;
+                    root.rest_client(codecs)
+                }
+                Some(_) => root.exported(Self::id_of(resolve, *id, world)?),
+                None => root,
+            });
+        }
+
+        for id in imported {
+            let specifier = Self::path(&options.import_specifier, resolve, id, None)?;
+            interfaces.push(interface(id)?.imported(specifier));
+        }
+
+        // Types are checked with the validators declared by any interface of the file
+        let validated: HashSet<String> = interfaces
+            .iter()
+            .flat_map(Interface::validated_types)
+            .cloned()
+            .collect();
+        for interface in &mut interfaces {
+            interface.validate_with(&validated);
+        }
+
+        let golem_host = options
+            .golem_host
+            .then(|| golem::Host::from_wit(resolve, &generated, &type_map))
+            .transpose()?;
+
+        let world = package.interfaces.get(MAIN_INTERFACE).and_then(|main| {
+            exports
+                .iter()
+                .find(|(id, _)| id == main)
+                .map(|(_, world)| (*world).to_owned())
+        });
+
+        Ok(Self {
+            interfaces,
+            golem_host,
+            target: options.target,
+            encodings: Encoding::enabled(options),
+            name: package.name.name.clone(),
+            world,
+        })
+    }
+
+    /// The path of an interface, such as `golem:shop/api@1.0.0`, given the world exporting it if any
+    fn id_of(resolve: &Resolve, id: Id<WitInterface>, world: Option<&str>) -> Result<String> {
+        resolve.id_of(id).ok_or_else(|| {
+            let name = resolve.interfaces[id].name.as_deref().unwrap_or_default();
+            match world {
+                Some(world) => eyre!(
+                    "Unknown package of interface `{name}` exported by world `{world}`, so it has no path"
+                ),
+                None => eyre!("Unknown package of interface `{name}`, so it has no path"),
+            }
+        })
+    }
+
+    /// Adds a named interface other than the generated `roots` to the imported ones,
+    /// after the interfaces it depends on
+    fn import(
+        id: Id<WitInterface>,
+        roots: &[Id<WitInterface>],
+        resolve: &Resolve,
+        imported: &mut Vec<Id<WitInterface>>,
+    ) {
+        if roots.contains(&id) || imported.contains(&id) || resolve.interfaces[id].name.is_none() {
+            return;
+        }
+
+        for dependency in resolve.interface_direct_deps(id) {
+            Self::import(dependency, roots, resolve, imported);
+        }
+
+        imported.push(id);
+    }
+
+    /// Fills in the `{namespace}`, `{package}`, `{interface}` and `{version}` placeholders
+    /// of a path format for an interface, dropping `@{version}` for unversioned packages.
+    /// With the name of the world exporting it, the path is the one of the wasm-rpc stub
+    /// of the world, `<namespace>:<package>-stub/stub-<world>`.
+    fn path(
+        format: &str,
+        resolve: &Resolve,
+        id: Id<WitInterface>,
+        stub: Option<&str>,
+    ) -> Result<String> {
+        let interface = &resolve.interfaces[id];
+        let PackageName {
+            namespace,
+            name,
+            version,
+        } = &resolve.packages[interface.package.ok_or_else(|| {
+            eyre!(
+                "Unknown package of interface `{}`",
+                interface.name.as_deref().unwrap_or_default()
+            )
+        })?]
+        .name;
+
+        let version = version
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+
+        let format = if version.is_empty() {
+            format.replace("@{version}", "")
+        } else {
+            format.to_owned()
+        };
+
+        let (name, interface) = match stub {
+            Some(world) => (format!("{name}-stub"), format!("stub-{world}")),
+            None => (name.clone(), interface.name.clone().unwrap_or_default()),
+        };
+
+        Ok(format
+            .replace("{namespace}", namespace)
+            .replace("{package}", &name)
+            .replace("{interface}", &interface)
+            .replace("{version}", &version))
+    }
+
+    /// The name of the WIT package, such as `shop` for `golem:shop`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The world exporting the main interface, if any
+    pub fn world(&self) -> Option<&str> {
+        self.world.as_deref()
+    }
+
+    /// Renders the starting point of the implementation of the exported interfaces,
+    /// which is meant to be edited, unlike the bindings
+    pub fn skeleton(&self, package: &str, printer: &Printer) -> Result<String> {
+        let stats: Vec<_> = self
+            .interfaces
+            .iter()
+            .flat_map(Interface::skeleton)
+            .collect();
+
+        if stats.is_empty() {
+            return Err(eyre!(
+                "No world of the package exports interface {MAIN_INTERFACE}"
+            ))
+            .with_suggestion(|| format!("Export interface {MAIN_INTERFACE} from a world"));
+        }
+
+        Ok(printer.print(&File {
+            header: None,
+            package: package.to_owned(),
+            imports: self.imports(),
+            package_object: Vec::new(),
+            stats,
+        }))
+    }
+
+    /// The imports of the generated files
+    fn imports(&self) -> Vec<String> {
+        let mut imports = Vec::new();
+        if self.target == Target::RestClient {
+            imports.push("scala.concurrent.{ExecutionContext, Future}".to_owned());
+        }
+        imports.extend([
+            "scala.scalajs.js".to_owned(),
+            "scala.scalajs.js.JSConverters._".to_owned(),
+            "scala.scalajs.js.annotation._".to_owned(),
+        ]);
+
+        imports
+    }
+
+    /// Renders this to the main sources and the test sources
+    pub fn render(self, package: &str, printer: &Printer) -> Result<Sources> {
+        let imports = self.imports();
+        let structural = self.interfaces.iter().any(Interface::has_structural_types);
+        let mut package_object = Interface::helpers();
+        let mut stats = Vec::new();
+        let mut test_instances = Vec::new();
+        let mut test_stats = Vec::new();
+        let mut encoded = Vec::new();
+        for interface in self.interfaces {
+            encoded.extend(interface.encoded_types().iter().cloned());
+            let rendered = interface.render()?;
+            package_object.extend(rendered.aliases);
+            stats.extend(rendered.stats);
+            test_instances.extend(rendered.test_instances);
+            test_stats.extend(rendered.test_stats);
+        }
+
+        if let Some(host) = &self.golem_host {
+            stats.extend(golem::wrappers(host));
+        }
+
+        let validated_arbitraries = self.encodings.contains(&Encoding::ScalaCheck)
+            && self.encodings.contains(&Encoding::Validation);
+        for encoding in self.encodings {
+            if encoding.test_scope() {
+                test_stats.extend(encoding.definitions(std::mem::take(&mut test_instances)));
+            } else {
+                stats.extend(encoding.definitions(Vec::new()));
+            }
+        }
+
+        if validated_arbitraries {
+            test_stats.extend(scalacheck::properties(&encoded));
+        }
+
+        if structural {
+            stats.extend(structural::runtime());
+        }
+
+        let header = Some("Generated by golem-scalajs-wit-bindgen".to_owned());
+        let test = (!test_stats.is_empty()).then(|| {
+            printer.print(&File {
+                header: header.clone(),
+                package: package.to_owned(),
+                imports: imports.clone(),
+                package_object: Vec::new(),
+                stats: test_stats,
+            })
+        });
+
+        Ok(Sources {
+            main: printer.print(&File {
+                header,
+                package: package.to_owned(),
+                imports,
+                package_object,
+                stats,
+            }),
+            test,
+        })
+    }
+}
@@ -0,0 +1,369 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ast::{Def, Expr, Param, Stat, Template, Val};
+
+/// The encoding of the WIT types in JSON, documenting the generated object
+const ENCODING: &str = "The circe codecs of the WIT types.
+
+Records are objects keyed by the WIT names of their fields, variants are objects with the WIT name
+of the case in `tag` and its payload, if any, in `val`, enums are the WIT names of their cases and
+flags are arrays of the WIT names of the flags that are set. Options are `null` when absent, which
+record fields may also be, results are objects with either an `ok` or an `err` member, errors being
+thrown as with their JS representation when decoded, tuples are arrays and chars are strings.";
+
+/// The `apply` methods of a codec of `ty`, which encode `value` and decode `cursor`
+fn codec(ty: &str, encode: Expr, decode: Expr) -> Vec<Stat> {
+    vec![
+        Stat::Def(
+            Def::new("apply")
+                .params(vec![Param::new("value", ty)])
+                .returns("Json")
+                .body(encode),
+        ),
+        Stat::Def(
+            Def::new("apply")
+                .params(vec![Param::new("cursor", "HCursor")])
+                .returns(format!("Decoder.Result[{ty}]"))
+                .body(decode),
+        ),
+    ]
+}
+
+/// A method of the `CirceCodecs` object returning a codec
+fn combinator(
+    doc: &str,
+    name: &str,
+    tparams: &[&str],
+    params: Vec<Param>,
+    ty: &str,
+    body: Vec<Stat>,
+) -> Stat {
+    Stat::Def(
+        Def::new(name)
+            .doc(Some(doc.to_owned()))
+            .tparams(tparams.iter().map(|tparam| (*tparam).to_owned()).collect())
+            .params(params)
+            .returns(format!("Codec[{ty}]"))
+            .body(Expr::New {
+                parents: vec![format!("Codec[{ty}]")],
+                body,
+            }),
+    )
+}
+
+/// A value of the `CirceCodecs` object that is the codec of a primitive
+fn primitive(name: &str, ty: &str, decoder: &str, encoder: &str) -> Stat {
+    Stat::Val(
+        Val::new(name)
+            .doc(Some(format!("The codec of `{name}`")))
+            .ty(format!("Codec[{ty}]"))
+            .rhs(Expr::raw(format!("Codec.from({decoder}, {encoder})"))),
+    )
+}
+
+/// The `CirceCodecs` object, which holds the codecs of the WIT primitives
+/// and the combinators building the codecs of the other WIT types
+pub fn runtime() -> Vec<Stat> {
+    let doc = |doc: &str| Some(doc.to_owned());
+
+    let mut stats = vec![
+        Stat::Expr(Expr::raw(
+            "import io.circe.{ACursor, Codec, Decoder, DecodingFailure, Encoder, HCursor, Json}",
+        )),
+        Stat::Class(
+            Template::new("Field")
+                .doc(doc(
+                    "A record field, with its JS name, its WIT name and the codec of its value",
+                ))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("name", "String"),
+                    Param::new("label", "String"),
+                    Param::new("codec", "Codec[_]"),
+                ]),
+        ),
+        Stat::Class(
+            Template::new("Case")
+                .doc(doc(
+                    "A variant case, with its WIT name and the codec of its payload",
+                ))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("label", "String"),
+                    Param::new("payload", "Option[Codec[_]]"),
+                ]),
+        ),
+        Stat::Class(
+            Template::new("Flag")
+                .doc(doc("A flag, with its JS name and its WIT name"))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("name", "String"),
+                    Param::new("label", "String"),
+                ]),
+        ),
+        Stat::Def(
+            Def::new("sequence")
+                .modifier("private")
+                .params(vec![Param::new("results", "Seq[Decoder.Result[Any]]")])
+                .returns("Decoder.Result[List[Any]]")
+                .body(Expr::raw(
+                    "results.foldRight[Decoder.Result[List[Any]]](Right(Nil))((result, rest) => result.flatMap(head => rest.map(head :: _)))",
+                )),
+        ),
+        Stat::Def(
+            Def::new("unsigned")
+                .modifier("private")
+                .params(vec![Param::new("n", "Long"), Param::new("max", "Long")])
+                .returns("Either[String, Long]")
+                .body(Expr::raw(
+                    "if (n >= 0 && n <= max) Right(n) else Left(s\"$n is out of range\")",
+                )),
+        ),
+        primitive(
+            "bool",
+            "Boolean",
+            "Decoder.decodeBoolean",
+            "Encoder.encodeBoolean",
+        ),
+        primitive("s8", "Byte", "Decoder.decodeByte", "Encoder.encodeByte"),
+        primitive(
+            "u8",
+            "Byte",
+            "Decoder.decodeLong.emap(unsigned(_, 0xffL)).map(_.toByte)",
+            "Encoder.instance[Byte](value => Json.fromInt(value & 0xff))",
+        ),
+        primitive("s16", "Short", "Decoder.decodeShort", "Encoder.encodeShort"),
+        primitive(
+            "u16",
+            "Short",
+            "Decoder.decodeLong.emap(unsigned(_, 0xffffL)).map(_.toShort)",
+            "Encoder.instance[Short](value => Json.fromInt(value & 0xffff))",
+        ),
+        primitive(
+            "s32",
+            "Integer",
+            "Decoder.decodeJavaInteger",
+            "Encoder.encodeJavaInteger",
+        ),
+        primitive(
+            "u32",
+            "Integer",
+            "Decoder.decodeLong.emap(unsigned(_, 0xffffffffL)).map(n => Integer.valueOf(n.toInt))",
+            "Encoder.instance[Integer](value => Json.fromLong(Integer.toUnsignedLong(value)))",
+        ),
+        primitive("s64", "Long", "Decoder.decodeLong", "Encoder.encodeLong"),
+        primitive(
+            "u64",
+            "Long",
+            "Decoder.decodeBigInt.emap(n => if (n >= 0 && n.bitLength <= 64) Right(n.longValue) else Left(s\"$n is out of range\"))",
+            "Encoder.instance[Long](value => Json.fromBigInt(BigInt(java.lang.Long.toUnsignedString(value))))",
+        ),
+        primitive("f32", "Float", "Decoder.decodeFloat", "Encoder.encodeFloat"),
+        primitive("f64", "Double", "Decoder.decodeDouble", "Encoder.encodeDouble"),
+        primitive("char", "Char", "Decoder.decodeChar", "Encoder.encodeChar"),
+        primitive(
+            "string",
+            "String",
+            "Decoder.decodeString",
+            "Encoder.encodeString",
+        ),
+    ];
+
+    stats.extend([
+        combinator(
+            "The codec of `list<T>`, given the codec of `T`",
+            "list",
+            &["A"],
+            vec![Param::new("item", "Codec[A]")],
+            "js.Array[A]",
+            codec(
+                "js.Array[A]",
+                Expr::raw("Json.fromValues(value.toList.map(element => item(element)))"),
+                Expr::raw("cursor.as(Decoder.decodeList(item)).map(_.toJSArray)"),
+            ),
+        ),
+        Stat::Class(
+            Template::new("OptionCodec")
+                .doc(doc(
+                    "The codec of `option<T>`, record fields of which may be left out",
+                ))
+                .modifier("private final")
+                .tparams(vec!["A".to_owned()])
+                .params(vec![Param::new("some", "Codec[A]")])
+                .extends("Codec[js.UndefOr[A]]")
+                .body(
+                    codec(
+                        "js.UndefOr[A]",
+                        Expr::raw("value.fold(Json.Null)(present => some(present))"),
+                        Expr::raw(
+                            "if (cursor.value.isNull) Right(js.undefined) else some(cursor).map(value => value: js.UndefOr[A])",
+                        ),
+                    )
+                    .into_iter()
+                    .chain([Stat::Def(
+                        Def::new("tryDecode")
+                            .modifier("override")
+                            .params(vec![Param::new("cursor", "ACursor")])
+                            .returns("Decoder.Result[js.UndefOr[A]]")
+                            .body(Expr::Match {
+                                scrutinee: "cursor".to_owned(),
+                                cases: vec![
+                                    ("cursor: HCursor".to_owned(), Expr::raw("apply(cursor)")),
+                                    ("_".to_owned(), Expr::raw("Right(js.undefined)")),
+                                ],
+                            }),
+                    )])
+                    .collect(),
+                ),
+        ),
+        Stat::Def(
+            Def::new("option")
+                .doc(doc("The codec of `option<T>`, given the codec of `T`"))
+                .tparams(vec!["A".to_owned()])
+                .params(vec![Param::new("some", "Codec[A]")])
+                .returns("Codec[js.UndefOr[A]]")
+                .body(Expr::raw("new OptionCodec(some)")),
+        ),
+        combinator(
+            "The codec of `result<T, E>`, given the optional codecs of `T` and `E`",
+            "result",
+            &["A", "E"],
+            vec![
+                Param::new("ok", "Option[Codec[A]]"),
+                Param::new("err", "Option[Codec[E]]"),
+            ],
+            "A",
+            codec(
+                "A",
+                Expr::raw("Json.obj(\"ok\" -> ok.fold(Json.Null)(codec => codec(value)))"),
+                Expr::Match {
+                    scrutinee: "cursor.downField(\"err\").success".to_owned(),
+                    cases: vec![
+                        (
+                            "Some(error)".to_owned(),
+                            Expr::raw(
+                                "err.fold[Decoder.Result[Any]](Right(js.undefined))(error.as(_)).flatMap(error => throw js.JavaScriptException(error))",
+                            ),
+                        ),
+                        (
+                            "None".to_owned(),
+                            Expr::raw(
+                                "ok.fold[Decoder.Result[A]](Right(().asInstanceOf[A]))(cursor.downField(\"ok\").as(_))",
+                            ),
+                        ),
+                    ],
+                },
+            ),
+        ),
+        combinator(
+            "The codec of a tuple, given the codecs of its items",
+            "tuple",
+            &["A"],
+            vec![Param::new("items", "Codec[_]*")],
+            "A",
+            codec(
+                "A",
+                Expr::raw(
+                    "Json.fromValues(items.zip(value.asInstanceOf[js.Array[Any]].toList).map { case (item, element) => item.asInstanceOf[Codec[Any]].apply(element) })",
+                ),
+                Expr::raw(
+                    "sequence(items.zipWithIndex.map { case (item, i) => cursor.downN(i).as(item.asInstanceOf[Codec[Any]]) }).map(values => js.Array(values: _*).asInstanceOf[A])",
+                ),
+            ),
+        ),
+        combinator(
+            "The codec of a record, given its fields",
+            "record",
+            &["A"],
+            vec![Param::new("fields", "Field*")],
+            "A",
+            codec(
+                "A",
+                Expr::Block(vec![
+                    Stat::Val(Val::new("members").rhs(Expr::raw("value.asInstanceOf[js.Dynamic]"))),
+                    Stat::Expr(Expr::raw(
+                        "Json.fromFields(fields.map(field => field.label -> field.codec.asInstanceOf[Codec[Any]].apply(members.selectDynamic(field.name))))",
+                    )),
+                ]),
+                Expr::raw(
+                    "sequence(fields.map(field => cursor.downField(field.label).as(field.codec.asInstanceOf[Codec[Any]]))).map(values => js.Dictionary(fields.map(_.name).zip(values): _*).asInstanceOf[A])",
+                ),
+            ),
+        ),
+        combinator(
+            "The codec of a variant, given its cases",
+            "variant",
+            &["A"],
+            vec![Param::new("cases", "Case*")],
+            "A",
+            codec(
+                "A",
+                Expr::Block(vec![
+                    Stat::Val(Val::new("variant").rhs(Expr::raw("value.asInstanceOf[js.Dynamic]"))),
+                    Stat::Val(Val::new("tag").rhs(Expr::raw("variant.tag.asInstanceOf[String]"))),
+                    Stat::Val(Val::new("payload").rhs(Expr::raw(
+                        "cases.find(_.label == tag).flatMap(_.payload).map(payload => \"val\" -> payload.asInstanceOf[Codec[Any]].apply(variant.`val`))",
+                    ))),
+                    Stat::Expr(Expr::raw(
+                        "Json.fromFields((\"tag\" -> Json.fromString(tag)) :: payload.toList)",
+                    )),
+                ]),
+                Expr::raw(
+                    "for { tag <- cursor.downField(\"tag\").as[String]; payload <- cases.find(_.label == tag).map(_.payload).toRight(DecodingFailure(s\"unknown case $tag\", cursor.history)); value <- payload.fold[Decoder.Result[Any]](Right(js.undefined))(codec => cursor.downField(\"val\").as(codec.asInstanceOf[Codec[Any]])) } yield js.Dictionary[Any](\"tag\" -> tag, \"val\" -> value).asInstanceOf[A]",
+                ),
+            ),
+        ),
+        combinator(
+            "The codec of an enum, given the WIT names of its cases",
+            "enumeration",
+            &["A"],
+            vec![Param::new("cases", "String*")],
+            "A",
+            codec(
+                "A",
+                Expr::raw("Json.fromString(value.asInstanceOf[String])"),
+                Expr::raw(
+                    "cursor.as[String].flatMap(label => if (cases.contains(label)) Right(label.asInstanceOf[A]) else Left(DecodingFailure(s\"unknown case $label\", cursor.history)))",
+                ),
+            ),
+        ),
+        combinator(
+            "The codec of flags, given its flags",
+            "flags",
+            &["A"],
+            vec![Param::new("flags", "Flag*")],
+            "A",
+            codec(
+                "A",
+                Expr::Block(vec![
+                    Stat::Val(Val::new("members").rhs(Expr::raw(
+                        "value.asInstanceOf[js.Dictionary[Boolean]]",
+                    ))),
+                    Stat::Expr(Expr::raw(
+                        "Json.fromValues(flags.filter(flag => members.getOrElse(flag.name, false)).map(flag => Json.fromString(flag.label)))",
+                    )),
+                ]),
+                Expr::raw(
+                    "cursor.as[List[String]].flatMap(set => set.find(label => !flags.exists(_.label == label)).map(label => DecodingFailure(s\"unknown flag $label\", cursor.history)).toLeft(js.Dictionary(flags.map(flag => flag.name -> set.contains(flag.label)): _*).asInstanceOf[A]))",
+                ),
+            ),
+        ),
+    ]);
+
+    vec![Stat::Object(
+        Template::new("CirceCodecs").doc(doc(ENCODING)).body(stats),
+    )]
+}
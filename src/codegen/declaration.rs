@@ -0,0 +1,147 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use color_eyre::{eyre::eyre, Result};
+use id_arena::{Arena, Id};
+use wit_parser::{Type as WitType, TypeDef, TypeDefKind};
+
+use super::ast::Stat;
+use super::{Alias, Encoding, Enum, Flags, Layer, Record, Render, Variant};
+use crate::naming::ScalaName;
+use crate::types::TypeMap;
+
+/// Represents a type declared by an interface
+pub enum TypeDeclaration {
+    Record(Record),
+    Variant(Variant),
+    Enum(Enum),
+    Flags(Flags),
+    Alias(Alias),
+}
+
+impl TypeDeclaration {
+    /// Constructs a `TypeDeclaration` from WIT, if the type is one that can be generated
+    pub fn from_wit(
+        id: Id<TypeDef>,
+        types: &Arena<TypeDef>,
+        type_map: &TypeMap,
+    ) -> Result<Option<Self>> {
+        let name = type_map
+            .get(&id)
+            .cloned()
+            .ok_or(eyre!("Could not find type ID {id:?} in type_map"))?;
+
+        let docs = &types[id].docs;
+
+        Ok(match &types[id].kind {
+            TypeDefKind::Record(record) => Some(Self::Record(Record::from_wit(
+                name, record, docs, type_map,
+            )?)),
+            TypeDefKind::Variant(variant) => Some(Self::Variant(Variant::from_wit(
+                name, variant, docs, type_map,
+            )?)),
+            TypeDefKind::Enum(enum_) => Some(Self::Enum(Enum::from_wit(name, enum_, docs))),
+            TypeDefKind::Flags(flags) => Some(Self::Flags(Flags::from_wit(name, flags, docs))),
+            _ => Alias::from_wit(name, id, types, type_map)?.map(Self::Alias),
+        })
+    }
+
+    /// Whether the values of a WIT type can be encoded, which rules out resources, streams
+    /// and futures, as well as the types that cannot be generated
+    pub fn encodable(kind: &TypeDefKind, types: &Arena<TypeDef>) -> bool {
+        let encodable = |ty: &WitType| match ty {
+            WitType::Id(id) => Self::encodable(&types[*id].kind, types),
+            _ => true,
+        };
+
+        match kind {
+            TypeDefKind::Record(record) => record.fields.iter().all(|field| encodable(&field.ty)),
+            TypeDefKind::Variant(variant) => variant
+                .cases
+                .iter()
+                .all(|case| case.ty.as_ref().is_none_or(encodable)),
+            TypeDefKind::Tuple(tuple) => {
+                (2..=22).contains(&tuple.types.len()) && tuple.types.iter().all(encodable)
+            }
+            TypeDefKind::List(ty) | TypeDefKind::Option(ty) | TypeDefKind::Type(ty) => {
+                encodable(ty)
+            }
+            TypeDefKind::Result(result) => result.ok.iter().chain(&result.err).all(encodable),
+            TypeDefKind::Enum(_) | TypeDefKind::Flags(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Declares the codec of the type in its companion object
+    pub fn codec(self, encoding: Encoding) -> Self {
+        match self {
+            Self::Record(record) => Self::Record(record.codec(encoding)),
+            Self::Variant(variant) => Self::Variant(variant.codec(encoding)),
+            Self::Enum(enum_) => Self::Enum(enum_.codec(encoding)),
+            Self::Flags(flags) => Self::Flags(flags.codec(encoding)),
+            Self::Alias(alias) => Self::Alias(alias.codec(encoding)),
+        }
+    }
+
+    /// The implicit codec of the type, for the encodings whose codecs are declared
+    /// outside of the companion objects
+    pub fn instance(&self, encoding: Encoding) -> Stat {
+        match self {
+            Self::Record(record) => record.instance(encoding),
+            Self::Variant(variant) => variant.instance(encoding),
+            Self::Enum(enum_) => enum_.instance(encoding),
+            Self::Flags(flags) => flags.instance(encoding),
+            Self::Alias(alias) => alias.instance(encoding),
+        }
+    }
+
+    /// The idiomatic counterpart of the type
+    pub fn idiomatic(&self, layer: &Layer) -> Vec<Stat> {
+        match self {
+            Self::Record(record) => record.idiomatic(layer),
+            Self::Variant(variant) => variant.idiomatic(layer),
+            Self::Enum(enum_) => enum_.idiomatic(layer),
+            Self::Flags(flags) => flags.idiomatic(layer),
+            Self::Alias(alias) => alias.idiomatic(layer),
+        }
+    }
+
+    /// Whether the type is an object on the JS side, which gets structural operations
+    pub fn is_structural(&self) -> bool {
+        matches!(self, Self::Record(_) | Self::Variant(_) | Self::Flags(_))
+    }
+
+    /// The kind and the names of the members, so that collisions between them can be resolved
+    pub fn member_names_mut(&mut self) -> (&'static str, Vec<&mut ScalaName>) {
+        match self {
+            Self::Record(record) => ("Field", record.field_names_mut().collect()),
+            Self::Variant(variant) => ("Case", variant.case_names_mut().collect()),
+            Self::Enum(enum_) => ("Case", enum_.case_names_mut().collect()),
+            Self::Flags(flags) => ("Flag", flags.flag_names_mut().collect()),
+            Self::Alias(_) => ("Member", Vec::new()),
+        }
+    }
+}
+
+impl Render for TypeDeclaration {
+    fn render(self) -> Result<Vec<Stat>> {
+        match self {
+            Self::Record(record) => record.render(),
+            Self::Variant(variant) => variant.render(),
+            Self::Enum(enum_) => enum_.render(),
+            Self::Flags(flags) => flags.render(),
+            Self::Alias(alias) => alias.render(),
+        }
+    }
+}
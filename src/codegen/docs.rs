@@ -0,0 +1,33 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use wit_parser::Docs;
+
+/// Converts WIT docs to the contents of a Scaladoc comment.
+/// The Markdown is kept as is, except for the sequences that would open or close a comment.
+pub fn scaladoc(docs: &Docs) -> Option<String> {
+    docs.contents
+        .as_deref()
+        .map(str::trim_end)
+        .filter(|contents| !contents.is_empty())
+        .map(|contents| contents.replace("*/", "*&#47;").replace("/*", "&#47;*"))
+}
+
+/// Appends Scaladoc tags such as `@param` to docs, separated by a blank line
+pub fn with_tags(doc: Option<String>, tags: Vec<String>) -> Option<String> {
+    match doc {
+        Some(doc) if !tags.is_empty() => Some(format!("{doc}\n\n{}", tags.join("\n"))),
+        doc => doc,
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ast::{Expr, Stat, Val};
+use super::{circe, scalacheck, validation, wave};
+use crate::generator::Options;
+use crate::types::Type;
+
+/// An encoding the generated types are converted to and from, or otherwise checked against,
+/// through an implicit codec declared in their companion objects, or in the test sources for
+/// the encodings only tests rely on
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// The WebAssembly Value Encoding (WAVE)
+    Wave,
+
+    /// JSON, through the codecs of circe
+    Circe,
+
+    /// Random values for property-based testing, through the generators of ScalaCheck
+    ScalaCheck,
+
+    /// Checks that the JS representation of values fits their WIT type
+    Validation,
+}
+
+impl Encoding {
+    /// The encodings enabled by the options
+    pub fn enabled(options: &Options) -> Vec<Self> {
+        [
+            (options.wave, Self::Wave),
+            (options.circe, Self::Circe),
+            (options.scalacheck, Self::ScalaCheck),
+            (options.validate, Self::Validation),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, encoding)| enabled.then_some(encoding))
+        .collect()
+    }
+
+    /// The generated object holding the codecs of the primitives and the combinators
+    pub fn runtime(self) -> &'static str {
+        match self {
+            Self::Wave => "Wave",
+            Self::Circe => "CirceCodecs",
+            Self::ScalaCheck => "Arbitraries",
+            Self::Validation => "Validation",
+        }
+    }
+
+    /// Whether the codecs are only used by tests, so that they are declared in the runtime object
+    /// of the test sources rather than in the companion objects
+    pub fn test_scope(self) -> bool {
+        self == Self::ScalaCheck
+    }
+
+    /// The name of the implicit codec in companion objects, which prefixes the names of the
+    /// codecs declared in the test sources
+    fn member(self) -> &'static str {
+        match self {
+            Self::Wave => "wave",
+            Self::Circe => "circe",
+            Self::ScalaCheck => "arbitrary",
+            Self::Validation => "validator",
+        }
+    }
+
+    /// The Scala expression of the codec of a type
+    pub fn codec(self, ty: &Type) -> String {
+        ty.codec(self.runtime(), &|name| {
+            if self.test_scope() {
+                format!("{}.{}", self.runtime(), self.name(&name))
+            } else {
+                format!("{name}.{}", self.member())
+            }
+        })
+    }
+
+    /// The name of the implicit codec of a generated type
+    fn name(self, ty: &str) -> String {
+        if self.test_scope() {
+            format!("{}{ty}", self.member())
+        } else {
+            self.member().to_owned()
+        }
+    }
+
+    /// The implicit codec of a generated type, declared in its companion object, or lazily in
+    /// the runtime object of the test sources, where it may refer to the codecs declared later
+    pub fn instance(self, ty: &str, codec: Expr) -> Stat {
+        let codec_type = match self {
+            Self::Wave => format!("Wave[{ty}]"),
+            Self::Circe => format!("io.circe.Codec[{ty}]"),
+            Self::ScalaCheck => format!("org.scalacheck.Arbitrary[{ty}]"),
+            Self::Validation => format!("Validation.Validator[{ty}]"),
+        };
+
+        let mut val = Val::new(self.name(ty)).modifier("implicit");
+        if self.test_scope() {
+            val = val.modifier("lazy");
+        }
+
+        Stat::Val(val.ty(codec_type).rhs(codec))
+    }
+
+    /// The definitions the codecs rely on, generated once per file, along with the codecs
+    /// of the generated types for the encodings in test scope
+    pub fn definitions(self, instances: Vec<Stat>) -> Vec<Stat> {
+        match self {
+            Self::Wave => wave::runtime(),
+            Self::Circe => circe::runtime(),
+            Self::ScalaCheck => scalacheck::runtime(instances),
+            Self::Validation => validation::runtime(),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+use color_eyre::Result;
+use convert_case::{Case, Casing};
+use wit_parser::{Docs, Enum as WitEnum};
+
+use super::ast::{Def, Expr, Param, Stat, Template, Val};
+use super::docs::scaladoc;
+use super::{Encoding, Layer, Render, IDIOMATIC_HELPERS};
+use crate::naming::{ScalaName, Scope, COMPANION_HELPERS};
+use crate::types::TypeName;
+
+/// Represents the name of an enum case in Scala
+struct EnumCaseName(ScalaName);
+
+impl Display for EnumCaseName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for EnumCaseName {
+    fn from(name: String) -> Self {
+        Self(ScalaName::new(&name, Case::Camel, COMPANION_HELPERS))
+    }
+}
+
+/// Represents an enum case in Scala
+struct EnumCase {
+    /// The case name
+    name: EnumCaseName,
+
+    /// The Scaladoc of the case
+    doc: Option<String>,
+}
+
+/// Represents an enum in Scala, which is a string on the JS side
+pub struct Enum {
+    /// The enum name
+    name: TypeName,
+
+    /// The enum cases
+    cases: Vec<EnumCase>,
+
+    /// The Scaladoc of the enum
+    doc: Option<String>,
+
+    /// The implicit instances declared in the companion object
+    instances: Vec<Stat>,
+}
+
+impl Enum {
+    /// Constructs an `Enum` from WIT
+    pub fn from_wit(name: TypeName, enum_: &WitEnum, docs: &Docs) -> Self {
+        Self {
+            name,
+            cases: enum_
+                .cases
+                .iter()
+                .map(|case| EnumCase {
+                    name: EnumCaseName::from(case.name.clone()),
+                    doc: scaladoc(&case.docs),
+                })
+                .collect(),
+            doc: scaladoc(docs),
+            instances: Vec::new(),
+        }
+    }
+
+    /// Declares the codec of the enum in its companion object
+    pub fn codec(mut self, encoding: Encoding) -> Self {
+        let instance = self.instance(encoding);
+        self.instances.push(instance);
+        self
+    }
+
+    /// The implicit codec of the enum
+    pub fn instance(&self, encoding: Encoding) -> Stat {
+        let cases = self
+            .cases
+            .iter()
+            .map(|case| Expr::raw(format!("\"{}\"", case.name.0.wit())))
+            .collect();
+
+        let name = self.name.to_string();
+        encoding.instance(
+            &name,
+            Expr::raw(format!("{}.enumeration[{name}]", encoding.runtime())).call(cases),
+        )
+    }
+
+    /// The idiomatic counterpart of the enum, a sealed trait with a case object per case,
+    /// whose companion object converts it from and to the JS representation
+    pub fn idiomatic(&self, layer: &Layer) -> Vec<Stat> {
+        let name = self.name.to_string();
+        if !layer.converts(&name) {
+            return Vec::new();
+        }
+
+        let js = layer.js_name(&name);
+
+        let mut scope = Scope::new(IDIOMATIC_HELPERS.iter().copied().chain([name.as_str()]));
+        let objects: Vec<String> = self
+            .cases
+            .iter()
+            .map(|case| scope.fresh(&case.name.0.wit().to_case(Case::Pascal)))
+            .collect();
+
+        let mut members: Vec<Stat> = self
+            .cases
+            .iter()
+            .zip(&objects)
+            .map(|(case, object)| {
+                Stat::Object(
+                    Template::new(object)
+                        .doc(case.doc.clone())
+                        .modifier("case")
+                        .extends(&name),
+                )
+            })
+            .collect();
+
+        let to_js = self
+            .cases
+            .iter()
+            .zip(&objects)
+            .map(|(case, object)| (object.clone(), Expr::raw(format!("{js}.{}", case.name))))
+            .collect();
+
+        let mut to_idiomatic: Vec<(String, Expr)> = self
+            .cases
+            .iter()
+            .zip(&objects)
+            .map(|(case, object)| (format!("\"{}\"", case.name.0.wit()), Expr::raw(object)))
+            .collect();
+        to_idiomatic.push((
+            "other".to_owned(),
+            Expr::raw(format!(
+                "throw new IllegalStateException(s\"Unknown case $other of {name}\")"
+            )),
+        ));
+
+        members.extend([
+            Stat::Def(
+                Def::new("toJS")
+                    .doc(Some(format!("Converts `{name}` to its JS representation")))
+                    .params(vec![Param::new("value", &name)])
+                    .returns(&js)
+                    .body(Expr::Match {
+                        scrutinee: "value".to_owned(),
+                        cases: to_js,
+                    }),
+            ),
+            Stat::Def(
+                Def::new("fromJS")
+                    .doc(Some(format!("Converts the JS representation of `{name}`")))
+                    .params(vec![Param::new("value", &js)])
+                    .returns(&name)
+                    .body(Expr::Match {
+                        scrutinee: "value.asInstanceOf[String]".to_owned(),
+                        cases: to_idiomatic,
+                    }),
+            ),
+        ]);
+
+        vec![
+            Stat::Trait(
+                Template::new(&name)
+                    .doc(self.doc.clone())
+                    .modifier("sealed"),
+            ),
+            Stat::Object(Template::new(&name).body(members)),
+        ]
+    }
+
+    /// The names of the cases, so that collisions between them can be resolved
+    pub fn case_names_mut(&mut self) -> impl Iterator<Item = &mut ScalaName> {
+        self.cases.iter_mut().map(|case| &mut case.name.0)
+    }
+}
+
+impl Render for Enum {
+    fn render(self) -> Result<Vec<Stat>> {
+        let name = self.name.to_string();
+        let values =
+            Scope::new(self.cases.iter().map(|case| case.name.0.unescaped())).fresh("values");
+
+        let mut members: Vec<Stat> = self
+            .cases
+            .iter()
+            .map(|case| {
+                Stat::Val(
+                    Val::new(case.name.to_string())
+                        .doc(case.doc.clone())
+                        .ty(&name)
+                        .rhs(Expr::raw(format!(
+                            "\"{}\".asInstanceOf[{name}]",
+                            case.name.0.wit()
+                        ))),
+                )
+            })
+            .collect();
+
+        members.push(Stat::Val(
+            Val::new(values)
+                .ty(format!("js.Array[{name}]"))
+                .rhs(Expr::raw(format!(
+                    "js.Array({})",
+                    self.cases
+                        .iter()
+                        .map(|case| case.name.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))),
+        ));
+        members.extend(self.instances);
+
+        Ok(vec![
+            Stat::Trait(
+                Template::new(&name)
+                    .doc(self.doc)
+                    .annotation("@js.native")
+                    .modifier("sealed")
+                    .extends("js.Any"),
+            ),
+            Stat::Object(Template::new(&name).body(members)),
+        ])
+    }
+}
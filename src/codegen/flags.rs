@@ -0,0 +1,57 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use color_eyre::Result;
+use wit_parser::{Docs, Flags as WitFlags};
+
+use super::ast::Stat;
+use super::{Encoding, Layer, Record, Render};
+use crate::naming::ScalaName;
+use crate::types::TypeName;
+
+/// Represents flags in Scala, which are an object of booleans on the JS side
+pub struct Flags(Record);
+
+impl Flags {
+    /// Constructs `Flags` from WIT
+    pub fn from_wit(name: TypeName, flags: &WitFlags, docs: &Docs) -> Self {
+        Self(Record::from_flags(name, flags, docs))
+    }
+
+    /// Declares the codec of the flags in their companion object
+    pub fn codec(self, encoding: Encoding) -> Self {
+        Self(self.0.flags_codec(encoding))
+    }
+
+    /// The implicit codec of the flags
+    pub fn instance(&self, encoding: Encoding) -> Stat {
+        self.0.flags_instance(encoding)
+    }
+
+    /// The idiomatic counterpart of the flags, a case class of booleans
+    pub fn idiomatic(&self, layer: &Layer) -> Vec<Stat> {
+        self.0.idiomatic(layer)
+    }
+
+    /// The names of the flags, so that collisions between them can be resolved
+    pub fn flag_names_mut(&mut self) -> impl Iterator<Item = &mut ScalaName> {
+        self.0.field_names_mut()
+    }
+}
+
+impl Render for Flags {
+    fn render(self) -> Result<Vec<Stat>> {
+        self.0.render()
+    }
+}
@@ -15,14 +15,20 @@
 use std::fmt::Display;
 
 use color_eyre::Result;
-use convert_case::{Case, Casing};
-use wit_parser::{Function as WitFunction, Results as WitResults, Type as WitType};
+use convert_case::Case;
+use wit_parser::{Function as WitFunction, FunctionKind, Results as WitResults, Type as WitType};
 
-use super::Render;
-use crate::types::{Type, TypeMap};
+use super::ast::{self, Def, Expr, Stat};
+use super::docs::{scaladoc, with_tags};
+use super::{Encoding, Layer, Render, Validated};
+use crate::naming::{ScalaName, Scope, OBJECT_MEMBERS};
+use crate::types::{Type, TypeMap, TypeName};
+
+/// The object holding the effect-polymorphic counterpart of the implemented trait
+pub const TAGLESS: &str = "Tagless";
 
 /// Represents the name of a function param in Scala
-struct ParamName(String);
+struct ParamName(ScalaName);
 
 impl Display for ParamName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -32,7 +38,7 @@ impl Display for ParamName {
 
 impl From<String> for ParamName {
     fn from(name: String) -> Self {
-        Self(name.to_case(Case::Camel))
+        Self(ScalaName::new(&name, Case::Camel, &[]))
     }
 }
 
@@ -43,6 +49,9 @@ struct Param {
 
     /// The Scala type associated to the param
     ty: Type,
+
+    /// The WIT type of the param, as written in the Scaladoc
+    wit_type: String,
 }
 
 impl Param {
@@ -51,13 +60,14 @@ impl Param {
         Ok(Self {
             name: ParamName::from(name),
             ty: Type::from_wit(ty, type_map)?,
+            wit_type: type_map.wit_name(&ty),
         })
     }
 }
 
 /// Represents a function name in Scala
 #[derive(Clone)]
-struct FunctionName(String);
+struct FunctionName(ScalaName);
 
 impl Display for FunctionName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -67,7 +77,7 @@ impl Display for FunctionName {
 
 impl From<String> for FunctionName {
     fn from(name: String) -> Self {
-        Self(name.to_case(Case::Camel))
+        Self(ScalaName::new(&name, Case::Camel, OBJECT_MEMBERS))
     }
 }
 
@@ -81,56 +91,439 @@ pub struct Function {
 
     /// The outputs of the function
     outs: Vec<Type>,
+
+    /// The types of the named results, when the function returns them as a record
+    result_fields: Vec<Type>,
+
+    /// The WIT type of the results, as written in the Scaladoc
+    wit_results: Option<String>,
+
+    /// The Scaladoc of the function, without the tags
+    doc: Option<String>,
 }
 
 impl Function {
-    /// Constructs a `Function` from WIT
-    pub fn from_wit(function: WitFunction, type_map: &TypeMap) -> Result<Self> {
+    /// Constructs a `Function` from WIT, whose named results are returned as `result_record`
+    /// when it has several of them
+    pub fn from_wit(
+        function: WitFunction,
+        type_map: &TypeMap,
+        result_record: Option<&TypeName>,
+    ) -> Result<Self> {
+        // The `self` param of resource methods is the instance the method is called on
+        let skipped = match function.kind {
+            FunctionKind::Method(_) => 1,
+            _ => 0,
+        };
+
         let params: Result<Vec<Param>> = function
             .params
-            .into_iter()
-            .map(|(name, ty)| Param::from_wit(name, ty, type_map))
+            .iter()
+            .skip(skipped)
+            .map(|(name, ty)| Param::from_wit(name.clone(), *ty, type_map))
             .collect();
 
-        let outs: Result<Vec<Type>> = match function.results {
+        let wit_results = match &function.results {
+            WitResults::Named(params) if params.is_empty() => None,
+            WitResults::Named(params) => Some(format!(
+                "({})",
+                params
+                    .iter()
+                    .map(|(name, ty)| format!("{name}: {}", type_map.wit_name(ty)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            WitResults::Anon(ty) => Some(type_map.wit_name(ty)),
+        };
+
+        let outs: Result<Vec<Type>> = match &function.results {
             WitResults::Named(params) => params
                 .iter()
                 .map(|(_, ty)| Type::from_wit(*ty, type_map))
                 .collect(),
-            WitResults::Anon(ty) => Type::from_wit(ty, type_map).map(|ty| vec![ty]),
+            WitResults::Anon(ty) => Type::from_wit(*ty, type_map).map(|ty| vec![ty]),
+        };
+
+        // jco returns several named results as an object keyed by their names
+        let (outs, result_fields) = match result_record {
+            Some(record) => (vec![Type::Custom(record.clone())], outs?),
+            None => (outs?, Vec::new()),
         };
 
         Ok(Self {
-            name: FunctionName::from(function.name),
+            name: FunctionName::from(function.item_name().to_owned()),
             params: params?,
-            outs: outs?,
+            outs,
+            result_fields,
+            wit_results,
+            doc: scaladoc(&function.docs),
         })
     }
+
+    /// The name of the function, so that collisions with other functions can be resolved
+    pub fn name_mut(&mut self) -> &mut ScalaName {
+        &mut self.name.0
+    }
+
+    /// The name of the function
+    pub fn name(&self) -> &ScalaName {
+        &self.name.0
+    }
+
+    /// The names of the params
+    pub fn param_names(&self) -> impl Iterator<Item = &ScalaName> {
+        self.params.iter().map(|param| &param.name.0)
+    }
+
+    /// The names of the params, so that collisions between them can be resolved
+    pub fn param_names_mut(&mut self) -> impl Iterator<Item = &mut ScalaName> {
+        self.params.iter_mut().map(|param| &mut param.name.0)
+    }
 }
 
-impl Render for Function {
-    fn render(self) -> Result<String> {
+impl Function {
+    /// The params of the function, as written in its signature
+    pub fn params(&self) -> Vec<ast::Param> {
+        self.params
+            .iter()
+            .map(|Param { name, ty, .. }| ast::Param::new(name.to_string(), ty.to_string()))
+            .collect()
+    }
+
+    /// The types of the outputs of the function
+    pub fn out_types(&self) -> Vec<String> {
+        self.outs.iter().map(Type::to_string).collect()
+    }
+
+    /// The record of the results and the types of its fields, when the function returns
+    /// several named results
+    pub fn result_record(&self) -> Option<(String, Vec<String>)> {
+        match self.outs.as_slice() {
+            [record] if !self.result_fields.is_empty() => Some((
+                record.to_string(),
+                self.result_fields.iter().map(Type::to_string).collect(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// The result type of the function
+    pub fn returns(&self) -> String {
+        let outs = self.out_types();
+
+        if outs.is_empty() {
+            "Unit".to_owned()
+        } else if outs.len() == 1 {
+            outs.first().unwrap().clone()
+        } else {
+            format!("({})", outs.join(", "))
+        }
+    }
+
+    /// The abstract method declaring this function, without its Scaladoc
+    fn signature(&self) -> Def {
+        Def::new(self.name.to_string())
+            .params(self.params())
+            .returns(self.returns())
+    }
+
+    /// The method of a native facade calling this function
+    pub fn facade(&self) -> Stat {
+        native(self.declaration(), &self.name.0)
+    }
+
+    /// The methods of a wasm-rpc stub calling this function on a remote worker: the blocking one
+    /// returns the results, and the other one returns the `pending` call right away
+    pub fn rpc(&self, pending: &str) -> Vec<Stat> {
+        let blocking = ScalaName::new(
+            &format!("blocking-{}", self.name.0.wit()),
+            Case::Camel,
+            OBJECT_MEMBERS,
+        );
+
+        let mut blocking_declaration = self.declaration();
+        blocking_declaration.name = blocking.to_string();
+
+        let declaration = self.declaration_returning(
+            pending,
+            format!("the pending call, which gets `{}`", self.returns()),
+        );
+
+        vec![
+            native(blocking_declaration, &blocking),
+            native(declaration, &self.name.0),
+        ]
+    }
+
+    /// The abstract method declaring this function in the implemented trait, with its Scaladoc
+    pub fn implemented(&self) -> Stat {
+        Stat::Def(self.declaration())
+    }
+
+    /// The abstract method declaring this function in the idiomatic layer, with its Scaladoc
+    pub fn idiomatic(&self, layer: &Layer) -> Stat {
+        let (params, returns) = self.typed(Some(layer));
+        let def = Def::new(self.name.to_string())
+            .params(params)
+            .returns(returns);
+
+        Stat::Def(self.documented(def, self.results_doc()))
+    }
+
+    /// The abstract method declaring this function in the effect-polymorphic trait, with its
+    /// Scaladoc, whose results are wrapped in `effect`, the types being seen from `layer` if any
+    pub fn tagless(&self, layer: Option<&Layer>, effect: &str) -> Stat {
+        let (params, returns) = self.typed(layer);
+        let def = Def::new(self.name.to_string())
+            .params(params)
+            .returns(format!("{effect}[{returns}]"));
+        let results = self
+            .wit_results
+            .as_ref()
+            .map(|results| format!("the effect of `{results}`"));
+
+        Stat::Def(self.documented(def, results))
+    }
+
+    /// The method implementing this function by running the effect returned by `api` with `run`
+    pub fn adapted(&self, layer: Option<&Layer>, api: &str, run: &str) -> Stat {
+        let body = Expr::raw(format!("{run}({api}.{}({}))", self.name, self.args()));
+        self.overridden(layer, body)
+    }
+
+    /// The params of the function passed on as arguments
+    pub fn args(&self) -> String {
+        self.params
+            .iter()
+            .map(|param| param.name.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The method overriding this function in a test double of the implemented trait,
+    /// the types being seen from `layer` if any
+    pub fn overridden(&self, layer: Option<&Layer>, body: Expr) -> Stat {
+        let (params, returns) = self.typed(layer);
+
+        Stat::Def(
+            Def::new(self.name.to_string())
+                .modifier("override")
+                .params(params)
+                .returns(returns)
+                .body(body),
+        )
+    }
+
+    /// The type of the functions scripting the responses of this function in a mock
+    pub fn response_type(&self, layer: Option<&Layer>) -> String {
+        let (params, returns) = self.typed(layer);
+        let params = params
+            .into_iter()
+            .map(|param| param.ty)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("({params}) => {returns}")
+    }
+
+    /// The params and the result type of the function, as seen from `layer` if any
+    fn typed(&self, layer: Option<&Layer>) -> (Vec<ast::Param>, String) {
+        let Some(layer) = layer else {
+            return (self.params(), self.returns());
+        };
+
         let params = self
             .params
             .iter()
-            .map(|Param { name, ty }| format!("{name}: {ty}"))
+            .map(|param| ast::Param::new(param.name.to_string(), layer.ty(&param.ty)))
+            .collect();
+        let outs: Vec<String> = self.outs.iter().map(|ty| layer.ty(ty)).collect();
+        let returns = match outs.len() {
+            0 => "Unit".to_owned(),
+            1 => outs[0].clone(),
+            _ => format!("({})", outs.join(", ")),
+        };
+
+        (params, returns)
+    }
+
+    /// The types the params and the results of the function refer to, as seen from `layer` if any
+    pub fn referenced_types(&self, layer: Option<&Layer>) -> Vec<String> {
+        let (params, returns) = self.typed(layer);
+        params
+            .into_iter()
+            .map(|param| param.ty)
+            .chain([returns])
+            .collect()
+    }
+
+    /// The method exporting this function under the name jco looks up, delegating to `target`.
+    /// With an idiomatic `layer`, the params and the results are converted by it, and with `validated`
+    /// types, the results are checked against their WIT type once converted.
+    pub fn export(
+        &self,
+        target: &str,
+        layer: Option<&Layer>,
+        validated: Option<&Validated>,
+    ) -> Stat {
+        let to_js = |ty: &Type, value: &str| {
+            layer.map_or_else(|| value.to_owned(), |layer| layer.to_js(ty, value))
+        };
+        let args = self
+            .params
+            .iter()
+            .map(|param| {
+                let name = param.name.to_string();
+                layer.map_or_else(
+                    || name.clone(),
+                    |layer| layer.to_idiomatic(&param.ty, &name),
+                )
+            })
             .collect::<Vec<_>>()
             .join(", ");
+        let call = format!("{target}.{}({args})", self.name);
+        let results = Scope::new(self.param_names().map(ScalaName::unescaped)).fresh("results");
 
-        let out = {
-            let outs = self.outs.iter().map(Type::to_string).collect::<Vec<_>>();
-
-            if outs.is_empty() {
-                "Unit".to_owned()
-            } else if outs.len() == 1 {
-                outs.first().unwrap().clone()
-            } else {
-                format!("({})", outs.join(", "))
+        // The conversion of the results, if they differ between the layers
+        let converted = match self.outs.as_slice() {
+            [] => None,
+            [out] => Some(to_js(out, &results)),
+            outs => {
+                let items: Vec<_> = (1..=outs.len())
+                    .map(|i| format!("{results}._{i}"))
+                    .collect();
+                let converted: Vec<_> = outs
+                    .iter()
+                    .zip(&items)
+                    .map(|(out, item)| to_js(out, item))
+                    .collect();
+                (converted != items).then(|| format!("({})", converted.join(", ")))
             }
         };
+        let converted = converted.filter(|converted| *converted != results);
+
+        // The results are checked once converted, when their type has a validator
+        let validator = match (self.outs.as_slice(), validated) {
+            ([out], Some(validated)) => validated.validator(out),
+            _ => None,
+        };
+        let check = |value: String| match &validator {
+            Some(validator) => Expr::raw(format!("{}.results", Encoding::Validation.runtime()))
+                .call(vec![
+                    Expr::raw(format!("\"{}\"", self.name.0.wit())),
+                    Expr::raw(validator.as_str()),
+                ])
+                .call(vec![Expr::raw(value)]),
+            None => Expr::raw(value),
+        };
+        let converted = match (converted, &validator) {
+            (converted, Some(_)) => Some(check(converted.unwrap_or_else(|| results.clone()))),
+            (converted, None) => converted.map(Expr::raw),
+        };
+
+        let body = match converted {
+            None => Expr::raw(call),
+            Some(_) if self.outs.len() == 1 => check(to_js(&self.outs[0], &call)),
+            Some(converted) => Expr::Block(vec![
+                Stat::Val(ast::Val::new(&results).rhs(Expr::raw(call))),
+                Stat::Expr(converted),
+            ]),
+        };
 
-        let name = self.name;
+        self.exported(body)
+    }
+
+    /// The method of the facade of an imported interface checking the arguments of this function
+    /// against their WIT type before calling it on the `native` object, the arguments whose type
+    /// has no validator being passed as is
+    pub fn validating(&self, native: &str, validated: &Validated) -> Stat {
+        let call = format!("{native}.{}({})", self.name, self.args());
+        let checked: Vec<_> = self
+            .params
+            .iter()
+            .filter_map(|param| {
+                validated.validator(&param.ty).map(|validator| {
+                    Expr::raw(format!(
+                        "(\"{}\", {validator}, {})",
+                        param.name.0.wit(),
+                        param.name
+                    ))
+                })
+            })
+            .collect();
+
+        let body = if checked.is_empty() {
+            Expr::raw(call)
+        } else {
+            Expr::Block(vec![
+                Stat::Expr(
+                    Expr::raw(format!("{}.arguments", Encoding::Validation.runtime())).call(
+                        std::iter::once(Expr::raw(format!("\"{}\"", self.name.0.wit())))
+                            .chain(checked)
+                            .collect(),
+                    ),
+                ),
+                Stat::Expr(Expr::raw(call)),
+            ])
+        };
+
+        Stat::Def(self.declaration().body(body))
+    }
+
+    /// The method exporting this function with the given body, overriding the one of the
+    /// implemented trait
+    fn exported(&self, body: Expr) -> Stat {
+        Stat::Def(
+            self.signature()
+                .annotation(format!("@JSExport(\"{}\")", self.name.0.converted()))
+                .modifier("override")
+                .body(body),
+        )
+    }
+
+    /// The description of the results in the Scaladoc
+    fn results_doc(&self) -> Option<String> {
+        self.wit_results
+            .as_ref()
+            .map(|results| format!("`{results}`"))
+    }
+
+    /// The abstract method declaring this function, with its Scaladoc
+    fn declaration(&self) -> Def {
+        self.documented(self.signature(), self.results_doc())
+    }
+
+    /// The abstract method declaring this function with another result type, with its Scaladoc
+    /// in which the result is described as `description`
+    pub fn declaration_returning(&self, returns: impl Into<String>, description: String) -> Def {
+        self.documented(self.signature().returns(returns), Some(description))
+    }
+
+    /// Adds the Scaladoc of the function to a method, with tags for its params and `returns`
+    fn documented(&self, def: Def, returns: Option<String>) -> Def {
+        let tags = self
+            .params
+            .iter()
+            .map(|param| format!("@param {} `{}`", param.name, param.wit_type))
+            .chain(returns.iter().map(|returns| format!("@return {returns}")))
+            .collect();
 
-        Ok(format!("def {name}({params}): {out}"))
+        def.doc(with_tags(self.doc.clone(), tags))
+    }
+}
+
+/// Turns a declaration into the method of a native JS type, which is named after `name` in JS
+fn native(declaration: Def, name: &ScalaName) -> Stat {
+    let declaration = declaration.body(Expr::raw("js.native"));
+
+    Stat::Def(match name.js_name() {
+        Some(js_name) => declaration.annotation(format!("@JSName(\"{js_name}\")")),
+        None => declaration,
+    })
+}
+
+impl Render for Function {
+    fn render(self) -> Result<Vec<Stat>> {
+        Ok(vec![Stat::Def(self.declaration())])
     }
 }
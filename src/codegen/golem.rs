@@ -0,0 +1,156 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use color_eyre::{eyre::eyre, Result};
+use id_arena::Id;
+use wit_parser::{Interface as WitInterface, Resolve};
+
+use super::ast::{Def, Expr, Param, Stat, Template, Val};
+use crate::bundled::GOLEM_RELEASE;
+use crate::types::TypeMap;
+
+/// The Scala names of the `golem:api/host` interface and of the types its wrappers refer to,
+/// which may be disambiguated from the names of the other generated interfaces
+pub struct Host {
+    /// The facade object of the interface
+    facade: String,
+
+    /// The `persistence-level` type
+    persistence_level: String,
+
+    /// The `retry-policy` type
+    retry_policy: String,
+
+    /// The `oplog-index` type
+    oplog_index: String,
+}
+
+impl Host {
+    /// Looks the names up among the `generated` interfaces, which include the bundled host API
+    pub fn from_wit(
+        resolve: &Resolve,
+        generated: &HashSet<Id<WitInterface>>,
+        type_map: &TypeMap,
+    ) -> Result<Self> {
+        let id = generated
+            .iter()
+            .copied()
+            .find(|id| {
+                resolve
+                    .id_of(*id)
+                    .is_some_and(|path| path.starts_with("golem:api/host"))
+            })
+            .ok_or(eyre!("Interface golem:api/host not found"))?;
+        let interface = &resolve.interfaces[id];
+
+        let type_name = |name: &str| {
+            interface
+                .types
+                .get(name)
+                .and_then(|ty| type_map.get(ty))
+                .map(ToString::to_string)
+                .ok_or(eyre!("Type {name} of interface golem:api/host not found"))
+        };
+
+        Ok(Self {
+            facade: type_map
+                .interface_name(id)
+                .map(ToString::to_string)
+                .ok_or(eyre!("Interface golem:api/host has no facade"))?,
+            persistence_level: type_name("persistence-level")?,
+            retry_policy: type_name("retry-policy")?,
+            oplog_index: type_name("oplog-index")?,
+        })
+    }
+}
+
+/// A method running `body` with a setting of the Golem host API changed,
+/// restoring the previous value of the setting afterwards
+fn scoped(host: &str, doc: &str, name: &str, param: Param, getter: &str, setter: &str) -> Stat {
+    let value = param.name.clone();
+
+    Stat::Def(
+        Def::new(name)
+            .doc(Some(doc.to_owned()))
+            .tparams(vec!["A".to_owned()])
+            .params(vec![param])
+            .params(vec![Param::new("body", "=> A")])
+            .returns("A")
+            .body(Expr::Block(vec![
+                Stat::Val(Val::new("previous").rhs(Expr::raw(format!("{host}.{getter}()")))),
+                Stat::Expr(Expr::raw(format!("{host}.{setter}({value})"))),
+                Stat::Expr(Expr::raw(format!(
+                    "try body finally {host}.{setter}(previous)"
+                ))),
+            ])),
+    )
+}
+
+/// The idiomatic wrappers around the facade of the Golem host API
+pub fn wrappers(host: &Host) -> Vec<Stat> {
+    vec![Stat::Object(
+        Template::new("Golem")
+            .doc(Some(format!(
+                "Idiomatic wrappers around the host API of Golem {GOLEM_RELEASE}"
+            )))
+            .body(vec![
+                scoped(
+                    &host.facade,
+                    "Runs `body` with the given persistence level, restoring the previous one afterwards",
+                    "withPersistenceLevel",
+                    Param::new("level", &host.persistence_level),
+                    "getOplogPersistenceLevel",
+                    "setOplogPersistenceLevel",
+                ),
+                scoped(
+                    &host.facade,
+                    "Runs `body` with side effects treated as idempotent or not, restoring the previous mode afterwards",
+                    "withIdempotenceMode",
+                    Param::new("idempotent", "Boolean"),
+                    "getIdempotenceMode",
+                    "setIdempotenceMode",
+                ),
+                scoped(
+                    &host.facade,
+                    "Runs `body` with the given retry policy, restoring the previous one afterwards",
+                    "withRetryPolicy",
+                    Param::new("policy", &host.retry_policy),
+                    "getRetryPolicy",
+                    "setRetryPolicy",
+                ),
+                Stat::Def(
+                    Def::new("atomically")
+                        .doc(Some(
+                            "Runs `body` as an atomic operation, which is retried as a whole on failure"
+                                .to_owned(),
+                        ))
+                        .tparams(vec!["A".to_owned()])
+                        .params(vec![Param::new("body", "=> A")])
+                        .returns("A")
+                        .body(Expr::Block(vec![
+                            Stat::Val(
+                                Val::new("begin")
+                                    .ty(&host.oplog_index)
+                                    .rhs(Expr::raw(format!("{}.markBeginOperation()", host.facade))),
+                            ),
+                            Stat::Val(Val::new("result").rhs(Expr::raw("body"))),
+                            Stat::Expr(Expr::raw(format!("{}.markEndOperation(begin)", host.facade))),
+                            Stat::Expr(Expr::raw("result")),
+                        ])),
+                ),
+            ]),
+    )]
+}
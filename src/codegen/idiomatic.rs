@@ -0,0 +1,323 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::types::{Primitive, Type, TypeName};
+
+/// The object holding the idiomatic layer
+pub const IDIOMATIC: &str = "Idiomatic";
+
+/// The type names that the idiomatic declarations must not shadow in their companion objects,
+/// since the conversions refer to them
+pub const IDIOMATIC_HELPERS: &[&str] = &["Int", "List", "Map", "Option", "Either", "Left", "Right"];
+
+/// How the lists of string-keyed pairs, `list<tuple<string, V>>`, are represented in the
+/// idiomatic layer, later pairs winning over earlier ones with the same key
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MapRepresentation {
+    /// An immutable `Map[String, V]`
+    Map,
+
+    /// A mutable `js.Dictionary[V]`
+    Dictionary,
+}
+
+/// The idiomatic layer of the generated types, in which records and flags are case classes,
+/// variants and enums are sealed ADTs, and lists, options, results and tuples are `List`,
+/// `Option`, `Either` and Scala tuples, as seen either from the `Idiomatic` object
+/// or from the JS layer
+#[derive(Clone)]
+pub struct Layer {
+    /// The prefix of the idiomatic types
+    idiomatic: String,
+
+    /// The prefix of the JS types, which the idiomatic ones shadow within the `Idiomatic` object
+    js: String,
+
+    /// The names of the types that have an idiomatic counterpart
+    converted: HashSet<String>,
+
+    /// How all the lists of string-keyed pairs are represented, if they are maps
+    maps: Option<MapRepresentation>,
+
+    /// How the lists of string-keyed pairs within the named aliases are represented,
+    /// overriding `maps`
+    mapped: HashMap<String, MapRepresentation>,
+}
+
+impl Layer {
+    /// Creates the layer as seen from the `Idiomatic` object, the JS types being looked up
+    /// in the given package
+    pub fn new(package: &str, converted: HashSet<String>) -> Self {
+        Self {
+            idiomatic: String::new(),
+            js: format!("_root_.{package}."),
+            converted,
+            maps: None,
+            mapped: HashMap::new(),
+        }
+    }
+
+    /// Represents the lists of string-keyed pairs as maps, all of them as `maps` if any,
+    /// and the ones within the `mapped` aliases as given
+    pub fn with_maps(
+        self,
+        maps: Option<MapRepresentation>,
+        mapped: HashMap<String, MapRepresentation>,
+    ) -> Self {
+        Self {
+            maps,
+            mapped,
+            ..self
+        }
+    }
+
+    /// The layer as seen from the JS layer
+    pub fn outside(&self) -> Self {
+        Self {
+            idiomatic: format!("{IDIOMATIC}."),
+            js: String::new(),
+            ..self.clone()
+        }
+    }
+
+    /// The layer in which the named alias is declared, with the lists of string-keyed pairs
+    /// it refers to represented as configured for it
+    pub fn within(&self, alias: &str) -> Self {
+        match self.mapped.get(alias) {
+            Some(representation) => Self {
+                maps: Some(*representation),
+                ..self.clone()
+            },
+            None => self.clone(),
+        }
+    }
+
+    /// The type of the values of a list of string-keyed pairs
+    pub fn map_values(ty: &Type) -> Option<&Type> {
+        let Type::Custom(TypeName::Constructor(list)) = ty else {
+            return None;
+        };
+        let [Some(Type::Custom(TypeName::Constructor(pair)))] = list.params() else {
+            return None;
+        };
+
+        match (list.name(), pair.params()) {
+            ("WitList", [Some(Type::Primitive(Primitive::String)), Some(value)])
+                if pair.name() == "js.Tuple2" =>
+            {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// How a type is represented, if it is a list of string-keyed pairs represented as a map,
+    /// along with the type of its values
+    fn map<'a>(&self, ty: &'a Type) -> Option<(MapRepresentation, &'a Type)> {
+        self.maps.zip(Self::map_values(ty))
+    }
+
+    /// Whether the named type has an idiomatic counterpart
+    pub fn converts(&self, name: &str) -> bool {
+        self.converted.contains(name)
+    }
+
+    /// The JS type of which the named type is the idiomatic counterpart
+    pub fn js_name(&self, name: &str) -> String {
+        format!("{}{name}", self.js)
+    }
+
+    /// The idiomatic counterpart of a type, the types without one being left as is
+    pub fn ty(&self, ty: &Type) -> String {
+        let param =
+            |param: &Option<Type>| param.as_ref().map_or("Unit".to_owned(), |ty| self.ty(ty));
+
+        if let Some((representation, value)) = self.map(ty) {
+            return match representation {
+                MapRepresentation::Map => format!("Map[String, {}]", self.ty(value)),
+                MapRepresentation::Dictionary => format!("js.Dictionary[{}]", self.ty(value)),
+            };
+        }
+
+        match ty {
+            Type::Primitive(Primitive::Integer | Primitive::UnsignedInteger) => "Int".to_owned(),
+            Type::Primitive(primitive) => primitive.to_string(),
+            Type::Custom(TypeName::Concrete(name)) if self.converts(&name.to_string()) => {
+                format!("{}{name}", self.idiomatic)
+            }
+            Type::Custom(TypeName::Concrete(name)) => name.to_string(),
+            Type::Custom(TypeName::Constructor(constructor)) => {
+                let params = constructor.params();
+                match constructor.name() {
+                    "WitList" => format!("List[{}]", param(&params[0])),
+                    "WitOption" => format!("Option[{}]", param(&params[0])),
+                    "WitResult" => format!("Either[{}, {}]", param(&params[1]), param(&params[0])),
+                    _ => format!(
+                        "({})",
+                        params.iter().map(param).collect::<Vec<_>>().join(", ")
+                    ),
+                }
+            }
+        }
+    }
+
+    /// The JS representation of a type
+    pub fn js_ty(&self, ty: &Type) -> String {
+        let param = |param: &Option<Type>| {
+            param
+                .as_ref()
+                .map_or("Unit".to_owned(), |ty| self.js_ty(ty))
+        };
+
+        match ty {
+            Type::Primitive(primitive) => primitive.to_string(),
+            Type::Custom(TypeName::Concrete(name)) if self.converts(&name.to_string()) => {
+                self.js_name(&name.to_string())
+            }
+            Type::Custom(TypeName::Concrete(name)) => name.to_string(),
+            Type::Custom(TypeName::Constructor(constructor)) => {
+                let params = constructor.params();
+                match constructor.name() {
+                    "WitList" => format!("js.Array[{}]", param(&params[0])),
+                    "WitOption" => format!("js.UndefOr[{}]", param(&params[0])),
+                    "WitResult" => param(&params[0]),
+                    name => format!(
+                        "{name}[{}]",
+                        params.iter().map(param).collect::<Vec<_>>().join(", ")
+                    ),
+                }
+            }
+        }
+    }
+
+    /// The expression converting `value` from its idiomatic type to its JS representation,
+    /// the error of a result being thrown
+    pub fn to_js(&self, ty: &Type, value: &str) -> String {
+        self.convert(ty, value, 0, true)
+    }
+
+    /// The expression converting `value` from its JS representation to its idiomatic type
+    pub fn to_idiomatic(&self, ty: &Type, value: &str) -> String {
+        self.convert(ty, value, 0, false)
+    }
+
+    /// Converts `value` in either direction, the temporaries being named after the nesting `depth`
+    fn convert(&self, ty: &Type, value: &str, depth: usize, to_js: bool) -> String {
+        let suffix = if depth == 0 {
+            String::new()
+        } else {
+            depth.to_string()
+        };
+        let item = format!("item{suffix}");
+        let convert = |param: &Type, value: &str| self.convert(param, value, depth + 1, to_js);
+
+        // Maps the items of a collection, unless they are the same in both layers
+        let map = |param: &Type| {
+            let converted = convert(param, &item);
+            if converted == item {
+                String::new()
+            } else {
+                format!(".map({item} => {converted})")
+            }
+        };
+
+        if let Some((representation, value_type)) = self.map(ty) {
+            let entry = format!("entry{suffix}");
+            let converted = convert(value_type, &format!("{entry}._2"));
+            return match (representation, to_js) {
+                (_, true) => format!(
+                    "{value}.toList.map({entry} => js.Tuple2({entry}._1, {converted})).toJSArray"
+                ),
+                (MapRepresentation::Map, false) => {
+                    format!("{value}.toList.map({entry} => {entry}._1 -> {converted}).toMap")
+                }
+                (MapRepresentation::Dictionary, false) => format!(
+                    "js.Dictionary({value}.toList.map({entry} => {entry}._1 -> {converted}): _*)"
+                ),
+            };
+        }
+
+        match ty {
+            Type::Primitive(Primitive::Integer | Primitive::UnsignedInteger) => {
+                if to_js {
+                    format!("Int.box({value})")
+                } else {
+                    format!("Int.unbox({value})")
+                }
+            }
+            Type::Primitive(_) => value.to_owned(),
+            Type::Custom(TypeName::Concrete(name)) if self.converts(&name.to_string()) => {
+                let method = if to_js { "toJS" } else { "fromJS" };
+                format!("{}{name}.{method}({value})", self.idiomatic)
+            }
+            Type::Custom(TypeName::Concrete(_)) => value.to_owned(),
+            Type::Custom(TypeName::Constructor(constructor)) => {
+                let params = constructor.params();
+                match (constructor.name(), to_js) {
+                    ("WitList", true) => {
+                        let item_type = params[0].as_ref().expect("lists have an item type");
+                        format!("{value}{}.toJSArray", map(item_type))
+                    }
+                    ("WitList", false) => {
+                        let item_type = params[0].as_ref().expect("lists have an item type");
+                        format!("{value}.toList{}", map(item_type))
+                    }
+                    ("WitOption", true) => {
+                        let some = params[0].as_ref().expect("options have a type");
+                        format!("{value}{}.orUndefined", map(some))
+                    }
+                    ("WitOption", false) => {
+                        let some = params[0].as_ref().expect("options have a type");
+                        format!("{value}.toOption{}", map(some))
+                    }
+                    ("WitResult", true) => {
+                        let ok = format!("ok{suffix}");
+                        let err = format!("err{suffix}");
+                        let ok_case = match &params[0] {
+                            Some(ty) => format!("case Right({ok}) => {}", convert(ty, &ok)),
+                            None => "case Right(_) => ()".to_owned(),
+                        };
+                        let err_case = match &params[1] {
+                            Some(ty) => format!(
+                                "case Left({err}) => throw js.JavaScriptException({})",
+                                convert(ty, &err)
+                            ),
+                            None => "case Left(_) => throw js.JavaScriptException(js.undefined)"
+                                .to_owned(),
+                        };
+                        format!("{value} match {{ {ok_case}; {err_case} }}")
+                    }
+                    ("WitResult", false) => match &params[0] {
+                        Some(ty) => format!("Right({})", convert(ty, value)),
+                        None => format!("Right({value})"),
+                    },
+                    (name, _) => {
+                        let tuple = format!("tuple{suffix}");
+                        let items = params
+                            .iter()
+                            .flatten()
+                            .enumerate()
+                            .map(|(i, param)| convert(param, &format!("{tuple}._{}", i + 1)))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        let constructor = if to_js { name } else { "" };
+                        format!("{{ val {tuple} = {value}; {constructor}({items}) }}")
+                    }
+                }
+            }
+        }
+    }
+}
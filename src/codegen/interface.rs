@@ -12,19 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 
 use color_eyre::{eyre::eyre, Result};
-use convert_case::{Case, Casing};
-use id_arena::Id;
-use wit_parser::{Interface as WitInterface, TypeDefKind, TypeOwner, UnresolvedPackage};
+use convert_case::Case;
+use id_arena::{Arena, Id};
+use wit_parser::{
+    Docs, Field as WitField, FunctionKind, Interface as WitInterface, Record as WitRecord, Resolve,
+    Results as WitResults, Type as WitType, TypeDef, TypeDefKind, TypeOwner,
+};
 
-use super::{Function, Record, Render, Variant};
-use crate::types::TypeMap;
+use super::ast::{Def, Expr, Param, Stat, Template, TypeAlias, Val};
+use super::docs::scaladoc;
+use super::rest::Codecs;
+use super::{rest, rpc, Validated};
+use super::{
+    Encoding, Function, Layer, Record, Render, Resource, TypeDeclaration, IDIOMATIC, TAGLESS,
+};
+use crate::generator::Options;
+use crate::naming::{
+    identifiers, interface_name, Collisions, Locator, ScalaName, Scope, TYPE_HELPERS,
+};
+use crate::types::{TypeMap, TypeName};
 
 /// Represents the name of an interface (trait) in Scala
 #[derive(Clone)]
-struct InterfaceName(String);
+struct InterfaceName(ScalaName);
 
 impl Display for InterfaceName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -32,149 +46,1130 @@ impl Display for InterfaceName {
     }
 }
 
-impl From<&String> for InterfaceName {
-    fn from(name: &String) -> Self {
-        Self(name.to_case(Case::UpperCamel))
+impl InterfaceName {
+    /// The name of an interface, as checked for collisions with the other generated interfaces
+    fn from_wit(interface_id: Id<WitInterface>, name: &str, type_map: &TypeMap) -> Self {
+        Self(
+            type_map
+                .interface_name(interface_id)
+                .cloned()
+                .unwrap_or_else(|| interface_name(name)),
+        )
     }
 }
 
+/// The order in which the types of an interface are generated
+#[derive(Clone, Copy, Default)]
+pub enum TypeOrder {
+    /// The order in which types are declared in WIT
+    #[default]
+    Source,
+
+    /// Types come after the types they refer to, keeping the WIT declaration order otherwise
+    Dependency,
+}
+
+/// Returns the named types a WIT type refers to, looking through anonymous types
+fn dependencies(kind: &TypeDefKind, types: &Arena<TypeDef>) -> Vec<Id<TypeDef>> {
+    fn collect(ty: Option<&WitType>, types: &Arena<TypeDef>, out: &mut Vec<Id<TypeDef>>) {
+        if let Some(WitType::Id(id)) = ty {
+            if types[*id].name.is_some() {
+                out.push(*id);
+            } else {
+                out.extend(dependencies(&types[*id].kind, types));
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    match kind {
+        TypeDefKind::Record(record) => record
+            .fields
+            .iter()
+            .for_each(|field| collect(Some(&field.ty), types, &mut out)),
+        TypeDefKind::Variant(variant) => variant
+            .cases
+            .iter()
+            .for_each(|case| collect(case.ty.as_ref(), types, &mut out)),
+        TypeDefKind::Tuple(tuple) => tuple
+            .types
+            .iter()
+            .for_each(|ty| collect(Some(ty), types, &mut out)),
+        TypeDefKind::Option(ty) | TypeDefKind::List(ty) | TypeDefKind::Type(ty) => {
+            collect(Some(ty), types, &mut out)
+        }
+        TypeDefKind::Result(result) => {
+            collect(result.ok.as_ref(), types, &mut out);
+            collect(result.err.as_ref(), types, &mut out);
+        }
+        _ => {}
+    }
+    out
+}
+
 /// Represents an interface (trait) in Scala
 pub struct Interface {
     /// The name of the interface
     name: InterfaceName,
 
-    /// The records associated to the interface
-    records: Vec<Record>,
+    /// The types declared by the interface, in the order they are generated
+    types: Vec<TypeDeclaration>,
 
-    /// The variants associated to the interface
-    variants: Vec<Variant>,
+    /// The names of the types that get codecs, in the order they are generated
+    encoded: Vec<String>,
+
+    /// The codecs of the types for the encodings in test scope, declared in the test sources
+    test_instances: Vec<Stat>,
+
+    /// The resources declared by the interface, which are only generated for imported interfaces
+    resources: Vec<Resource>,
 
     /// The functions associated to the interface
     functions: Vec<Function>,
+
+    /// The Scaladoc of the interface
+    doc: Option<String>,
+
+    /// How the interface is bound to JS
+    binding: Binding,
+
+    /// The idiomatic layer the implementation of the interface is written against, if any
+    idiomatic: Option<Layer>,
+
+    /// The package of the implemented trait, when an effect-polymorphic counterpart
+    /// of the trait is generated
+    tagless: Option<String>,
+
+    /// Whether a stub and a mock of the implemented trait are generated
+    test_doubles: bool,
+
+    /// The types with a validator, when the values crossing the component boundary are checked
+    validated: Option<Validated>,
+}
+
+/// The statements an interface renders to, by where they are declared
+pub struct Rendered {
+    /// The members of the package object, which are the aliases of the types
+    pub aliases: Vec<Stat>,
+
+    /// The statements declared in the package
+    pub stats: Vec<Stat>,
+
+    /// The codecs of the types for the encodings in test scope, declared in their runtime object
+    pub test_instances: Vec<Stat>,
+
+    /// The statements declared in the package of the test sources
+    pub test_stats: Vec<Stat>,
+}
+
+/// How an interface is bound to JS
+enum Binding {
+    /// A trait implemented by the component, exported under a path such as `golem:it/api`
+    /// when a world exports the interface
+    Implemented { export_path: Option<String> },
+
+    /// A facade over the JS module with the given specifier, for an interface imported by a world
+    Imported { specifier: String },
+
+    /// A client of the interface exported by other workers, through the wasm-rpc stub
+    /// imported from the JS module with the given specifier
+    RpcClient { specifier: String },
+
+    /// A client invoking the interface on a worker through the Golem REST API
+    RestClient { codecs: Codecs },
 }
 
 impl Interface {
-    /// Constructs an `Interface` from WIT
+    /// Constructs an `Interface` from WIT. The types brought in by `use` from one of the
+    /// `generated` interfaces are left out, since that interface already declares them.
     pub fn from_wit(
-        unresolved_package: &UnresolvedPackage,
-        interface_name: &'static str,
+        resolve: &Resolve,
+        interface_id: Id<WitInterface>,
+        type_map: &TypeMap,
+        collisions: &Collisions,
+        locator: &Locator,
+        options: &Options,
+        generated: &HashSet<Id<WitInterface>>,
     ) -> Result<Self> {
-        let (interface_id, interface) = Self::get_interface(interface_name, unresolved_package)?;
-        let type_map = TypeMap::from(unresolved_package);
-        let types = &unresolved_package.types;
+        let interface = &resolve.interfaces[interface_id];
+        let interface_name = interface.name.as_deref().ok_or(eyre!(
+            "Interface with ID {interface_id:?} does not have a name"
+        ))?;
+        let types = &resolve.types;
 
-        let records: Result<Vec<Record>> = types
+        // Types are looked up in the WIT source, so that the output does not depend on the arena
+        let mut ids: Vec<(Id<TypeDef>, &String)> = types
             .iter()
-            .filter(|(_, ty)| match ty.owner {
-                TypeOwner::Interface(id) => id == interface_id,
-                _ => false,
-            })
-            .filter_map(|(_, ty)| match &ty.kind {
-                TypeDefKind::Record(record) => ty
-                    .name
-                    .as_ref()
-                    .map(|name| Record::from_wit(name, record, &type_map)),
-                _ => None,
-            })
+            .filter(|(_, ty)| ty.owner == TypeOwner::Interface(interface_id))
+            .filter(|(id, _)| !Self::declared_elsewhere(*id, resolve, type_map, generated))
+            .filter_map(|(id, ty)| ty.name.as_ref().map(|name| (id, name)))
             .collect();
+        ids.sort_by_key(|(id, name)| {
+            (
+                locator
+                    .declaration(interface_name, name)
+                    .unwrap_or(usize::MAX),
+                id.index(),
+            )
+        });
 
-        let variants: Result<Vec<Variant>> = types
+        let ids = match options.type_order {
+            TypeOrder::Source => ids,
+            TypeOrder::Dependency => Self::sort_by_dependency(ids, types),
+        };
+
+        let result_records = Self::result_records(resolve, interface_id, type_map);
+        let result_names: HashMap<String, TypeName> = result_records
             .iter()
-            .filter(|(_, ty)| match ty.owner {
-                TypeOwner::Interface(id) => id == interface_id,
-                _ => false,
-            })
-            .filter_map(|(_, ty)| match &ty.kind {
-                TypeDefKind::Variant(variant) => ty
-                    .name
-                    .as_ref()
-                    .map(|name| Variant::from_wit(name, variant, &type_map)),
-                _ => None,
-            })
+            .map(|(function, _, name, _)| (function.clone(), name.clone()))
             .collect();
 
-        let functions: Result<Vec<Function>> = interface
+        let mut declarations = Vec::new();
+        let mut resources = Vec::new();
+        let mut converted = HashSet::new();
+        let mut encoded = Vec::new();
+        let mut test_instances = Vec::new();
+        let mut mapped = HashMap::new();
+        let mut declare = |mut declaration: TypeDeclaration,
+                           kind: &TypeDefKind,
+                           name: Option<&TypeName>,
+                           wit_name: &str|
+         -> Result<()> {
+            let (member_kind, names) = declaration.member_names_mut();
+            collisions.resolve(member_kind, &[interface_name, wit_name], names)?;
+
+            if TypeDeclaration::encodable(kind, types) {
+                if let Some(name) = name {
+                    converted.insert(name.to_string());
+                    encoded.push(name.to_string());
+                }
+                for encoding in Encoding::enabled(options) {
+                    if encoding.test_scope() {
+                        test_instances.push(declaration.instance(encoding));
+                    } else {
+                        declaration = declaration.codec(encoding);
+                    }
+                }
+            }
+            declarations.push(declaration);
+
+            Ok(())
+        };
+
+        for (id, wit_name) in ids {
+            if let TypeDefKind::Resource = types[id].kind {
+                let name = type_map
+                    .get(&id)
+                    .cloned()
+                    .ok_or(eyre!("Could not find type ID {id:?} in type_map"))?;
+                let functions = interface.functions.values().filter(|function| {
+                    matches!(
+                        function.kind,
+                        FunctionKind::Method(resource)
+                            | FunctionKind::Static(resource)
+                            | FunctionKind::Constructor(resource) if resource == id
+                    )
+                });
+
+                let mut resource =
+                    Resource::from_wit(name, &types[id].docs, functions, type_map, &result_names)?;
+                collisions.resolve(
+                    "Function",
+                    &[interface_name, wit_name],
+                    resource.function_names_mut(),
+                )?;
+                Self::resolve_params(resource.functions_mut(), interface_name, collisions)?;
+
+                resources.push(resource);
+            } else {
+                let declaration = TypeDeclaration::from_wit(id, types, type_map)?.ok_or_else(|| {
+                    eyre!(
+                        "Unsupported type `{wit_name}` of interface `{interface_name}`, since `{}` cannot be generated",
+                        type_map.kind_wit_name(&types[id].kind)
+                    )
+                })?;
+                if let Some(representation) = options.map_types.get(wit_name) {
+                    match (&declaration, type_map.get(&id)) {
+                        (TypeDeclaration::Alias(alias), Some(name))
+                            if Layer::map_values(alias.target()).is_some() =>
+                        {
+                            mapped.insert(name.to_string(), *representation);
+                        }
+                        _ => {
+                            return Err(eyre!(
+                                "Type {wit_name} of interface {interface_name} is not a list of string-keyed pairs, so it cannot be a map"
+                            ))
+                        }
+                    }
+                }
+                declare(declaration, &types[id].kind, type_map.get(&id), wit_name)?;
+            }
+        }
+
+        for (_, function, name, record) in result_records {
+            let docs = Docs {
+                contents: Some(format!("The results of `{function}`")),
+            };
+            let declaration =
+                TypeDeclaration::Record(Record::from_wit(name.clone(), &record, &docs, type_map)?);
+            declare(
+                declaration,
+                &TypeDefKind::Record(record),
+                Some(&name),
+                &function,
+            )?;
+        }
+
+        let mut functions = interface
             .functions
-            .iter()
-            .map(|(_, function)| Function::from_wit(function.clone(), &type_map))
-            .collect();
+            .values()
+            .filter(|function| function.kind == FunctionKind::Freestanding)
+            .map(|function| {
+                Function::from_wit(function.clone(), type_map, result_names.get(&function.name))
+            })
+            .collect::<Result<Vec<Function>>>()?;
+
+        collisions.resolve(
+            "Function",
+            &[interface_name],
+            functions.iter_mut().map(Function::name_mut),
+        )?;
+        Self::resolve_params(functions.iter_mut(), interface_name, collisions)?;
 
         Ok(Self {
-            name: InterfaceName::from(interface.name.as_ref().ok_or(eyre!(
-                "Interface with ID {interface_id:?} does not have a name"
-            ))?),
-            records: records?,
-            variants: variants?,
-            functions: functions?,
+            name: InterfaceName::from_wit(interface_id, interface_name, type_map),
+            types: declarations,
+            encoded,
+            test_instances,
+            resources,
+            functions,
+            doc: scaladoc(&interface.docs),
+            binding: Binding::Implemented { export_path: None },
+            validated: options.validate.then(|| Validated::new(converted.clone())),
+            idiomatic: options
+                .idiomatic
+                .then(|| Layer::new(&options.package, converted).with_maps(options.maps, mapped)),
+            tagless: options.tagless.then(|| options.package.clone()),
+            test_doubles: options.test_doubles,
         })
     }
 
-    fn get_interface<'a>(
-        name: &'static str,
-        unresolved_package: &'a UnresolvedPackage,
-    ) -> Result<(Id<WitInterface>, &'a WitInterface)> {
-        unresolved_package
-            .interfaces
+    /// Exports the implementation of the interface under the given path
+    pub fn exported(self, path: String) -> Self {
+        Self {
+            binding: Binding::Implemented {
+                export_path: Some(path),
+            },
+            ..self
+        }
+    }
+
+    /// Binds the interface to the JS module with the given specifier instead of implementing it
+    pub fn imported(self, specifier: String) -> Self {
+        Self {
+            binding: Binding::Imported { specifier },
+            ..self
+        }
+    }
+
+    /// Calls the interface on other workers through the JS module of its wasm-rpc stub
+    pub fn rpc_client(self, specifier: String) -> Self {
+        Self {
+            binding: Binding::RpcClient { specifier },
+            ..self
+        }
+    }
+
+    /// Invokes the interface on a worker through the Golem REST API, exchanging values with `codecs`
+    pub fn rest_client(self, codecs: Codecs) -> Self {
+        Self {
+            binding: Binding::RestClient { codecs },
+            ..self
+        }
+    }
+
+    /// The names of the types of the interface that get codecs, in the order they are generated
+    pub fn encoded_types(&self) -> &[String] {
+        &self.encoded
+    }
+
+    /// The types of the interface that have a validator
+    pub fn validated_types(&self) -> impl Iterator<Item = &String> {
+        self.validated.iter().flat_map(Validated::names)
+    }
+
+    /// Lets the values crossing the component boundary be checked against the types
+    /// declared by other interfaces of the file, once they have a validator
+    pub fn validate_with(&mut self, names: &HashSet<String>) {
+        if let Some(validated) = &mut self.validated {
+            validated.extend(names);
+        }
+    }
+
+    /// Whether the interface declares types that get structural operations
+    pub fn has_structural_types(&self) -> bool {
+        self.types.iter().any(TypeDeclaration::is_structural)
+    }
+
+    /// The starting point of the implementation of an exported interface, which is an object
+    /// implementing every function with `???`, and a main method registering it with the export
+    pub fn skeleton(&self) -> Vec<Stat> {
+        let Binding::Implemented {
+            export_path: Some(_),
+        } = &self.binding
+        else {
+            return Vec::new();
+        };
+
+        let name = self.name.to_string();
+        let implementation = format!("{name}Impl");
+        let implemented = match self.idiomatic {
+            Some(_) => format!("{IDIOMATIC}.{name}"),
+            None => name.clone(),
+        };
+        let layer = self.idiomatic.as_ref().map(Layer::outside);
+
+        vec![
+            Stat::Object(
+                Template::new(&implementation)
+                    .doc(Some(format!(
+                        "The implementation of `{name}`, every function of which is yet to be written"
+                    )))
+                    .extends(implemented)
+                    .body(
+                        self.functions
+                            .iter()
+                            .map(|function| {
+                                function.overridden(layer.as_ref(), Expr::raw("???"))
+                            })
+                            .collect(),
+                    ),
+            ),
+            Stat::Object(
+                Template::new("Main")
+                    .doc(Some(format!(
+                        "Registers `{implementation}` with the export when the module is loaded"
+                    )))
+                    .body(vec![Stat::Def(
+                        Def::new("main")
+                            .params(vec![Param::new("args", "Array[String]")])
+                            .returns("Unit")
+                            .body(Expr::raw(format!(
+                                "{name}Export.register({implementation})"
+                            ))),
+                    )]),
+            ),
+        ]
+    }
+
+    /// The records returned by the functions with several named results, with the WIT name
+    /// and the Scaladoc label of each function, such as `CounterGetResult` for `counter.get`
+    fn result_records(
+        resolve: &Resolve,
+        interface_id: Id<WitInterface>,
+        type_map: &TypeMap,
+    ) -> Vec<(String, String, TypeName, WitRecord)> {
+        resolve.interfaces[interface_id]
+            .functions
+            .values()
+            .filter_map(|function| {
+                let WitResults::Named(results) = &function.results else {
+                    return None;
+                };
+                if results.len() < 2 {
+                    return None;
+                }
+
+                let name = type_map.result_name(interface_id, &function.name)?;
+                let label = match function.kind {
+                    FunctionKind::Method(resource) | FunctionKind::Static(resource) => format!(
+                        "{}.{}",
+                        resolve.types[resource].name.as_deref().unwrap_or_default(),
+                        function.item_name()
+                    ),
+                    _ => function.item_name().to_owned(),
+                };
+
+                let record = WitRecord {
+                    fields: results
+                        .iter()
+                        .map(|(name, ty)| WitField {
+                            name: name.clone(),
+                            ty: *ty,
+                            docs: Docs::default(),
+                        })
+                        .collect(),
+                };
+
+                Some((function.name.clone(), label, name.clone(), record))
+            })
+            .collect()
+    }
+
+    /// Checks that the params of every function map to distinct Scala identifiers
+    fn resolve_params<'a>(
+        functions: impl Iterator<Item = &'a mut Function>,
+        interface_name: &str,
+        collisions: &Collisions,
+    ) -> Result<()> {
+        for function in functions {
+            let wit_name = function.name_mut().wit().to_owned();
+
+            collisions.resolve(
+                "Param",
+                &[interface_name, &wit_name],
+                function.param_names_mut(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether the type is brought in by `use` under the same name from an interface
+    /// that is generated as well, in which case declaring it again would clash
+    fn declared_elsewhere(
+        id: Id<TypeDef>,
+        resolve: &Resolve,
+        type_map: &TypeMap,
+        generated: &HashSet<Id<WitInterface>>,
+    ) -> bool {
+        let ty = &resolve.types[id];
+
+        match ty.kind {
+            TypeDefKind::Type(WitType::Id(target)) => match resolve.types[target].owner {
+                TypeOwner::Interface(owner) => {
+                    ty.owner != TypeOwner::Interface(owner)
+                        && generated.contains(&owner)
+                        && type_map.get(&id).map(ToString::to_string)
+                            == type_map.get(&target).map(ToString::to_string)
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Sorts types so that they come after the types they refer to, keeping the given order otherwise
+    fn sort_by_dependency<'a>(
+        ids: Vec<(Id<TypeDef>, &'a String)>,
+        types: &Arena<TypeDef>,
+    ) -> Vec<(Id<TypeDef>, &'a String)> {
+        fn visit<'a>(
+            id: Id<TypeDef>,
+            names: &HashMap<Id<TypeDef>, &'a String>,
+            types: &Arena<TypeDef>,
+            visited: &mut HashSet<Id<TypeDef>>,
+            sorted: &mut Vec<(Id<TypeDef>, &'a String)>,
+        ) {
+            if !visited.insert(id) {
+                return;
+            }
+
+            for dependency in dependencies(&types[id].kind, types) {
+                if names.contains_key(&dependency) {
+                    visit(dependency, names, types, visited, sorted);
+                }
+            }
+
+            sorted.push((id, names[&id]));
+        }
+
+        let names: HashMap<_, _> = ids.iter().copied().collect();
+        let mut visited = HashSet::new();
+        let mut sorted = Vec::with_capacity(ids.len());
+
+        for (id, _) in &ids {
+            visit(*id, &names, types, &mut visited, &mut sorted);
+        }
+
+        sorted
+    }
+
+    /// The native object through which the functions of an imported interface are called
+    fn facade(
+        name: &InterfaceName,
+        doc: Option<String>,
+        functions: Vec<Function>,
+        specifier: &str,
+    ) -> Stat {
+        Stat::Object(
+            Template::new(name.to_string())
+                .doc(doc)
+                .annotation("@js.native")
+                .annotation(format!("@JSImport(\"{specifier}\", JSImport.Namespace)"))
+                .extends("js.Object")
+                .body(functions.iter().map(Function::facade).collect()),
+        )
+    }
+
+    /// The object through which the functions of an imported interface are called, which checks
+    /// the arguments against their WIT type before calling the native object it wraps
+    fn validating_facade(
+        name: &InterfaceName,
+        doc: Option<String>,
+        functions: Vec<Function>,
+        specifier: &str,
+        validated: &Validated,
+    ) -> Stat {
+        // The native object must not shadow the types referred to by the functions
+        let mut scope = Scope::new(identifiers(
+            functions
+                .iter()
+                .flat_map(|function| function.referenced_types(None)),
+        ));
+        let native = scope.fresh("Native");
+
+        let mut body = vec![Stat::Object(
+            Template::new(&native)
+                .annotation("@js.native")
+                .annotation(format!("@JSImport(\"{specifier}\", JSImport.Namespace)"))
+                .modifier("private")
+                .extends("js.Object")
+                .body(functions.iter().map(Function::facade).collect()),
+        )];
+        body.extend(
+            functions
+                .iter()
+                .map(|function| function.validating(&native, validated)),
+        );
+
+        Stat::Object(Template::new(name.to_string()).doc(doc).body(body))
+    }
+
+    /// The object exporting a registered implementation of the interface in the shape jco expects,
+    /// under the name of the interface in camel case, which componentize-js looks it up by.
+    /// With an idiomatic layer, the registered implementation is the idiomatic one,
+    /// and the params and the results are converted around the calls.
+    /// Since componentize-js does not await the exported functions, the results are returned
+    /// directly rather than as futures or promises.
+    fn export(
+        name: &InterfaceName,
+        functions: &[Function],
+        path: &str,
+        idiomatic: Option<&Layer>,
+        validated: Option<&Validated>,
+    ) -> Stat {
+        // componentize-js looks an exported interface up by its name in camel case
+        let export_name = ScalaName::new(name.0.wit(), Case::Camel, &[]);
+        let name = name.to_string();
+        let implemented = match idiomatic {
+            Some(_) => format!("{IDIOMATIC}.{name}"),
+            None => name.clone(),
+        };
+
+        // Params are included since the delegating methods refer to the registered implementation
+        let mut scope = Scope::new(functions.iter().flat_map(|function| {
+            std::iter::once(function.name())
+                .chain(function.param_names())
+                .map(|name| name.unescaped().to_owned())
+        }));
+        scope.extend(TYPE_HELPERS.iter().copied());
+        let implementation = scope.fresh("implementation");
+        let register = scope.fresh("register");
+        let registered = scope.fresh("registered");
+
+        let mut body = vec![
+            Stat::Var(
+                Val::new(&implementation)
+                    .modifier("private")
+                    .ty(format!("Option[{implemented}]"))
+                    .rhs(Expr::raw("None")),
+            ),
+            Stat::Def(
+                Def::new(&register)
+                    .doc(Some(
+                        "Registers the implementation the exported functions delegate to"
+                            .to_owned(),
+                    ))
+                    .params(vec![Param::new("api", &implemented)])
+                    .returns("Unit")
+                    .body(Expr::raw(format!("{implementation} = Some(api)"))),
+            ),
+            Stat::Def(
+                Def::new(&registered)
+                    .modifier("private")
+                    .returns(&implemented)
+                    .body(Expr::Match {
+                        scrutinee: implementation,
+                        cases: vec![
+                            ("Some(api)".to_owned(), Expr::raw("api")),
+                            (
+                                "None".to_owned(),
+                                Expr::raw(format!(
+                                    "throw new IllegalStateException(\"No implementation of {name} was registered\")"
+                                )),
+                            ),
+                        ],
+                    }),
+            ),
+        ];
+        let outside = idiomatic.map(Layer::outside);
+        body.extend(
+            functions
+                .iter()
+                .map(|function| function.export(&registered, outside.as_ref(), validated)),
+        );
+
+        Stat::Object(
+            Template::new(format!("{name}Export"))
+                .doc(Some(format!(
+                "Exports the implementation of `{name}` registered with `{register}` as `{path}`"
+            )))
+                .annotation(format!(
+                    "@JSExportTopLevel(\"{}\")",
+                    export_name.converted()
+                ))
+                .extends(&name)
+                .body(body),
+        )
+    }
+
+    /// The object holding the idiomatic counterparts of the types of the interface and the trait
+    /// of the idiomatic implementation, which the export converts from and to the JS ones
+    fn idiomatic(
+        name: &InterfaceName,
+        doc: Option<String>,
+        types: &[TypeDeclaration],
+        functions: &[Function],
+        layer: &Layer,
+    ) -> Stat {
+        let mut body: Vec<Stat> = types
+            .iter()
+            .flat_map(|declaration| declaration.idiomatic(layer))
+            .collect();
+        body.push(Stat::Trait(
+            Template::new(name.to_string()).doc(doc).body(
+                functions
+                    .iter()
+                    .map(|function| function.idiomatic(layer))
+                    .collect(),
+            ),
+        ));
+
+        Stat::Object(
+            Template::new(IDIOMATIC)
+                .doc(Some(format!(
+                    "The idiomatic counterparts of the types of `{name}`, in which the implementation registered with `{name}Export` is written"
+                )))
+                .body(body),
+        )
+    }
+
+    /// The object holding the effect-polymorphic counterpart of the implemented trait, which is
+    /// `Api[F[_]]` for an interface named `Api`, the natural transformations running common effects
+    /// as the results of the implemented trait, and the adapter from the former to the latter.
+    /// With an idiomatic layer, the implemented trait is the idiomatic one.
+    fn tagless(
+        name: &InterfaceName,
+        doc: Option<String>,
+        functions: &[Function],
+        layer: Option<&Layer>,
+        package: &str,
+    ) -> Stat {
+        let implemented = match layer {
+            Some(_) => format!("_root_.{package}.{IDIOMATIC}.{name}"),
+            None => format!("_root_.{package}.{name}"),
+        };
+        let layer = layer.map(Layer::outside);
+        let layer = layer.as_ref();
+
+        // The type params and the members of the object must not shadow the types of the signatures
+        let mut types = Scope::new(identifiers(
+            functions
+                .iter()
+                .flat_map(|function| function.referenced_types(layer)),
+        ));
+        types.extend([name.to_string()]);
+        let effect = types.fresh("F");
+        let id = types.fresh("Id");
+        let run_trait = types.fresh("Run");
+
+        let mut terms = Scope::new(functions.iter().flat_map(|function| {
+            std::iter::once(function.name())
+                .chain(function.param_names())
+                .map(|name| name.unescaped().to_owned())
+        }));
+        let api = terms.fresh("api");
+        let run = terms.fresh("run");
+
+        let signatures = functions
             .iter()
-            .find(|(_, interface)| interface.name.clone().unwrap_or_default() == name)
-            .ok_or(eyre!("Interface {name} not found"))
+            .map(|function| function.tagless(layer, &effect))
+            .collect();
+
+        // The natural transformations from common effects, with their name, the effect,
+        // and how they run an `effect` of type `F[A]`
+        let runs = [
+            ("id", id.clone(), "effect"),
+            ("attempt", "scala.util.Try".to_owned(), "effect.get"),
+            ("thunk", "Function0".to_owned(), "effect()"),
+        ]
+        .into_iter()
+        .map(|(run_name, run_effect, body)| {
+            Stat::Val(
+                Val::new(run_name)
+                    .doc(Some(format!("Runs effects of type `{run_effect}`")))
+                    .ty(format!("{run_trait}[{run_effect}]"))
+                    .rhs(Expr::New {
+                        parents: vec![format!("{run_trait}[{run_effect}]")],
+                        body: vec![Stat::Def(
+                            Def::new("apply")
+                                .tparams(vec!["A".to_owned()])
+                                .params(vec![Param::new("effect", format!("{run_effect}[A]"))])
+                                .returns("A")
+                                .body(Expr::raw(body)),
+                        )],
+                    }),
+            )
+        })
+        .collect();
+
+        Stat::Object(
+            Template::new(TAGLESS)
+                .doc(Some(format!(
+                    "The effect-polymorphic counterpart of `{name}`, whose implementations are run as `{name}` by `adapt`"
+                )))
+                .body(vec![
+                    Stat::TypeAlias(
+                        TypeAlias::new(&id)
+                            .doc(Some("The effect of computations returning their result directly".to_owned()))
+                            .tparams(vec!["A".to_owned()])
+                            .rhs("A"),
+                    ),
+                    Stat::Trait(
+                        Template::new(name.to_string())
+                            .doc(doc)
+                            .tparams(vec![format!("{effect}[_]")])
+                            .body(signatures),
+                    ),
+                    Stat::Trait(
+                        Template::new(&run_trait)
+                            .doc(Some(format!(
+                                "A natural transformation running the effects of type `{effect}` as the results of `{name}`"
+                            )))
+                            .tparams(vec![format!("{effect}[_]")])
+                            .body(vec![Stat::Def(
+                                Def::new("apply")
+                                    .doc(Some("Runs an effect".to_owned()))
+                                    .tparams(vec!["A".to_owned()])
+                                    .params(vec![Param::new("effect", format!("{effect}[A]"))])
+                                    .returns("A"),
+                            )]),
+                    ),
+                    Stat::Object(Template::new(&run_trait).body(runs)),
+                    Stat::Def(
+                        Def::new("adapt")
+                            .doc(Some(format!(
+                                "Runs `{api}` as an implementation of `{name}`, its effects being run by `{run}`"
+                            )))
+                            .tparams(vec![format!("{effect}[_]")])
+                            .params(vec![
+                                Param::new(&api, format!("{name}[{effect}]")),
+                                Param::new(&run, format!("{run_trait}[{effect}]")),
+                            ])
+                            .returns(&implemented)
+                            .body(Expr::New {
+                                parents: vec![implemented.clone()],
+                                body: functions
+                                    .iter()
+                                    .map(|function| function.adapted(layer, &api, &run))
+                                    .collect(),
+                            }),
+                    ),
+                ]),
+        )
     }
 
-    /// Renders this to a String
-    pub fn render(self, package: &str) -> Result<String> {
-        fn render(elements: Vec<impl Render>) -> Result<String> {
-            let elements: Result<Vec<String>> = elements.into_iter().map(Render::render).collect();
-            Ok(elements?.join("\n"))
+    /// The test doubles of the implemented trait: a stub, every method of which fails unless
+    /// overridden, and a mock recording its calls and returning the responses scripted for them.
+    /// With an idiomatic layer, the implemented trait is the idiomatic one.
+    fn test_doubles(
+        name: &InterfaceName,
+        functions: &[Function],
+        layer: Option<&Layer>,
+    ) -> Vec<Stat> {
+        let implemented = match layer {
+            Some(_) => format!("{IDIOMATIC}.{name}"),
+            None => name.to_string(),
+        };
+        let layer = layer.map(Layer::outside);
+        let layer = layer.as_ref();
+
+        // The members of the mock must not clash with the methods, nor be shadowed by the params
+        let mut scope = Scope::new(functions.iter().flat_map(|function| {
+            std::iter::once(function.name())
+                .chain(function.param_names())
+                .map(|name| name.unescaped().to_owned())
+        }));
+        scope.extend(TYPE_HELPERS.iter().copied());
+        let calls = scope.fresh("calls");
+
+        let mut mock = vec![Stat::Val(
+            Val::new(&calls)
+                .doc(Some(
+                    "The calls made so far, as the name of the function and its arguments, in order"
+                        .to_owned(),
+                ))
+                .ty("scala.collection.mutable.ArrayBuffer[(String, List[Any])]")
+                .rhs(Expr::raw("scala.collection.mutable.ArrayBuffer.empty")),
+        )];
+        for function in functions {
+            let function_name = function.name().unescaped();
+            let response = scope.fresh(&format!("{function_name}Response"));
+            let unscripted = format!(
+                "throw new NotImplementedError(\"No response was scripted for {function_name}\")"
+            );
+            let ignored = vec!["_"; function.param_names().count()].join(", ");
+            let doc = if ignored.is_empty() {
+                format!("The response of `{function_name}`, which fails until scripted")
+            } else {
+                format!("The response of `{function_name}` to its arguments, which fails until scripted")
+            };
+
+            mock.push(Stat::Var(
+                Val::new(&response)
+                    .doc(Some(doc))
+                    .ty(function.response_type(layer))
+                    .rhs(Expr::raw(format!("({ignored}) => {unscripted}"))),
+            ));
+            mock.push(function.overridden(
+                layer,
+                Expr::Block(vec![
+                    Stat::Expr(Expr::raw(format!(
+                        "{calls} += ((\"{function_name}\", List({})))",
+                        function.args()
+                    ))),
+                    Stat::Expr(Expr::raw(format!("{response}({})", function.args()))),
+                ]),
+            ));
         }
 
-        let records = render(self.records)?;
-        let variants = render(self.variants)?;
-        let functions = render(self.functions)?;
-        let name = self.name;
+        vec![
+            Stat::Class(
+                Template::new(format!("Stub{name}"))
+                    .doc(Some(format!(
+                        "A stub of `{implemented}`, every method of which fails unless overridden"
+                    )))
+                    .extends(&implemented)
+                    .body(
+                        functions
+                            .iter()
+                            .map(|function| {
+                                function.overridden(layer, Expr::raw("???"))
+                            })
+                            .collect(),
+                    ),
+            ),
+            Stat::Class(
+                Template::new(format!("Mock{name}"))
+                    .doc(Some(format!(
+                        "A mock of `{implemented}`, which records its calls and returns the responses scripted for them"
+                    )))
+                    .extends(&implemented)
+                    .body(mock),
+            ),
+        ]
+    }
 
-        Ok(format!(
-            "
-                // Generated by golem-scalajs-wit-bindgen
-                package {package}
+    /// The type aliases and constructors used by the generated signatures, which are declared
+    /// in the package object so that every generated type, trait and facade sees them
+    pub fn helpers() -> Vec<Stat> {
+        vec![
+            Stat::TypeAlias(
+                TypeAlias::new("WitResult")
+                    .tparams(vec!["+Ok".to_owned(), "+Err".to_owned()])
+                    .rhs("Ok"),
+            ),
+            Stat::Object(Template::new("WitResult").body(vec![
+                Stat::Def(
+                    Def::new("ok")
+                        .tparams(vec!["Ok".to_owned()])
+                        .params(vec![Param::new("value", "Ok")])
+                        .returns("WitResult[Ok, Nothing]")
+                        .body(Expr::raw("value")),
+                ),
+                Stat::Def(
+                    Def::new("err")
+                        .tparams(vec!["Err".to_owned()])
+                        .params(vec![Param::new("value", "Err")])
+                        .returns("WitResult[Nothing, Err]")
+                        .body(Expr::raw("throw js.JavaScriptException(value)")),
+                ),
+                Stat::Val(
+                    Val::new("unit")
+                        .ty("WitResult[Unit, Nothing]")
+                        .rhs(Expr::raw("()")),
+                ),
+            ])),
+            Stat::TypeAlias(
+                TypeAlias::new("WitOption")
+                    .tparams(vec!["+A".to_owned()])
+                    .rhs("js.UndefOr[A]"),
+            ),
+            Stat::Object(Template::new("WitOption").body(vec![
+                Stat::Def(
+                    Def::new("some")
+                        .tparams(vec!["A".to_owned()])
+                        .params(vec![Param::new("value", "A")])
+                        .returns("WitOption[A]")
+                        .body(Expr::raw("value")),
+                ),
+                Stat::Val(
+                    Val::new("none")
+                        .ty("WitOption[Nothing]")
+                        .rhs(Expr::raw("js.undefined")),
+                ),
+                Stat::Def(
+                    Def::new("fromOption")
+                        .tparams(vec!["A".to_owned()])
+                        .params(vec![Param::new("option", "Option[A]")])
+                        .body(Expr::Match {
+                            scrutinee: "option".to_owned(),
+                            cases: vec![
+                                (
+                                    "Some(value)".to_owned(),
+                                    Expr::raw("value.asInstanceOf[js.UndefOr[A]]"),
+                                ),
+                                ("None".to_owned(), Expr::raw("js.undefined")),
+                            ],
+                        }),
+                ),
+            ])),
+            Stat::TypeAlias(
+                TypeAlias::new("WitList")
+                    .tparams(vec!["A".to_owned()])
+                    .rhs("js.Array[A]"),
+            ),
+            Stat::Object(Template::new("WitList").body(vec![Stat::Def(
+                Def::new("fromList")
+                    .tparams(vec!["A".to_owned()])
+                    .params(vec![Param::new("list", "List[A]")])
+                    .returns("WitList[A]")
+                    .body(Expr::raw("list.toJSArray")),
+            )])),
+        ]
+    }
+}
+
+impl Interface {
+    /// Renders the interface to the statements declared in the main sources and in the test sources
+    pub fn render(self) -> Result<Rendered> {
+        fn render(elements: Vec<impl Render>) -> Result<Vec<Stat>> {
+            let elements: Result<Vec<Vec<Stat>>> =
+                elements.into_iter().map(Render::render).collect();
+            Ok(elements?.into_iter().flatten().collect())
+        }
 
-                import scala.scalajs.js
-                import scala.scalajs.js.JSConverters._
+        if let (false, Some(resource)) = (
+            matches!(self.binding, Binding::Imported { .. }),
+            self.resources.first(),
+        ) {
+            return Err(eyre!(
+                "Unsupported resource `{}` in interface `{}`, since resources are only generated for imported interfaces",
+                resource.name(),
+                self.name.0.wit()
+            ));
+        }
 
-                {records}
+        let idiomatic = match (&self.binding, &self.idiomatic) {
+            (Binding::Implemented { .. }, Some(layer)) => Some(Self::idiomatic(
+                &self.name,
+                self.doc.clone(),
+                &self.types,
+                &self.functions,
+                layer,
+            )),
+            _ => None,
+        };
 
-                {variants}
+        let tagless = match (&self.binding, &self.tagless) {
+            (Binding::Implemented { .. }, Some(package)) => Some(Self::tagless(
+                &self.name,
+                self.doc.clone(),
+                &self.functions,
+                self.idiomatic.as_ref(),
+                package,
+            )),
+            _ => None,
+        };
 
-                trait {name} {{
-                    type WitResult[+Ok, +Err] = Ok
-                    object WitResult {{
-                        def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+        let test_doubles = match &self.binding {
+            Binding::Implemented { .. } if self.test_doubles => {
+                Self::test_doubles(&self.name, &self.functions, self.idiomatic.as_ref())
+            }
+            _ => Vec::new(),
+        };
 
-                        def err[Err](value: Err): WitResult[Nothing, Err] = throw js.JavaScriptException(value)
+        let (aliases, types): (Vec<_>, Vec<_>) = self
+            .types
+            .into_iter()
+            .partition(|declaration| matches!(declaration, TypeDeclaration::Alias(_)));
+        let aliases = render(aliases)?;
+        let mut stats = render(types)?;
 
-                        val unit: WitResult[Unit, Nothing] = ()
-                    }}
+        match self.binding {
+            Binding::Implemented { export_path } => {
+                let export = export_path.as_ref().map(|path| {
+                    Self::export(
+                        &self.name,
+                        &self.functions,
+                        path,
+                        self.idiomatic.as_ref(),
+                        self.validated.as_ref(),
+                    )
+                });
 
-                    type WitOption[+A] = js.UndefOr[A]
-                    object WitOption {{
-                        def some[A](value: A): WitOption[A] = value
+                let body = self
+                    .functions
+                    .iter()
+                    .map(|function| function.implemented())
+                    .collect();
 
-                        val none: WitOption[Nothing] = js.undefined
+                stats.push(Stat::Trait(
+                    Template::new(self.name.to_string())
+                        .doc(self.doc)
+                        .body(body),
+                ));
+                stats.extend(idiomatic);
+                stats.extend(tagless);
+                stats.extend(export);
+            }
+            Binding::Imported { specifier } => {
+                for resource in self.resources {
+                    stats.extend(resource.facade(&specifier));
+                }
 
-                        def fromOption[A](option: Option[A]) =
-                        option match {{
-                            case Some(value) => value.asInstanceOf[js.UndefOr[A]]
-                            case None        => js.undefined
-                        }}
-                    }}
+                if !self.functions.is_empty() {
+                    stats.push(match &self.validated {
+                        Some(validated) => Self::validating_facade(
+                            &self.name,
+                            self.doc,
+                            self.functions,
+                            &specifier,
+                            validated,
+                        ),
+                        None => Self::facade(&self.name, self.doc, self.functions, &specifier),
+                    });
+                }
+            }
+            Binding::RpcClient { specifier } => {
+                stats.extend(rpc::client(
+                    &self.name.0,
+                    self.doc,
+                    self.functions,
+                    &specifier,
+                ));
+            }
+            Binding::RestClient { codecs } => {
+                stats.extend(rest::client(&self.name.0, self.doc, self.functions, codecs));
+            }
+        }
 
-                    type WitList[A] = js.Array[A]
-                    object WitList {{
-                        def fromList[A](list: List[A]): WitList[A] = list.toJSArray
-                    }}
-                    
-                    {functions}
-                }}
-            "
-        ))
+        Ok(Rendered {
+            aliases,
+            stats,
+            test_instances: self.test_instances,
+            test_stats: test_doubles,
+        })
     }
 }
@@ -12,20 +12,49 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod alias;
+mod ast;
+mod bindings;
+mod circe;
+mod declaration;
+mod docs;
+mod encoding;
+mod enumeration;
+mod flags;
 mod function;
+mod golem;
+mod idiomatic;
 mod interface;
+mod printer;
 mod record;
+mod resource;
+mod rest;
+mod rpc;
+mod scalacheck;
+mod structural;
+mod validation;
 mod variant;
+mod wave;
 
+pub use alias::*;
+pub use bindings::*;
+pub use declaration::*;
+pub use encoding::*;
+pub use enumeration::*;
+pub use flags::*;
 pub use function::*;
+pub use idiomatic::*;
 pub use interface::*;
+pub use printer::*;
 pub use record::*;
+pub use resource::*;
+pub use validation::Validated;
 pub use variant::*;
 
 use color_eyre::Result;
 
-/// The `Render` trait represents things that can be rendered to a `String`
+/// The `Render` trait represents things that can be rendered to Scala statements
 pub trait Render {
-    /// Renders this to a `String`
-    fn render(self) -> Result<String>;
+    /// Renders this to Scala statements
+    fn render(self) -> Result<Vec<ast::Stat>>;
 }
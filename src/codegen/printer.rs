@@ -0,0 +1,671 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ast::{Def, Expr, File, Param, Stat, Template, TypeAlias, Val};
+
+/// Prints a Scala syntax tree in a style close to the scalafmt defaults
+pub struct Printer {
+    /// The number of spaces per indentation level
+    indent: usize,
+
+    /// The column after which signatures, parameter lists, bodies and expressions are broken over
+    /// several lines, raw expressions being printed on a single line however long they are
+    width: usize,
+}
+
+impl Default for Printer {
+    fn default() -> Self {
+        Self {
+            indent: 2,
+            width: 80,
+        }
+    }
+}
+
+impl Printer {
+    /// Creates a new instance of `Printer`
+    pub fn new(indent: usize, width: usize) -> Self {
+        Self { indent, width }
+    }
+
+    /// Prints a whole file, ending with a newline. With a package object, which has to be declared
+    /// in the parent package, the statements are nested in a package of their own.
+    pub fn print(&self, file: &File) -> String {
+        let mut sections = Vec::new();
+
+        let (parent, package) = match file.package.rsplit_once('.') {
+            Some((parent, package)) if !file.package_object.is_empty() => (Some(parent), package),
+            _ if !file.package_object.is_empty() => (None, file.package.as_str()),
+            _ => (Some(file.package.as_str()), ""),
+        };
+
+        let mut preamble = Vec::new();
+        if let Some(header) = &file.header {
+            preamble.extend(header.lines().map(|line| format!("// {line}")));
+        }
+        if let Some(parent) = parent {
+            preamble.push(format!("package {parent}"));
+        }
+        if !preamble.is_empty() {
+            sections.push(preamble);
+        }
+
+        if !file.imports.is_empty() {
+            sections.push(
+                file.imports
+                    .iter()
+                    .map(|import| format!("import {import}"))
+                    .collect(),
+            );
+        }
+
+        if file.package_object.is_empty() {
+            sections.extend(file.stats.iter().map(|stat| self.stat(stat, 0)));
+        } else {
+            sections.push(self.braces(
+                &format!("package object {package}"),
+                None,
+                &file.package_object,
+                0,
+            ));
+            sections.push(self.braces(&format!("package {package}"), None, &file.stats, 0));
+        }
+
+        let mut out = sections
+            .into_iter()
+            .map(|lines| lines.join("\n"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        out.push('\n');
+        out
+    }
+
+    /// Prints statements, separating them with a blank line whenever one of them spans several lines
+    fn stats(&self, stats: &[Stat], level: usize) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::new();
+        let mut previous_multiline = false;
+
+        for (i, stat) in stats.iter().enumerate() {
+            let printed = self.stat(stat, level);
+            let multiline = printed.len() > 1;
+
+            if i > 0 && (multiline || previous_multiline) {
+                lines.push(String::new());
+            }
+
+            lines.extend(printed);
+            previous_multiline = multiline;
+        }
+
+        lines
+    }
+
+    fn stat(&self, stat: &Stat, level: usize) -> Vec<String> {
+        let (doc, lines) = match stat {
+            Stat::Class(template) => (&template.doc, self.template("class", template, level)),
+            Stat::Trait(template) => (&template.doc, self.template("trait", template, level)),
+            Stat::Object(template) => (&template.doc, self.template("object", template, level)),
+            Stat::Def(def) => (&def.doc, self.def(def, level)),
+            Stat::Val(val) => (&val.doc, self.val("val", val, level)),
+            Stat::Var(var) => (&var.doc, self.val("var", var, level)),
+            Stat::TypeAlias(alias) => (&alias.doc, vec![self.type_alias(alias, level)]),
+            Stat::Expr(expr) => (&None, self.expr(&self.pad(level), expr, "", level)),
+        };
+
+        match doc {
+            Some(doc) => self.doc(doc, level).into_iter().chain(lines).collect(),
+            None => lines,
+        }
+    }
+
+    /// Prints a Scaladoc comment in the scalafmt `SpaceAsterisk` style
+    fn doc(&self, doc: &str, level: usize) -> Vec<String> {
+        let pad = self.pad(level);
+        let lines: Vec<&str> = doc.lines().collect();
+
+        if let [line] = lines.as_slice() {
+            return vec![format!("{pad}/** {line} */")];
+        }
+
+        let mut printed = Vec::with_capacity(lines.len() + 1);
+        for (i, line) in lines.iter().enumerate() {
+            let marker = if i == 0 { "/**" } else { "  *" };
+            printed.push(format!("{pad}{marker} {line}").trim_end().to_owned());
+        }
+        printed.push(format!("{pad}  */"));
+        printed
+    }
+
+    fn template(&self, keyword: &str, template: &Template, level: usize) -> Vec<String> {
+        let annotations = template
+            .annotations
+            .iter()
+            .map(|annotation| format!("{}{annotation}", self.pad(level)));
+        let opening = format!(
+            "{}{}{keyword} {}{}",
+            self.pad(level),
+            prefix(&[], &template.modifiers),
+            template.name,
+            tparams(&template.tparams),
+        );
+        let parents = parents(&template.parents);
+
+        let mut head = match &template.params {
+            Some(params) => {
+                let head = format!(
+                    "{opening}{}{parents}",
+                    self.params(std::slice::from_ref(params))
+                );
+
+                if head.len() + 2 <= self.width || params.is_empty() {
+                    vec![head]
+                } else {
+                    let mut lines = vec![format!("{opening}(")];
+                    lines.extend(self.wrapped_params(params, level));
+                    lines.push(format!("{}){parents}", self.pad(level)));
+                    lines
+                }
+            }
+            None => vec![format!("{opening}{parents}")],
+        };
+
+        if !template.body.is_empty() || template.self_alias.is_some() {
+            let last = head.pop().unwrap_or_default();
+            head.extend(self.braces(&last, template.self_alias.as_deref(), &template.body, level));
+        }
+
+        annotations.chain(head).collect()
+    }
+
+    fn def(&self, def: &Def, level: usize) -> Vec<String> {
+        let annotations = def
+            .annotations
+            .iter()
+            .map(|annotation| format!("{}{annotation}", self.pad(level)));
+
+        annotations
+            .chain(self.def_without_annotations(def, level))
+            .collect()
+    }
+
+    fn def_without_annotations(&self, def: &Def, level: usize) -> Vec<String> {
+        let head = format!(
+            "{}{}def {}{}{}{}",
+            self.pad(level),
+            prefix(&[], &def.modifiers),
+            def.name,
+            tparams(&def.tparams),
+            self.params(&def.params),
+            def.ret
+                .as_ref()
+                .map(|ret| format!(": {ret}"))
+                .unwrap_or_default(),
+        );
+
+        // A body that does not fit is moved to the next line before breaking params
+        let fits = match &def.body {
+            None => head.len() <= self.width,
+            Some(body) if self.inline(body).is_some() => head.len() + 2 <= self.width,
+            Some(_) => head.len() + 4 <= self.width,
+        };
+
+        if fits || def.params.iter().all(Vec::is_empty) {
+            return self.definition(head, def.body.as_ref(), level);
+        }
+
+        // Breaks the param lists over several lines, one param per line
+        let opening = format!(
+            "{}{}def {}{}",
+            self.pad(level),
+            prefix(&[], &def.modifiers),
+            def.name,
+            tparams(&def.tparams),
+        );
+
+        let mut lines = Vec::new();
+        let mut current = opening;
+        for params in &def.params {
+            if params.is_empty() {
+                current.push_str("()");
+                continue;
+            }
+
+            current.push('(');
+            lines.push(current);
+            lines.extend(self.wrapped_params(params, level));
+            current = format!("{})", self.pad(level));
+        }
+
+        if let Some(ret) = &def.ret {
+            current.push_str(&format!(": {ret}"));
+        }
+
+        lines.extend(self.definition(current, def.body.as_ref(), level));
+        lines
+    }
+
+    fn val(&self, keyword: &str, val: &Val, level: usize) -> Vec<String> {
+        let head = format!(
+            "{}{}{keyword} {}{}",
+            self.pad(level),
+            prefix(&val.annotations, &val.modifiers),
+            val.name,
+            val.ty
+                .as_ref()
+                .map(|ty| format!(": {ty}"))
+                .unwrap_or_default(),
+        );
+
+        self.definition(head, val.rhs.as_ref(), level)
+    }
+
+    fn type_alias(&self, alias: &TypeAlias, level: usize) -> String {
+        format!(
+            "{}type {}{}{}",
+            self.pad(level),
+            alias.name,
+            tparams(&alias.tparams),
+            alias
+                .rhs
+                .as_ref()
+                .map(|rhs| format!(" = {rhs}"))
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Prints `head = body`, moving a body that does not fit to the next line
+    fn definition(&self, head: String, body: Option<&Expr>, level: usize) -> Vec<String> {
+        match body {
+            None => vec![head],
+            Some(body) => self.hanging(&format!("{head} ="), body, level),
+        }
+    }
+
+    /// Prints a body after `head`, such as `val a =` or `case a =>`, on the same line if it fits,
+    /// and otherwise indented on the next lines, unless it is broken after an opening parenthesis,
+    /// brace or arrow that fits on the line
+    fn hanging(&self, head: &str, body: &Expr, level: usize) -> Vec<String> {
+        let Some(inline) = self.inline(body) else {
+            return self.expr(&format!("{head} "), body, "", level);
+        };
+
+        let next = self.pad(level + 1);
+        if head.len() + 1 + inline.len() <= self.width {
+            vec![format!("{head} {inline}")]
+        } else if matches!(body, Expr::Raw(_)) || next.len() + inline.len() <= self.width {
+            vec![head.to_owned(), format!("{next}{inline}")]
+        } else {
+            // Breaking on the line of the head must save a line over breaking below it
+            let lines = self.expr(&format!("{head} "), body, "", level);
+            let below: Vec<String> = std::iter::once(head.to_owned())
+                .chain(self.expr(&next, body, "", level + 1))
+                .collect();
+            if self.opens(&lines) && lines.len() < below.len() {
+                lines
+            } else {
+                below
+            }
+        }
+    }
+
+    /// Whether the first of some lines fits and ends with an opening parenthesis, brace or arrow
+    fn opens(&self, lines: &[String]) -> bool {
+        lines.first().is_some_and(|first| {
+            first.len() <= self.width
+                && (first.ends_with('(') || first.ends_with('{') || first.ends_with("=>"))
+        })
+    }
+
+    /// Prints an expression on a single line, unless it holds statements
+    fn inline(&self, expr: &Expr) -> Option<String> {
+        Some(match expr {
+            Expr::Raw(raw) => raw.clone(),
+            Expr::New { parents, body } if body.is_empty() => {
+                format!("new {} {{}}", parents.join(" with "))
+            }
+            Expr::Block(_) | Expr::New { .. } | Expr::Match { .. } => return None,
+            Expr::Call { fun, args } => {
+                let fun = self.inline(fun)?;
+                match args.as_slice() {
+                    [lambda @ Expr::Lambda { braces: true, .. }] => {
+                        format!("{fun} {}", self.inline(lambda)?)
+                    }
+                    _ => format!(
+                        "{fun}({})",
+                        args.iter()
+                            .map(|arg| self.inline(arg))
+                            .collect::<Option<Vec<_>>>()?
+                            .join(", ")
+                    ),
+                }
+            }
+            Expr::Select { qualifier, name } => format!("{}.{name}", self.inline(qualifier)?),
+            Expr::Lambda {
+                params,
+                body,
+                braces,
+            } => {
+                let body = self.inline(body)?;
+                if *braces {
+                    format!("{{ {params} => {body} }}")
+                } else {
+                    format!("{params} => {body}")
+                }
+            }
+            Expr::Infix { op, operands } => operands
+                .iter()
+                .map(|operand| self.inline(operand))
+                .collect::<Option<Vec<_>>>()?
+                .join(&format!(" {op} ")),
+            Expr::If {
+                cond,
+                then,
+                otherwise,
+            } => format!(
+                "if ({}) {} else {}",
+                self.inline(cond)?,
+                self.inline(then)?,
+                self.inline(otherwise)?
+            ),
+            Expr::Ascribe { expr, ty } => format!("{}: {ty}", self.inline(expr)?),
+        })
+    }
+
+    /// Prints an expression whose first line is appended to `head` and whose last line is
+    /// followed by `tail`, breaking it over several lines when it does not fit
+    fn expr(&self, head: &str, expr: &Expr, tail: &str, level: usize) -> Vec<String> {
+        if let Some(inline) = self.inline(expr) {
+            if matches!(expr, Expr::Raw(_)) || head.len() + inline.len() + tail.len() <= self.width
+            {
+                return vec![format!("{head}{inline}{tail}")];
+            }
+        }
+
+        let mut lines = match expr {
+            Expr::Call { fun, args } => return self.call(head, fun, args, tail, level),
+            Expr::Select { qualifier, name } => {
+                // A member of a broken call or block is selected after its closing delimiter
+                let mut lines = self.expr(head, qualifier, "", level);
+                let closed = lines.len() > 1
+                    && lines
+                        .last()
+                        .is_some_and(|last| [")", "}"].contains(&last.trim()));
+                match lines.last_mut() {
+                    Some(last) if closed => last.push_str(&format!(".{name}")),
+                    _ => lines.push(format!("{}.{name}", self.pad(level + 1))),
+                }
+                lines
+            }
+            Expr::Lambda {
+                params,
+                body,
+                braces: true,
+            } => {
+                let mut lines = vec![format!("{head}{{ {params} =>")];
+                lines.extend(self.expr(&self.pad(level + 1), body, "", level + 1));
+                lines.push(format!("{}}}", self.pad(level)));
+                lines
+            }
+            Expr::Lambda { params, body, .. } => {
+                return std::iter::once(format!("{head}{params} =>"))
+                    .chain(self.expr(&self.pad(level + 1), body, tail, level + 1))
+                    .collect();
+            }
+            Expr::Infix { op, operands } => {
+                let last = operands.len().saturating_sub(1);
+                let mut lines = Vec::new();
+                for (i, operand) in operands.iter().enumerate() {
+                    let (head, level) = match i {
+                        0 => (head.to_owned(), level),
+                        _ => (self.pad(level + 1), level + 1),
+                    };
+                    let tail = if i < last {
+                        format!(" {op}")
+                    } else {
+                        tail.to_owned()
+                    };
+
+                    // A last operand that is broken anyway opens on the line of the operator
+                    let fits = self
+                        .inline(operand)
+                        .is_some_and(|inline| head.len() + inline.len() + tail.len() <= self.width);
+                    if i == last && i > 0 && !fits {
+                        let previous = lines.pop().unwrap_or_default();
+                        let indent = if i == 1 { level - 1 } else { level };
+                        let hugged = self.expr(&format!("{previous} "), operand, &tail, indent);
+                        if self.opens(&hugged) {
+                            lines.extend(hugged);
+                            break;
+                        }
+                        lines.push(previous);
+                    }
+
+                    lines.extend(self.expr(&head, operand, &tail, level));
+                }
+                return lines;
+            }
+            Expr::If {
+                cond,
+                then,
+                otherwise,
+            } => {
+                // The condition continues deeper than the branches
+                let mut lines = self.expr(&format!("{head}if ("), cond, ")", level + 1);
+                if let Expr::Block(_) = **then {
+                    // A block keeps its braces on the lines of the condition and of `else`
+                    let last = lines.pop().unwrap_or_default();
+                    lines.extend(self.expr(&format!("{last} "), then, "", level));
+                    lines.pop();
+                    let mut otherwise =
+                        self.hanging(&format!("{}}} else", self.pad(level)), otherwise, level);
+                    if let Some(last) = otherwise.last_mut() {
+                        last.push_str(tail);
+                    }
+                    lines.extend(otherwise);
+                    return lines;
+                }
+                lines.extend(self.expr(&self.pad(level + 1), then, "", level + 1));
+                if let Expr::If { .. } = **otherwise {
+                    lines.extend(self.expr(
+                        &format!("{}else ", self.pad(level)),
+                        otherwise,
+                        tail,
+                        level,
+                    ));
+                } else {
+                    lines.push(format!("{}else", self.pad(level)));
+                    lines.extend(self.expr(&self.pad(level + 1), otherwise, tail, level + 1));
+                }
+                return lines;
+            }
+            Expr::Ascribe { expr, ty } => {
+                return self.expr(head, expr, &format!(": {ty}{tail}"), level)
+            }
+            Expr::Raw(raw) => vec![format!("{head}{raw}")],
+            Expr::Block(stats) => {
+                let mut lines = vec![format!("{head}{{")];
+                lines.extend(self.stats(stats, level + 1));
+                lines.push(format!("{}}}", self.pad(level)));
+                lines
+            }
+            Expr::New { parents, body } => {
+                let head = format!("{head}new {}", parents.join(" with "));
+                self.braces(&head, None, body, level)
+            }
+            Expr::Match { scrutinee, cases } => {
+                let mut lines = vec![format!("{head}{scrutinee} match {{")];
+                for (pattern, body) in cases {
+                    let case = format!("{}case {pattern} =>", self.pad(level + 1));
+                    lines.extend(self.hanging(&case, body, level + 1));
+                }
+                lines.push(format!("{}}}", self.pad(level)));
+                lines
+            }
+        };
+
+        if let Some(last) = lines.last_mut() {
+            last.push_str(tail);
+        }
+        lines
+    }
+
+    /// Prints a call that does not fit on a line. A method called on the result of another call
+    /// goes on a line of its own, and otherwise the args are broken, after those of a call
+    /// returning the called function when it does not fit either.
+    fn call(&self, head: &str, fun: &Expr, args: &[Expr], tail: &str, level: usize) -> Vec<String> {
+        match (fun, self.inline(fun)) {
+            (Expr::Select { qualifier, name }, _) if matches!(**qualifier, Expr::Call { .. }) => {
+                let mut lines = self.expr(head, qualifier, "", level);
+                lines.extend(self.args(
+                    &format!("{}.{name}", self.pad(level + 1)),
+                    args,
+                    tail,
+                    level + 1,
+                ));
+                lines
+            }
+            // A curried call keeps its function on one line if there is room for a parenthesis
+            (_, Some(inline))
+                if !matches!(fun, Expr::Call { .. }) || head.len() + inline.len() < self.width =>
+            {
+                self.args(&format!("{head}{inline}"), args, tail, level)
+            }
+            _ => {
+                let mut lines = self.expr(head, fun, "", level);
+                let last = lines.pop().unwrap_or_default();
+                lines.extend(self.args(&last, args, tail, level));
+                lines
+            }
+        }
+    }
+
+    /// Prints the args of a call after `fun`, on the same line if they fit, and otherwise one per
+    /// line, a lambda that is the only arg staying on the line of the call
+    fn args(&self, fun: &str, args: &[Expr], tail: &str, level: usize) -> Vec<String> {
+        let inline: Option<Vec<_>> = args.iter().map(|arg| self.inline(arg)).collect();
+        if let Some(inline) = inline {
+            let line = match args {
+                [Expr::Lambda { braces: true, .. }] => format!("{fun} {}", inline[0]),
+                _ => format!("{fun}({})", inline.join(", ")),
+            };
+            if line.len() + tail.len() <= self.width {
+                return vec![format!("{line}{tail}")];
+            }
+        }
+
+        let pad = self.pad(level);
+        match args {
+            [Expr::Lambda {
+                params,
+                body,
+                braces,
+            }] => {
+                let (open, close) = if *braces { (" { ", "}") } else { ("(", ")") };
+                let mut lines = vec![format!("{fun}{open}{params} =>")];
+                lines.extend(self.expr(&self.pad(level + 1), body, "", level + 1));
+                lines.push(format!("{pad}{close}{tail}"));
+                lines
+            }
+            _ => {
+                let last = args.len().saturating_sub(1);
+                let mut lines = vec![format!("{fun}(")];
+                for (i, arg) in args.iter().enumerate() {
+                    let separator = if i < last { "," } else { "" };
+                    lines.extend(self.expr(&self.pad(level + 1), arg, separator, level + 1));
+                }
+                lines.push(format!("{pad}){tail}"));
+                lines
+            }
+        }
+    }
+
+    /// Prints `head {` followed by the statements and a closing brace
+    fn braces(
+        &self,
+        head: &str,
+        self_alias: Option<&str>,
+        stats: &[Stat],
+        level: usize,
+    ) -> Vec<String> {
+        let opening = match self_alias {
+            Some(alias) => format!("{head} {{ {alias} =>"),
+            None => format!("{head} {{"),
+        };
+
+        let mut lines = vec![opening];
+        lines.extend(self.stats(stats, level + 1));
+        lines.push(format!("{}}}", self.pad(level)));
+        lines
+    }
+
+    /// Prints param lists on a single line
+    fn params(&self, params: &[Vec<Param>]) -> String {
+        params
+            .iter()
+            .map(|params| {
+                let params = params.iter().map(param).collect::<Vec<_>>().join(", ");
+                format!("({params})")
+            })
+            .collect()
+    }
+
+    /// Prints params one per line, for a definition at `level`
+    fn wrapped_params(&self, params: &[Param], level: usize) -> Vec<String> {
+        let last = params.len().saturating_sub(1);
+
+        params
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let separator = if i < last { "," } else { "" };
+                format!("{}{}{separator}", self.pad(level + 2), param(p))
+            })
+            .collect()
+    }
+
+    fn pad(&self, level: usize) -> String {
+        " ".repeat(level * self.indent)
+    }
+}
+
+fn param(param: &Param) -> String {
+    match &param.default {
+        Some(default) => format!("{}: {} = {default}", param.name, param.ty),
+        None => format!("{}: {}", param.name, param.ty),
+    }
+}
+
+fn prefix(annotations: &[String], modifiers: &[String]) -> String {
+    annotations
+        .iter()
+        .chain(modifiers)
+        .map(|word| format!("{word} "))
+        .collect()
+}
+
+fn tparams(tparams: &[String]) -> String {
+    if tparams.is_empty() {
+        String::new()
+    } else {
+        format!("[{}]", tparams.join(", "))
+    }
+}
+
+fn parents(parents: &[String]) -> String {
+    if parents.is_empty() {
+        String::new()
+    } else {
+        format!(" extends {}", parents.join(" with "))
+    }
+}
@@ -15,14 +15,17 @@
 use std::fmt::Display;
 
 use color_eyre::Result;
-use convert_case::{Case, Casing};
-use wit_parser::{Field as WitField, Record as WitRecord};
+use convert_case::Case;
+use wit_parser::{Docs, Field as WitField, Flags as WitFlags, Record as WitRecord};
 
-use super::Render;
-use crate::types::{ConcreteName, Type, TypeMap, TypeName};
+use super::ast::{Def, Expr, Param, Stat, Template, Val};
+use super::docs::{scaladoc, with_tags};
+use super::{structural, Encoding, Layer, Render};
+use crate::naming::{identifiers, ScalaName, Scope};
+use crate::types::{Primitive, Type, TypeMap, TypeName};
 
 /// Represents the name of a record field in Scala
-struct FieldName(String);
+struct FieldName(ScalaName);
 
 impl Display for FieldName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -32,7 +35,7 @@ impl Display for FieldName {
 
 impl From<String> for FieldName {
     fn from(name: String) -> Self {
-        Self(name.to_case(Case::Camel))
+        Self(ScalaName::new(&name, Case::Camel, &[]))
     }
 }
 
@@ -43,6 +46,12 @@ struct Field {
 
     /// The Scala type associated to the field
     ty: Type,
+
+    /// The default value of the field in `apply`
+    default: Option<String>,
+
+    /// The Scaladoc of the field
+    doc: Option<String>,
 }
 
 impl Field {
@@ -51,6 +60,8 @@ impl Field {
         Ok(Self {
             name: FieldName::from(field.name),
             ty: Type::from_wit(field.ty, type_map)?,
+            default: None,
+            doc: scaladoc(&field.docs),
         })
     }
 }
@@ -62,11 +73,22 @@ pub struct Record {
 
     /// The record fields
     fields: Vec<Field>,
+
+    /// The Scaladoc of the record
+    doc: Option<String>,
+
+    /// The implicit instances declared in the companion object
+    instances: Vec<Stat>,
 }
 
 impl Record {
     // Constructs a `Record` from WIT
-    pub fn from_wit(name: &str, record: &WitRecord, type_map: &TypeMap) -> Result<Self> {
+    pub fn from_wit(
+        name: TypeName,
+        record: &WitRecord,
+        docs: &Docs,
+        type_map: &TypeMap,
+    ) -> Result<Self> {
         let fields: Result<Vec<Field>> = record
             .clone()
             .fields
@@ -75,54 +97,325 @@ impl Record {
             .collect();
 
         Ok(Self {
-            name: TypeName::Concrete(ConcreteName::from(name.to_owned())),
+            name,
             fields: fields?,
+            doc: scaladoc(docs),
+            instances: Vec::new(),
         })
     }
+
+    /// Constructs a `Record` of booleans defaulting to `false`, which is how flags are represented
+    pub fn from_flags(name: TypeName, flags: &WitFlags, docs: &Docs) -> Self {
+        Self {
+            name,
+            fields: flags
+                .flags
+                .iter()
+                .map(|flag| Field {
+                    name: FieldName::from(flag.name.clone()),
+                    ty: Type::Primitive(Primitive::Boolean),
+                    default: Some("false".to_owned()),
+                    doc: scaladoc(&flag.docs),
+                })
+                .collect(),
+            doc: scaladoc(docs),
+            instances: Vec::new(),
+        }
+    }
+
+    /// Declares the codec of the record in its companion object
+    pub fn codec(mut self, encoding: Encoding) -> Self {
+        let instance = self.instance(encoding);
+        self.instances.push(instance);
+        self
+    }
+
+    /// The implicit codec of the record
+    pub fn instance(&self, encoding: Encoding) -> Stat {
+        let runtime = encoding.runtime();
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                Expr::raw(format!("{runtime}.Field")).call(vec![
+                    Expr::raw(format!("\"{}\"", field.name.0.converted())),
+                    Expr::raw(format!("\"{}\"", field.name.0.wit())),
+                    Expr::raw(encoding.codec(&field.ty)),
+                ])
+            })
+            .collect();
+
+        let name = self.name.to_string();
+        encoding.instance(
+            &name,
+            Expr::raw(format!("{runtime}.record[{name}]")).call(fields),
+        )
+    }
+
+    /// Declares the codec of the record as flags in its companion object
+    pub fn flags_codec(mut self, encoding: Encoding) -> Self {
+        let instance = self.flags_instance(encoding);
+        self.instances.push(instance);
+        self
+    }
+
+    /// The implicit codec of the record as flags, which lists the fields that are `true`
+    pub fn flags_instance(&self, encoding: Encoding) -> Stat {
+        let runtime = encoding.runtime();
+        let flags = self
+            .fields
+            .iter()
+            .map(|field| {
+                Expr::raw(format!(
+                    "{runtime}.Flag(\"{}\", \"{}\")",
+                    field.name.0.converted(),
+                    field.name.0.wit()
+                ))
+            })
+            .collect();
+
+        let name = self.name.to_string();
+        encoding.instance(
+            &name,
+            Expr::raw(format!("{runtime}.flags[{name}]")).call(flags),
+        )
+    }
+
+    /// The idiomatic counterpart of the record, a case class whose companion object converts it
+    /// from and to the JS representation
+    pub fn idiomatic(&self, layer: &Layer) -> Vec<Stat> {
+        let name = self.name.to_string();
+        if !layer.converts(&name) {
+            return Vec::new();
+        }
+
+        let js = layer.js_name(&name);
+
+        let params = self
+            .fields
+            .iter()
+            .map(|field| {
+                let param = Param::new(field.name.to_string(), layer.ty(&field.ty));
+                match &field.default {
+                    Some(default) => param.default(default),
+                    None => param,
+                }
+            })
+            .collect();
+        let tags = self
+            .fields
+            .iter()
+            .filter_map(|field| {
+                field
+                    .doc
+                    .as_ref()
+                    .map(|doc| format!("@param {} {doc}", field.name))
+            })
+            .collect();
+
+        let convert = |convert: &dyn Fn(&Type, &str) -> String| {
+            self.fields
+                .iter()
+                .map(|field| convert(&field.ty, &format!("value.{}", field.name)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        vec![
+            Stat::Class(
+                Template::new(&name)
+                    .doc(with_tags(self.doc.clone(), tags))
+                    .modifier("final case")
+                    .params(params),
+            ),
+            Stat::Object(Template::new(&name).body(vec![
+                Stat::Def(
+                    Def::new("toJS")
+                        .doc(Some(format!("Converts `{name}` to its JS representation")))
+                        .params(vec![Param::new("value", &name)])
+                        .returns(&js)
+                        .body(Expr::raw(format!(
+                            "{js}({})",
+                            convert(&|ty, value| layer.to_js(ty, value))
+                        ))),
+                ),
+                Stat::Def(
+                    Def::new("fromJS")
+                        .doc(Some(format!("Converts the JS representation of `{name}`")))
+                        .params(vec![Param::new("value", &js)])
+                        .returns(&name)
+                        .body(Expr::raw(format!(
+                            "{name}({})",
+                            convert(&|ty, value| layer.to_idiomatic(ty, value))
+                        ))),
+                ),
+            ])),
+        ]
+    }
+
+    /// The `copy` method and the structural operations added to the record, which leave
+    /// its JS representation untouched
+    fn ops(&self, name: &str) -> Stat {
+        // The names declared in the companion object must not shadow the types of the fields
+        let ops = Scope::new(
+            identifiers(self.fields.iter().map(|field| field.ty.to_string()))
+                .iter()
+                .map(String::as_str)
+                .chain([name]),
+        )
+        .fresh("Ops");
+        let self_name =
+            Scope::new(self.fields.iter().map(|field| field.name.0.unescaped())).fresh("self");
+
+        let copy_params = self
+            .fields
+            .iter()
+            .map(|field| {
+                Param::new(field.name.to_string(), field.ty.to_string())
+                    .default(format!("{self_name}.{}", field.name))
+            })
+            .collect();
+        let args = self
+            .fields
+            .iter()
+            .map(|field| field.name.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Stat::Class(
+            Template::new(ops)
+                .doc(Some(format!(
+                    "The structural methods of `{name}`, which leave its JS representation untouched"
+                )))
+                .modifier("implicit final")
+                .params(vec![Param::new(&self_name, name)])
+                .extends("AnyVal")
+                .body(
+                    [Stat::Def(
+                        Def::new("copy")
+                            .doc(Some(format!(
+                                "A new `{name}` with the given fields replaced"
+                            )))
+                            .params(copy_params)
+                            .returns(name)
+                            .body(Expr::raw(format!("{name}({args})"))),
+                    )]
+                    .into_iter()
+                    .chain(structural::operations(name, &self_name, name))
+                    .collect(),
+                ),
+        )
+    }
+
+    /// The names of the fields, so that collisions between them can be resolved
+    pub fn field_names_mut(&mut self) -> impl Iterator<Item = &mut ScalaName> {
+        self.fields.iter_mut().map(|field| &mut field.name.0)
+    }
 }
 
 impl Render for Record {
-    fn render(self) -> Result<String> {
-        fn render<F>(fields: &[Field], sep: &str, formatter: F) -> String
-        where
-            F: FnMut(&Field) -> String,
-        {
-            fields.iter().map(formatter).collect::<Vec<_>>().join(sep)
+    fn render(self) -> Result<Vec<Stat>> {
+        fn annotate(val: Val, name: &FieldName) -> Val {
+            match name.0.js_name() {
+                Some(js_name) => val.annotation(format!("@JSName(\"{js_name}\")")),
+                None => val,
+            }
         }
 
-        let fields = render(&self.fields, "\n", |Field { name, ty }| {
-            format!("val {name}: {ty}")
-        });
-
-        let apply_params = render(&self.fields, ", ", |Field { name, ty }| {
-            format!("{name}: {ty}")
-        });
-
-        let apply_temp_vars = render(&self.fields, "\n", |Field { name, ty }| {
-            format!("val {name}0: {ty} = {name}")
-        });
-
-        let new_vars = render(&self.fields, "\n", |Field { name, ty }| {
-            format!("val {name}: {ty} = {name}0")
-        });
-
-        let name = self.name;
-
-        Ok(format!(
-            "
-                sealed trait {name} extends js.Object {{
-                    {fields}
-                }}
-                object {name} {{
-                    def apply({apply_params}): {name} = {{
-                        {apply_temp_vars}
-
-                        new {name} {{
-                            {new_vars}
-                        }}
-                    }}
-                }}
-            "
-        ))
+        let name = self.name.to_string();
+
+        let mut scope = Scope::new(self.fields.iter().map(|field| field.name.0.unescaped()));
+        let temp_vars: Vec<String> = self
+            .fields
+            .iter()
+            .map(|field| scope.fresh(&format!("{}0", field.name.0.unescaped())))
+            .collect();
+
+        let fields = self
+            .fields
+            .iter()
+            .map(|Field { name, ty, doc, .. }| {
+                Stat::Val(annotate(
+                    Val::new(name.to_string())
+                        .doc(doc.clone())
+                        .ty(ty.to_string()),
+                    name,
+                ))
+            })
+            .collect();
+
+        let apply_params = self
+            .fields
+            .iter()
+            .map(
+                |Field {
+                     name, ty, default, ..
+                 }| {
+                    let param = Param::new(name.to_string(), ty.to_string());
+                    match default {
+                        Some(default) => param.default(default),
+                        None => param,
+                    }
+                },
+            )
+            .collect();
+
+        let mut apply_body: Vec<Stat> = self
+            .fields
+            .iter()
+            .zip(&temp_vars)
+            .map(|(Field { name, ty, .. }, temp_var)| {
+                Stat::Val(
+                    Val::new(temp_var)
+                        .ty(ty.to_string())
+                        .rhs(Expr::raw(name.to_string())),
+                )
+            })
+            .collect();
+
+        let new_vars = self
+            .fields
+            .iter()
+            .zip(&temp_vars)
+            .map(|(Field { name, ty, .. }, temp_var)| {
+                Stat::Val(annotate(
+                    Val::new(name.to_string())
+                        .ty(ty.to_string())
+                        .rhs(Expr::raw(temp_var)),
+                    name,
+                ))
+            })
+            .collect();
+
+        apply_body.push(Stat::Expr(Expr::New {
+            parents: vec![name.clone()],
+            body: new_vars,
+        }));
+
+        let ops = self.ops(&name);
+
+        Ok(vec![
+            Stat::Trait(
+                Template::new(&name)
+                    .doc(self.doc)
+                    .modifier("sealed")
+                    .extends("js.Object")
+                    .body(fields),
+            ),
+            Stat::Object(
+                Template::new(&name).body(
+                    [Stat::Def(
+                        Def::new("apply")
+                            .params(apply_params)
+                            .returns(&name)
+                            .body(Expr::Block(apply_body)),
+                    )]
+                    .into_iter()
+                    .chain([ops])
+                    .chain(self.instances)
+                    .collect(),
+                ),
+            ),
+        ])
     }
 }
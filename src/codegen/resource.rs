@@ -0,0 +1,131 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use color_eyre::Result;
+use wit_parser::{Docs, Function as WitFunction, FunctionKind};
+
+use super::ast::{Stat, Template};
+use super::docs::scaladoc;
+use super::Function;
+use crate::naming::ScalaName;
+use crate::types::{TypeMap, TypeName};
+
+/// Represents a resource in Scala, which is a class on the JS side
+pub struct Resource {
+    /// The resource name
+    name: TypeName,
+
+    /// The constructor of the resource, if it has one
+    constructor: Option<Function>,
+
+    /// The methods called on instances of the resource
+    methods: Vec<Function>,
+
+    /// The static functions of the resource
+    statics: Vec<Function>,
+
+    /// The Scaladoc of the resource
+    doc: Option<String>,
+}
+
+impl Resource {
+    /// Constructs a `Resource` from WIT, out of the functions of the interface that belong to it
+    pub fn from_wit<'a>(
+        name: TypeName,
+        docs: &Docs,
+        functions: impl IntoIterator<Item = &'a WitFunction>,
+        type_map: &TypeMap,
+        result_records: &HashMap<String, TypeName>,
+    ) -> Result<Self> {
+        let mut resource = Self {
+            name,
+            constructor: None,
+            methods: Vec::new(),
+            statics: Vec::new(),
+            doc: scaladoc(docs),
+        };
+
+        for function in functions {
+            let converted = Function::from_wit(
+                function.clone(),
+                type_map,
+                result_records.get(&function.name),
+            )?;
+
+            match function.kind {
+                FunctionKind::Constructor(_) => resource.constructor = Some(converted),
+                FunctionKind::Method(_) => resource.methods.push(converted),
+                FunctionKind::Static(_) => resource.statics.push(converted),
+                FunctionKind::Freestanding => {}
+            }
+        }
+
+        Ok(resource)
+    }
+
+    /// The name of the resource
+    pub fn name(&self) -> &TypeName {
+        &self.name
+    }
+
+    /// The names of the methods and static functions, so that collisions between them can be resolved
+    pub fn function_names_mut(&mut self) -> impl Iterator<Item = &mut ScalaName> {
+        self.methods
+            .iter_mut()
+            .chain(self.statics.iter_mut())
+            .map(Function::name_mut)
+    }
+
+    /// The functions of the resource, so that collisions between their params can be resolved
+    pub fn functions_mut(&mut self) -> impl Iterator<Item = &mut Function> {
+        self.constructor
+            .iter_mut()
+            .chain(self.methods.iter_mut())
+            .chain(self.statics.iter_mut())
+    }
+
+    /// The native class (and companion object for static functions) importing the resource
+    /// from the JS module with the given specifier
+    pub fn facade(self, specifier: &str) -> Vec<Stat> {
+        let name = self.name.to_string();
+        let import = format!("@JSImport(\"{specifier}\", \"{}\")", self.name.js_name());
+
+        let mut class = Template::new(&name)
+            .doc(self.doc)
+            .annotation("@js.native")
+            .annotation(&import)
+            .extends("js.Object")
+            .body(self.methods.iter().map(Function::facade).collect());
+
+        if let Some(constructor) = &self.constructor {
+            class = class.params(constructor.params());
+        }
+
+        let mut stats = vec![Stat::Class(class)];
+
+        if !self.statics.is_empty() {
+            stats.push(Stat::Object(
+                Template::new(&name)
+                    .annotation("@js.native")
+                    .annotation(import)
+                    .extends("js.Object")
+                    .body(self.statics.iter().map(Function::facade).collect()),
+            ));
+        }
+
+        stats
+    }
+}
@@ -0,0 +1,1031 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Clients invoking the main interface on a worker through the Golem REST API, with params
+//! and results in the typed JSON value format of Golem
+
+use std::collections::HashMap;
+
+use color_eyre::{eyre::eyre, Result};
+use convert_case::Case;
+use id_arena::Id;
+use wit_parser::{
+    FunctionKind, Interface as WitInterface, Resolve, Results as WitResults, Type as WitType,
+    TypeDef, TypeDefKind,
+};
+
+use super::ast::{Def, Expr, Param, Stat, Template, Val};
+use super::Function;
+use crate::naming::{escape, ScalaName, Scope, OBJECT_MEMBERS};
+use crate::types::TypeMap;
+
+/// The members of the companion object of the client, which generated members must not shadow
+const RUNTIME: &[&str] = &[
+    "apply",
+    "unapply",
+    "Codec",
+    "GolemError",
+    "Signature",
+    "executionContext",
+    "analysed",
+    "primitive",
+    "bool",
+    "s8",
+    "u8",
+    "s16",
+    "u16",
+    "s32",
+    "u32",
+    "s64",
+    "u64",
+    "f32",
+    "f64",
+    "chr",
+    "str",
+    "list",
+    "option",
+    "result",
+    "tuple",
+    "record",
+    "variant",
+    "enumeration",
+    "flags",
+    "typeOf",
+    "bigIntMarker",
+    "stringify",
+    "exact",
+    "parse",
+    "invoke",
+    "decode",
+];
+
+/// The codecs of the values an interface exchanges with workers
+pub struct Codecs {
+    /// The path of the interface, such as `golem:shop/api`, which qualifies its function names
+    path: String,
+
+    /// The names given to the codecs of named types
+    names: HashMap<Id<TypeDef>, String>,
+
+    /// The codecs of named types, as the name and the definition of each
+    named: Vec<(String, Expr)>,
+
+    /// The signatures of the functions by WIT name, as the name and the definition of each
+    signatures: HashMap<String, (String, Expr)>,
+
+    /// The names taken in the companion object of the client
+    scope: Scope,
+}
+
+impl Codecs {
+    /// Constructs the `Codecs` of the params and results of the freestanding functions
+    /// of an interface, which is invoked under the given path
+    pub fn from_wit(
+        resolve: &Resolve,
+        interface_id: Id<WitInterface>,
+        type_map: &TypeMap,
+        path: String,
+    ) -> Result<Self> {
+        let mut codecs = Self {
+            path,
+            names: HashMap::new(),
+            named: Vec::new(),
+            signatures: HashMap::new(),
+            scope: Scope::new(RUNTIME.iter().chain(OBJECT_MEMBERS).copied()),
+        };
+
+        let functions = resolve.interfaces[interface_id]
+            .functions
+            .values()
+            .filter(|function| function.kind == FunctionKind::Freestanding);
+
+        for function in functions {
+            let params = function
+                .params
+                .iter()
+                .map(|(_, ty)| codecs.codec(ty, resolve, type_map))
+                .collect::<Result<Vec<_>>>()?;
+
+            let results = match &function.results {
+                WitResults::Named(results) => results
+                    .iter()
+                    .map(|(_, ty)| codecs.codec(ty, resolve, type_map))
+                    .collect::<Result<Vec<_>>>()?,
+                WitResults::Anon(ty) => vec![codecs.codec(ty, resolve, type_map)?],
+            };
+
+            let name = escape(
+                &codecs
+                    .scope
+                    .fresh(ScalaName::new(&function.name, Case::Camel, &[]).unescaped()),
+            );
+            let signature = Expr::raw("Signature").call(vec![
+                Expr::raw(format!("\"{}.{{{}}}\"", codecs.path, function.name)),
+                Expr::raw("Seq").call(params),
+                Expr::raw("Seq").call(results),
+            ]);
+            codecs
+                .signatures
+                .insert(function.name.clone(), (name, signature));
+        }
+
+        Ok(codecs)
+    }
+
+    /// The expression of the codec of a WIT type, defining the codecs of the named types it refers to
+    fn codec(&mut self, ty: &WitType, resolve: &Resolve, type_map: &TypeMap) -> Result<Expr> {
+        let id = match ty {
+            WitType::Bool => return Ok(Expr::raw("bool")),
+            WitType::U8 => return Ok(Expr::raw("u8")),
+            WitType::U16 => return Ok(Expr::raw("u16")),
+            WitType::U32 => return Ok(Expr::raw("u32")),
+            WitType::U64 => return Ok(Expr::raw("u64")),
+            WitType::S8 => return Ok(Expr::raw("s8")),
+            WitType::S16 => return Ok(Expr::raw("s16")),
+            WitType::S32 => return Ok(Expr::raw("s32")),
+            WitType::S64 => return Ok(Expr::raw("s64")),
+            WitType::F32 => return Ok(Expr::raw("f32")),
+            WitType::F64 => return Ok(Expr::raw("f64")),
+            WitType::Char => return Ok(Expr::raw("chr")),
+            WitType::String => return Ok(Expr::raw("str")),
+            WitType::Id(id) => *id,
+        };
+
+        if let Some(name) = self.names.get(&id) {
+            return Ok(Expr::raw(name.clone()));
+        }
+
+        let type_def = &resolve.types[id];
+        let mut codec = |ty: &WitType| self.codec(ty, resolve, type_map);
+
+        let definition = match &type_def.kind {
+            // Aliases share the codec of the type they stand for
+            TypeDefKind::Type(ty) => return codec(ty),
+            TypeDefKind::Record(record) => Expr::raw("record").call(
+                record
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        Ok(Expr::tuple(vec![
+                            Expr::raw(format!("\"{}\"", field.name)),
+                            Expr::raw(format!(
+                                "\"{}\"",
+                                ScalaName::new(&field.name, Case::Camel, &[]).converted()
+                            )),
+                            codec(&field.ty)?,
+                        ]))
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            TypeDefKind::Flags(flags) => Expr::raw("flags").call(
+                flags
+                    .flags
+                    .iter()
+                    .map(|flag| {
+                        Expr::raw(format!(
+                            "(\"{}\", \"{}\")",
+                            flag.name,
+                            ScalaName::new(&flag.name, Case::Camel, &[]).converted()
+                        ))
+                    })
+                    .collect(),
+            ),
+            TypeDefKind::Variant(variant) => Expr::raw("variant").call(
+                variant
+                    .cases
+                    .iter()
+                    .map(|case| {
+                        Ok(Expr::tuple(vec![
+                            Expr::raw(format!("\"{}\"", case.name)),
+                            optional(case.ty.as_ref(), &mut codec)?,
+                        ]))
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            TypeDefKind::Enum(enumeration) => Expr::raw("enumeration").call(
+                enumeration
+                    .cases
+                    .iter()
+                    .map(|case| Expr::raw(format!("\"{}\"", case.name)))
+                    .collect(),
+            ),
+            TypeDefKind::Option(ty) => Expr::raw("option").call(vec![codec(ty)?]),
+            TypeDefKind::Result(result) => Expr::raw("result").call(vec![
+                optional(result.ok.as_ref(), &mut codec)?,
+                optional(result.err.as_ref(), &mut codec)?,
+            ]),
+            TypeDefKind::Tuple(tuple) => {
+                Expr::raw("tuple").call(tuple.types.iter().map(&mut codec).collect::<Result<_>>()?)
+            }
+            TypeDefKind::List(ty) => Expr::raw("list").call(vec![codec(ty)?]),
+            _ => {
+                return Err(eyre!(
+                    "Values of type `{}` cannot be sent over the Golem REST API",
+                    type_map.wit_name(ty)
+                ))
+            }
+        };
+
+        match (&type_def.name, type_map.get(&id)) {
+            (Some(_), Some(type_name)) => {
+                let hint = format!("{}-codec", type_name.js_name());
+                let name = self
+                    .scope
+                    .fresh(ScalaName::new(&hint, Case::Camel, &[]).unescaped());
+                self.names.insert(id, name.clone());
+                self.named.push((name.clone(), definition));
+                Ok(Expr::raw(name))
+            }
+            _ => Ok(definition),
+        }
+    }
+}
+
+/// The expression of the optional codec of an optional WIT type
+fn optional(
+    ty: Option<&WitType>,
+    codec: &mut impl FnMut(&WitType) -> Result<Expr>,
+) -> Result<Expr> {
+    Ok(match ty {
+        Some(ty) => Expr::raw("Some").call(vec![codec(ty)?]),
+        None => Expr::raw("None"),
+    })
+}
+
+/// The members of an anonymous class implementing `Codec`
+fn codec(typ: Expr, encode: Expr, decode: Expr) -> Vec<Stat> {
+    vec![
+        Stat::Val(Val::new("typ").ty("js.Any").rhs(typ)),
+        Stat::Def(
+            Def::new("encode")
+                .params(vec![Param::new("value", "Any")])
+                .returns("js.Any")
+                .body(encode),
+        ),
+        Stat::Def(
+            Def::new("decode")
+                .params(vec![Param::new("json", "js.Any")])
+                .returns("Any")
+                .body(decode),
+        ),
+    ]
+}
+
+/// The call of `fun` with the items of a collection as its args, `fun(items: _*)`
+fn splat(fun: &str, items: Expr) -> Expr {
+    Expr::raw(fun).call(vec![items.ascribe("_*")])
+}
+
+/// A method of the companion object returning a codec
+fn combinator(doc: &str, name: &str, params: Param, body: Vec<Stat>) -> Stat {
+    Stat::Def(
+        Def::new(name)
+            .doc(Some(doc.to_owned()))
+            .params(vec![params])
+            .returns("Codec")
+            .body(Expr::New {
+                parents: vec!["Codec".to_owned()],
+                body,
+            }),
+    )
+}
+
+/// A value of the companion object that is a codec
+fn primitive(name: &str, analysed: &str, encode: Option<(&str, &str)>) -> Stat {
+    let rhs = match encode {
+        None => Expr::raw(format!("primitive(\"{analysed}\")")),
+        Some((encode, decode)) => Expr::New {
+            parents: vec!["Codec".to_owned()],
+            body: codec(
+                Expr::raw(format!("analysed(\"{analysed}\")")),
+                Expr::raw(encode),
+                Expr::raw(decode),
+            ),
+        },
+    };
+
+    Stat::Val(Val::new(name).ty("Codec").rhs(rhs))
+}
+
+/// The members of the companion object that convert values and call the Golem REST API
+fn runtime() -> Vec<Stat> {
+    let mut stats = vec![
+        Stat::Trait(
+            Template::new("Codec")
+                .doc(Some(
+                    "A WIT type in the typed JSON value format of Golem, converting values from and to\n\
+                     their JS representation"
+                        .to_owned(),
+                ))
+                .body(vec![
+                    Stat::Def(
+                        Def::new("typ")
+                            .doc(Some("The analysed type, which is how Golem describes WIT types".to_owned()))
+                            .returns("js.Any"),
+                    ),
+                    Stat::Def(
+                        Def::new("encode")
+                            .doc(Some("Converts a value from its JS representation to JSON".to_owned()))
+                            .params(vec![Param::new("value", "Any")])
+                            .returns("js.Any"),
+                    ),
+                    Stat::Def(
+                        Def::new("decode")
+                            .doc(Some("Converts a value from JSON to its JS representation".to_owned()))
+                            .params(vec![Param::new("json", "js.Any")])
+                            .returns("Any"),
+                    ),
+                    Stat::Def(
+                        Def::new("typed")
+                            .doc(Some("Converts a value to a typed JSON value".to_owned()))
+                            .params(vec![Param::new("value", "Any")])
+                            .returns("js.Any")
+                            .body(Expr::raw(
+                                "js.Dictionary[js.Any](\"typ\" -> typ, \"value\" -> encode(value))",
+                            )),
+                    ),
+                ]),
+        ),
+        Stat::Class(
+            Template::new("GolemError")
+                .doc(Some(
+                    "An error status returned by the Golem REST API, with the body of the response"
+                        .to_owned(),
+                ))
+                .modifier("final case")
+                .params(vec![Param::new("status", "Int"), Param::new("body", "String")])
+                .extends("Exception(s\"Golem responded with status $status: $body\")"),
+        ),
+        Stat::Class(
+            Template::new("Signature")
+                .doc(Some(
+                    "The qualified name of a function, with the codecs of its params and results"
+                        .to_owned(),
+                ))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("function", "String"),
+                    Param::new("params", "Seq[Codec]"),
+                    Param::new("results", "Seq[Codec]"),
+                ]),
+        ),
+        Stat::Val(
+            Val::new("executionContext")
+                .modifier("private")
+                .modifier("implicit")
+                .ty("ExecutionContext")
+                .rhs(Expr::raw("scala.scalajs.concurrent.JSExecutionContext.queue")),
+        ),
+        Stat::Def(
+            Def::new("analysed")
+                .modifier("private")
+                .params(vec![
+                    Param::new("name", "String"),
+                    Param::new("fields", "(String, js.Any)*"),
+                ])
+                .returns("js.Any")
+                .body(Expr::Block(vec![
+                    Stat::Val(Val::new("typ").rhs(Expr::raw("js.Dictionary[js.Any](fields: _*)"))),
+                    Stat::Expr(Expr::raw("typ(\"type\") = name")),
+                    Stat::Expr(Expr::raw("typ")),
+                ])),
+        ),
+        Stat::Def(
+            Def::new("primitive")
+                .modifier("private")
+                .params(vec![Param::new("name", "String")])
+                .returns("Codec")
+                .body(Expr::New {
+                    parents: vec!["Codec".to_owned()],
+                    body: codec(
+                        Expr::raw("analysed(name)"),
+                        Expr::raw("value.asInstanceOf[js.Any]"),
+                        Expr::raw("json"),
+                    ),
+                }),
+        ),
+    ];
+
+    for (name, analysed) in [
+        ("bool", "Bool"),
+        ("s8", "S8"),
+        ("s16", "S16"),
+        ("s32", "S32"),
+        ("f32", "F32"),
+        ("f64", "F64"),
+        ("str", "Str"),
+    ] {
+        stats.push(primitive(name, analysed, None));
+    }
+
+    // Unsigned integers are in two's complement in Scala, 64-bit integers are sent as `BigInt`s
+    // so that they keep their precision, and chars are strings in JSON
+    stats.extend([
+        primitive(
+            "u8",
+            "U8",
+            Some((
+                "value.asInstanceOf[Byte] & 0xff",
+                "json.asInstanceOf[Int].toByte",
+            )),
+        ),
+        primitive(
+            "u16",
+            "U16",
+            Some((
+                "value.asInstanceOf[Short] & 0xffff",
+                "json.asInstanceOf[Int].toShort",
+            )),
+        ),
+        primitive(
+            "u32",
+            "U32",
+            Some((
+                "Integer.toUnsignedLong(value.asInstanceOf[Int]).toDouble",
+                "json.asInstanceOf[Double].toLong.toInt",
+            )),
+        ),
+        primitive(
+            "s64",
+            "S64",
+            Some((
+                "js.BigInt(value.asInstanceOf[Long].toString)",
+                "java.lang.Long.parseLong(js.Dynamic.global.BigInt(json).toString)",
+            )),
+        ),
+        primitive(
+            "u64",
+            "U64",
+            Some((
+                "js.BigInt(java.lang.Long.toUnsignedString(value.asInstanceOf[Long]))",
+                "java.lang.Long.parseUnsignedLong(js.Dynamic.global.BigInt(json).toString)",
+            )),
+        ),
+        primitive(
+            "chr",
+            "Chr",
+            Some((
+                "value.asInstanceOf[Char].toString",
+                "json.asInstanceOf[String].charAt(0)",
+            )),
+        ),
+    ]);
+
+    let codecs = Param::new("codec", "Option[Codec]");
+    stats.extend([
+        Stat::Def(
+            Def::new("typeOf")
+                .modifier("private")
+                .params(vec![codecs])
+                .returns("js.Any")
+                .body(Expr::raw("codec.map(_.typ).orNull")),
+        ),
+        combinator(
+            "The codec of `list<T>`, given the codec of `T`",
+            "list",
+            Param::new("inner", "Codec"),
+            codec(
+                Expr::raw("analysed(\"List\", \"inner\" -> inner.typ)"),
+                Expr::raw("value.asInstanceOf[js.Array[Any]].map(inner.encode)"),
+                Expr::raw("json.asInstanceOf[js.Array[js.Any]].map(inner.decode)"),
+            ),
+        ),
+        combinator(
+            "The codec of `option<T>`, given the codec of `T`",
+            "option",
+            Param::new("inner", "Codec"),
+            codec(
+                Expr::raw("analysed(\"Option\", \"inner\" -> inner.typ)"),
+                Expr::raw("if (js.isUndefined(value)) null else inner.encode(value)"),
+                Expr::if_else(
+                    Expr::raw("json == null || js.isUndefined(json)"),
+                    Expr::raw("js.undefined"),
+                    Expr::raw("inner.decode(json)"),
+                ),
+            ),
+        ),
+        Stat::Def(
+            Def::new("result")
+                .doc(Some(
+                    "The codec of `result<T, E>`, given the codecs of `T` and `E`, errors being thrown\n\
+                     as with their JS representation"
+                        .to_owned(),
+                ))
+                .params(vec![
+                    Param::new("ok", "Option[Codec]"),
+                    Param::new("err", "Option[Codec]"),
+                ])
+                .returns("Codec")
+                .body(Expr::New {
+                    parents: vec!["Codec".to_owned()],
+                    body: codec(
+                        Expr::raw("analysed(\"Result\", \"ok\" -> typeOf(ok), \"err\" -> typeOf(err))"),
+                        Expr::raw(
+                            "js.Dictionary[js.Any](\"ok\" -> ok.fold[js.Any](null)(_.encode(value)))",
+                        ),
+                        Expr::Block(vec![
+                            Stat::Val(
+                                Val::new("cases")
+                                    .rhs(Expr::raw("json.asInstanceOf[js.Dictionary[js.Any]]")),
+                            ),
+                            Stat::Expr(Expr::Match {
+                                scrutinee: "cases.get(\"ok\")".to_owned(),
+                                cases: vec![
+                                    (
+                                        "Some(json)".to_owned(),
+                                        Expr::raw("ok.fold[Any](())(_.decode(json))"),
+                                    ),
+                                    (
+                                        "None".to_owned(),
+                                        Expr::raw(
+                                            "throw js.JavaScriptException(err.fold[Any](())(_.decode(cases(\"err\"))))",
+                                        ),
+                                    ),
+                                ],
+                            }),
+                        ]),
+                    ),
+                }),
+        ),
+        combinator(
+            "The codec of a tuple, given the codecs of its items",
+            "tuple",
+            Param::new("items", "Codec*"),
+            codec(
+                Expr::raw("analysed(\"Tuple\", \"items\" -> js.Array(items.map(_.typ): _*))"),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("values").rhs(Expr::raw("value.asInstanceOf[js.Array[Any]]"))),
+                    Stat::Expr(splat(
+                        "js.Array",
+                        Expr::raw("items.zipWithIndex").method(
+                            "map",
+                            vec![Expr::braced("case (item, i)", Expr::raw("item.encode(values(i))"))],
+                        ),
+                    )),
+                ]),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("values").rhs(Expr::raw("json.asInstanceOf[js.Array[js.Any]]"))),
+                    Stat::Expr(splat(
+                        "js.Array",
+                        Expr::raw("items.zipWithIndex").method(
+                            "map",
+                            vec![Expr::braced("case (item, i)", Expr::raw("item.decode(values(i))"))],
+                        ),
+                    )),
+                ]),
+            ),
+        ),
+        combinator(
+            "The codec of a record, given the WIT name, the JS name and the codec of each field",
+            "record",
+            Param::new("fields", "(String, String, Codec)*"),
+            codec(
+                Expr::raw("analysed").call(vec![
+                    Expr::raw("\"Record\""),
+                    Expr::infix(
+                        "->",
+                        vec![
+                            Expr::raw("\"fields\""),
+                            splat(
+                                "js.Array",
+                                Expr::raw("fields").method(
+                                    "map",
+                                    vec![Expr::braced(
+                                        "case (name, _, field)",
+                                        Expr::raw("js.Dictionary[js.Any](\"name\" -> name, \"typ\" -> field.typ)"),
+                                    )],
+                                ),
+                            ),
+                        ],
+                    ),
+                ]),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("members").rhs(Expr::raw("value.asInstanceOf[js.Dictionary[Any]]"))),
+                    Stat::Expr(splat(
+                        "js.Dictionary[js.Any]",
+                        Expr::raw("fields").method(
+                            "map",
+                            vec![Expr::braced(
+                                "case (name, jsName, field)",
+                                Expr::raw("name -> field.encode(members.getOrElse(jsName, js.undefined))"),
+                            )],
+                        ),
+                    )),
+                ]),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("members").rhs(Expr::raw("json.asInstanceOf[js.Dictionary[js.Any]]"))),
+                    Stat::Expr(splat(
+                        "js.Dictionary[Any]",
+                        Expr::raw("fields").method(
+                            "map",
+                            vec![Expr::braced(
+                                "case (name, jsName, field)",
+                                Expr::raw("jsName -> field.decode(members.getOrElse(name, null))"),
+                            )],
+                        ),
+                    )),
+                ]),
+            ),
+        ),
+    ]);
+
+    let mut variant = codec(
+        Expr::raw("analysed").call(vec![
+            Expr::raw("\"Variant\""),
+            Expr::infix(
+                "->",
+                vec![
+                    Expr::raw("\"cases\""),
+                    splat(
+                        "js.Array",
+                        Expr::raw("cases").method(
+                            "map",
+                            vec![Expr::braced(
+                                "case (name, payload)",
+                                Expr::raw("js.Dictionary[js.Any](\"name\" -> name, \"typ\" -> typeOf(payload))"),
+                            )],
+                        ),
+                    ),
+                ],
+            ),
+        ]),
+        Expr::Block(vec![
+            Stat::Val(Val::new("variant").rhs(Expr::raw("value.asInstanceOf[js.Dynamic]"))),
+            Stat::Val(Val::new("tag").rhs(Expr::raw("variant.tag.asInstanceOf[String]"))),
+            Stat::Expr(Expr::raw("js.Dictionary[js.Any]").call(vec![Expr::raw(
+                "tag -> payload(tag).fold[js.Any](null)(_.encode(variant.`val`))",
+            )])),
+        ]),
+        Expr::Block(vec![
+            Stat::Val(Val::new("(tag, value)").rhs(Expr::raw(
+                "json.asInstanceOf[js.Dictionary[js.Any]].head",
+            ))),
+            Stat::Expr(Expr::raw("js.Dictionary[Any]").call(vec![
+                Expr::raw("\"tag\" -> tag"),
+                Expr::raw("\"val\" -> payload(tag).fold[Any](js.undefined)(_.decode(value))"),
+            ])),
+        ]),
+    );
+    variant.push(Stat::Def(
+        Def::new("payload")
+            .modifier("private")
+            .params(vec![Param::new("tag", "String")])
+            .returns("Option[Codec]")
+            .body(Expr::raw("cases.find(_._1 == tag).flatMap(_._2)")),
+    ));
+
+    stats.extend([
+        combinator(
+            "The codec of a variant, given the name and the codec of the payload of each case",
+            "variant",
+            Param::new("cases", "(String, Option[Codec])*"),
+            variant,
+        ),
+        combinator(
+            "The codec of an enum, given the name of each case",
+            "enumeration",
+            Param::new("cases", "String*"),
+            codec(
+                Expr::raw("analysed(\"Enum\", \"cases\" -> js.Array(cases: _*))"),
+                Expr::raw("value.asInstanceOf[js.Any]"),
+                Expr::raw("json"),
+            ),
+        ),
+        combinator(
+            "The codec of flags, given the WIT name and the JS name of each flag",
+            "flags",
+            Param::new("names", "(String, String)*"),
+            codec(
+                Expr::raw("analysed(\"Flags\", \"names\" -> js.Array(names.map(_._1): _*))"),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("flags").rhs(Expr::raw("value.asInstanceOf[js.Dictionary[Boolean]]"))),
+                    Stat::Expr(splat(
+                        "js.Array",
+                        Expr::raw("names").method(
+                            "collect",
+                            vec![Expr::braced(
+                                "case (name, jsName) if flags.getOrElse(jsName, false)",
+                                Expr::raw("name"),
+                            )],
+                        ),
+                    )),
+                ]),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("set").rhs(Expr::raw("json.asInstanceOf[js.Array[String]]"))),
+                    Stat::Expr(splat(
+                        "js.Dictionary",
+                        Expr::raw("names").method(
+                            "map",
+                            vec![Expr::braced(
+                                "case (name, jsName)",
+                                Expr::raw("jsName -> set.contains(name)"),
+                            )],
+                        ),
+                    )),
+                ]),
+            ),
+        ),
+        Stat::Val(
+            Val::new("bigIntMarker")
+                .doc(Some(
+                    "The prefix marking the digits of a `BigInt` while it is converted to JSON".to_owned(),
+                ))
+                .modifier("private")
+                .rhs(Expr::raw("\"\\u0000bigint:\"")),
+        ),
+        Stat::Def(
+            Def::new("stringify")
+                .doc(Some(
+                    "Converts a value to JSON, writing the `BigInt`s of 64-bit integers as exact numbers".to_owned(),
+                ))
+                .modifier("private")
+                .params(vec![Param::new("value", "js.Any")])
+                .returns("String")
+                .body(Expr::Block(vec![
+                    Stat::Val(Val::new("marked").rhs(Expr::raw("js.JSON.stringify").call(vec![
+                        Expr::raw("value"),
+                        Expr::lambda(
+                            "(_: String, value: js.Any)",
+                            Expr::if_else(
+                                Expr::raw("js.typeOf(value) == \"bigint\""),
+                                Expr::raw("(bigIntMarker + value.toString)").ascribe("js.Any"),
+                                Expr::raw("value"),
+                            ),
+                        ),
+                    ]))),
+                    Stat::Val(Val::new("marker").rhs(Expr::raw(
+                        "java.util.regex.Pattern.quote(js.JSON.stringify(bigIntMarker).init)",
+                    ))),
+                    Stat::Expr(Expr::raw("marked.replaceAll(marker + \"(-?[0-9]+)\\\"\", \"$1\")")),
+                ])),
+        ),
+        Stat::Def(
+            Def::new("exact")
+                .doc(Some(
+                    "Reads an integer that does not fit a double as an exact `BigInt`, where the JS\n\
+                     engine gives its source"
+                        .to_owned(),
+                ))
+                .modifier("private")
+                .params(vec![
+                    Param::new("key", "String"),
+                    Param::new("value", "js.Any"),
+                    Param::new("context", "js.UndefOr[js.Dynamic]"),
+                ])
+                .returns("js.Any")
+                .body(Expr::Block(vec![
+                    Stat::Val(Val::new("source").rhs(
+                        Expr::raw("context")
+                            .method("flatMap", vec![Expr::raw("_.source.asInstanceOf[js.UndefOr[String]]")])
+                            .method("filter", vec![Expr::raw("_.matches(\"-?[0-9]+\")")]),
+                    )),
+                    Stat::Val(Val::new("unsafe").rhs(Expr::infix(
+                        "&&",
+                        vec![
+                            Expr::raw("js.typeOf(value) == \"number\""),
+                            Expr::raw("!js.Dynamic.global.Number.isSafeInteger(value).asInstanceOf[Boolean]"),
+                        ],
+                    ))),
+                    Stat::Expr(Expr::raw(
+                        "if (unsafe && source.isDefined) js.Dynamic.global.BigInt(source.get) else value",
+                    )),
+                ])),
+        ),
+        Stat::Def(
+            Def::new("parse")
+                .doc(Some(
+                    "Parses JSON, reading the integers that do not fit a double with `exact`".to_owned(),
+                ))
+                .modifier("private")
+                .params(vec![Param::new("text", "String")])
+                .returns("js.Dynamic")
+                .body(Expr::raw(
+                    "js.Dynamic.global.JSON.parse(text, js.Any.fromFunction3(exact))",
+                )),
+        ),
+        Stat::Def(
+            Def::new("invoke")
+                .doc(Some(
+                    "Invokes a function on a worker and awaits its results, which are converted by `results`"
+                        .to_owned(),
+                ))
+                .modifier("private")
+                .tparams(vec!["A".to_owned()])
+                .params(vec![
+                    Param::new("worker", "String"),
+                    Param::new("token", "Option[String]"),
+                    Param::new("signature", "Signature"),
+                    Param::new("params", "Seq[Any]"),
+                ])
+                .params(vec![Param::new("results", "js.Array[Any] => A")])
+                .returns("Future[A]")
+                .body(Expr::Block(vec![
+                    Stat::Val(Val::new("function").rhs(Expr::raw(
+                        "js.URIUtils.encodeURIComponent(signature.function)",
+                    ))),
+                    Stat::Val(Val::new("typed").rhs(Expr::raw(
+                        "signature.params.zip(params).map { case (codec, value) => codec.typed(value) }",
+                    ))),
+                    Stat::Val(Val::new("headers").rhs(Expr::raw(
+                        "js.Dictionary(\"Content-Type\" -> \"application/json\")",
+                    ))),
+                    Stat::Expr(Expr::raw(
+                        "token.foreach(token => headers(\"Authorization\") = s\"Bearer $token\")",
+                    )),
+                    Stat::Val(Val::new("request").rhs(Expr::raw("js.Dictionary[js.Any]").call(vec![
+                        Expr::raw("\"method\" -> \"POST\""),
+                        Expr::raw("\"headers\" -> headers"),
+                        Expr::raw("\"body\" -> stringify(js.Dictionary[js.Any](\"params\" -> js.Array(typed: _*)))"),
+                    ]))),
+                    Stat::Val(Val::new("response").rhs(
+                        Expr::raw("js.Dynamic.global.fetch")
+                            .call(vec![
+                                Expr::raw("s\"$worker/invoke-and-await?function=$function\""),
+                                Expr::raw("request"),
+                            ])
+                            .select("asInstanceOf[js.Promise[js.Dynamic]]"),
+                    )),
+                    Stat::Expr(Expr::raw("response.toFuture").method(
+                        "flatMap",
+                        vec![Expr::lambda(
+                            "response",
+                            Expr::raw("response.text().asInstanceOf[js.Promise[String]].toFuture").method(
+                                "map",
+                                vec![Expr::lambda(
+                                    "body",
+                                    Expr::raw("results(decode(signature, response.status.asInstanceOf[Int], body))"),
+                                )],
+                            ),
+                        )],
+                    )),
+                ])),
+        ),
+        Stat::Def(
+            Def::new("decode")
+                .doc(Some(
+                    "Decodes the results of an invocation, failing with a `GolemError` on error statuses"
+                        .to_owned(),
+                ))
+                .modifier("private")
+                .params(vec![
+                    Param::new("signature", "Signature"),
+                    Param::new("status", "Int"),
+                    Param::new("body", "String"),
+                ])
+                .returns("js.Array[Any]")
+                .body(Expr::Block(vec![
+                    Stat::Expr(Expr::raw(
+                        "if (status < 200 || status >= 300) throw GolemError(status, body)",
+                    )),
+                    Stat::Val(Val::new("values").rhs(Expr::raw(
+                        "parse(body).result.value.asInstanceOf[js.Array[js.Any]]",
+                    ))),
+                    Stat::Expr(splat(
+                        "js.Array",
+                        Expr::raw("signature.results.zipWithIndex").method(
+                            "map",
+                            vec![Expr::braced("case (codec, i)", Expr::raw("codec.decode(values(i))"))],
+                        ),
+                    )),
+                ])),
+        ),
+    ]);
+
+    stats
+}
+
+/// The class invoking the functions of an interface on a worker through the Golem REST API,
+/// and its companion object holding the codecs of the values it exchanges with the worker
+pub fn client(
+    name: &ScalaName,
+    doc: Option<String>,
+    functions: Vec<Function>,
+    mut codecs: Codecs,
+) -> Vec<Stat> {
+    let class = format!("{}RestClient", name.unescaped());
+
+    // The members used in the bodies of the methods must not be shadowed by their params
+    let mut scope = Scope::new(functions.iter().flat_map(|function| {
+        function
+            .param_names()
+            .map(|name| name.unescaped().to_owned())
+    }));
+    let worker = scope.fresh("worker");
+    let invoke = scope.fresh("invoke");
+
+    let mut body = vec![
+        Stat::Val(
+            Val::new(&worker)
+                .modifier("private")
+                .ty("String")
+                .rhs(Expr::raw(
+                    "s\"$baseUrl/v1/components/$componentId/workers/$workerName\"",
+                )),
+        ),
+        Stat::Def(
+            Def::new(&invoke)
+                .modifier("private")
+                .tparams(vec!["A".to_owned()])
+                .params(vec![
+                    Param::new("signature", format!("{class}.Signature")),
+                    Param::new("params", "Any*"),
+                ])
+                .params(vec![Param::new("results", "js.Array[Any] => A")])
+                .returns("Future[A]")
+                .body(
+                    Expr::raw(format!("{class}.invoke"))
+                        .call(
+                            [&worker, "token", "signature", "params"]
+                                .into_iter()
+                                .map(Expr::raw)
+                                .collect(),
+                        )
+                        .call(vec![Expr::raw("results")]),
+                ),
+        ),
+    ];
+
+    for function in &functions {
+        let (signature, _) = &codecs.signatures[function.name().wit()];
+        let args = std::iter::once(format!("{class}.{signature}"))
+            .chain(function.param_names().map(ToString::to_string))
+            .map(Expr::raw)
+            .collect();
+
+        let outs = function.out_types();
+        let value =
+            |(i, out): (usize, &String)| Expr::raw(format!("values({i}).asInstanceOf[{out}]"));
+        let results = match (function.result_record(), outs.as_slice()) {
+            (Some((record, fields)), _) => Expr::lambda(
+                "values",
+                Expr::raw(record).call(fields.iter().enumerate().map(value).collect()),
+            ),
+            (None, []) => Expr::lambda("_", Expr::raw("()")),
+            (None, [out]) => Expr::lambda(
+                "values",
+                Expr::raw(format!("values(0).asInstanceOf[{out}]")),
+            ),
+            (None, outs) => Expr::lambda(
+                "values",
+                Expr::tuple(outs.iter().enumerate().map(value).collect()),
+            ),
+        };
+
+        body.push(Stat::Def(
+            function
+                .declaration_returning(
+                    format!("Future[{}]", function.returns()),
+                    format!("the future of `{}`", function.returns()),
+                )
+                .body(Expr::raw(invoke.as_str()).call(args).call(vec![results])),
+        ));
+    }
+
+    let mut companion = runtime();
+    companion.extend(codecs.named.into_iter().map(|(name, definition)| {
+        Stat::Val(Val::new(name).modifier("lazy").ty("Codec").rhs(definition))
+    }));
+    companion.extend(functions.iter().map(|function| {
+        let (name, definition) = codecs
+            .signatures
+            .remove(function.name().wit())
+            .expect("the signatures cover the functions");
+        Stat::Val(Val::new(name).ty("Signature").rhs(definition))
+    }));
+
+    let doc = Some(format!(
+        "{}Invokes `{}` on a worker through the Golem REST API\n\n\
+         @param baseUrl the URL of the Golem REST API, such as `http://localhost:9881`\n\
+         @param componentId the ID of the component of the worker\n\
+         @param workerName the name of the worker\n\
+         @param token the token authenticating the requests, if any",
+        doc.map(|doc| format!("{doc}\n\n")).unwrap_or_default(),
+        codecs.path,
+    ));
+
+    vec![
+        Stat::Class(
+            Template::new(&class)
+                .doc(doc)
+                .params(vec![
+                    Param::new("baseUrl", "String"),
+                    Param::new("componentId", "String"),
+                    Param::new("workerName", "String"),
+                    Param::new("token", "Option[String]").default("None"),
+                ])
+                .body(body),
+        ),
+        Stat::Object(Template::new(&class).body(companion)),
+    ]
+}
@@ -0,0 +1,111 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Clients calling the interfaces of other workers through the stubs generated by wasm-rpc
+
+use convert_case::Case;
+
+use super::ast::{Def, Expr, Param, Stat, Template};
+use super::Function;
+use crate::naming::{ScalaName, TYPE_HELPERS};
+
+/// The type of the location of a worker, from `golem:rpc/types`
+const URI: &str = "Uri";
+
+/// The type of the readiness of a pending call, from `wasi:io/poll`
+const POLLABLE: &str = "Pollable";
+
+/// The class of a pending call of a function, as named by wasm-rpc
+fn pending(function: &Function) -> ScalaName {
+    ScalaName::new(
+        &format!("future-{}-result", function.name().wit()),
+        Case::UpperCamel,
+        TYPE_HELPERS,
+    )
+}
+
+/// The native class of a pending call of `function`
+fn pending_class(function: &Function, specifier: &str) -> Stat {
+    let name = pending(function);
+
+    Stat::Class(
+        Template::new(name.to_string())
+            .doc(Some(format!(
+                "A pending call of `{}` on a remote worker",
+                function.name().wit()
+            )))
+            .annotation("@js.native")
+            .annotation(format!(
+                "@JSImport(\"{specifier}\", \"{}\")",
+                name.converted()
+            ))
+            .extends("js.Object")
+            .body(vec![
+                Stat::Def(
+                    Def::new("subscribe")
+                        .doc(Some(
+                            "Creates a pollable that is ready once the call has completed"
+                                .to_owned(),
+                        ))
+                        .params(vec![])
+                        .returns(POLLABLE)
+                        .body(Expr::raw("js.native")),
+                ),
+                Stat::Def(
+                    Def::new("get")
+                        .doc(Some(
+                            "The results of the call, once it has completed".to_owned(),
+                        ))
+                        .params(vec![])
+                        .returns(format!("WitOption[{}]", function.returns()))
+                        .body(Expr::raw("js.native")),
+                ),
+            ]),
+    )
+}
+
+/// The native class calling the functions of an interface on the remote worker at `location`,
+/// with a blocking and a non-blocking method per function, followed by the classes of the pending calls
+pub fn client(
+    name: &ScalaName,
+    doc: Option<String>,
+    functions: Vec<Function>,
+    specifier: &str,
+) -> Vec<Stat> {
+    let methods = functions
+        .iter()
+        .flat_map(|function| function.rpc(&pending(function).to_string()))
+        .collect();
+
+    let mut stats = vec![Stat::Class(
+        Template::new(name.to_string())
+            .doc(doc)
+            .annotation("@js.native")
+            .annotation(format!(
+                "@JSImport(\"{specifier}\", \"{}\")",
+                name.converted()
+            ))
+            .params(vec![Param::new("location", URI)])
+            .extends("js.Object")
+            .body(methods),
+    )];
+
+    stats.extend(
+        functions
+            .iter()
+            .map(|function| pending_class(function, specifier)),
+    );
+
+    stats
+}
@@ -0,0 +1,238 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ast::{Def, Expr, Param, Stat, Template, Val};
+
+/// The values generated for the WIT types, documenting the generated object
+const GENERATION: &str = "The ScalaCheck generators of the WIT types.
+
+The values are built in their JS representation. Unsigned integers cover the range of their WIT
+type, wrapped into the Scala type of the same width, chars are the Unicode scalar values a `Char`
+holds, mostly ASCII, and strings are made of them. Results only generate `ok` values, since errors
+are thrown rather than represented.
+
+The generators of the generated types are implicit and named after them, such as `arbitraryItem`
+for `Item`, so that importing `Arbitraries._` brings them into scope.";
+
+/// A method of the `Arbitraries` object returning a generator
+fn combinator(
+    doc: &str,
+    name: &str,
+    tparams: &[&str],
+    params: Vec<Param>,
+    ty: &str,
+    gen: &str,
+) -> Stat {
+    Stat::Def(
+        Def::new(name)
+            .doc(Some(doc.to_owned()))
+            .tparams(tparams.iter().map(|tparam| (*tparam).to_owned()).collect())
+            .params(params)
+            .returns(format!("Arbitrary[{ty}]"))
+            .body(Expr::raw(format!("Arbitrary({gen})"))),
+    )
+}
+
+/// A value of the `Arbitraries` object that is the generator of a primitive
+fn primitive(name: &str, ty: &str, gen: &str) -> Stat {
+    Stat::Val(
+        Val::new(name)
+            .doc(Some(format!("The generator of `{name}`")))
+            .ty(format!("Arbitrary[{ty}]"))
+            .rhs(Expr::raw(format!("Arbitrary({gen})"))),
+    )
+}
+
+/// The `Arbitraries` object, which holds the generators of the WIT primitives, the combinators
+/// building the generators of the other WIT types, and the generators of the generated types
+pub fn runtime(instances: Vec<Stat>) -> Vec<Stat> {
+    let doc = |doc: &str| Some(doc.to_owned());
+
+    let mut stats = vec![
+        Stat::Expr(Expr::raw("import org.scalacheck.{Arbitrary, Gen}")),
+        Stat::Class(
+            Template::new("Field")
+                .doc(doc(
+                    "A record field, with its JS name, its WIT name and the generator of its value",
+                ))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("name", "String"),
+                    Param::new("label", "String"),
+                    Param::new("arbitrary", "Arbitrary[_]"),
+                ]),
+        ),
+        Stat::Class(
+            Template::new("Case")
+                .doc(doc(
+                    "A variant case, with its WIT name and the generator of its payload",
+                ))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("label", "String"),
+                    Param::new("payload", "Option[Arbitrary[_]]"),
+                ]),
+        ),
+        Stat::Class(
+            Template::new("Flag")
+                .doc(doc("A flag, with its JS name and its WIT name"))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("name", "String"),
+                    Param::new("label", "String"),
+                ]),
+        ),
+        Stat::Def(
+            Def::new("sequence")
+                .modifier("private")
+                .params(vec![Param::new("arbitraries", "Seq[Arbitrary[_]]")])
+                .returns("Gen[List[Any]]")
+                .body(Expr::raw(
+                    "Gen.sequence[List[Any], Any](arbitraries.map(_.arbitrary.asInstanceOf[Gen[Any]]))",
+                )),
+        ),
+        primitive("bool", "Boolean", "Gen.oneOf(true, false)"),
+        primitive("s8", "Byte", "Gen.chooseNum(Byte.MinValue, Byte.MaxValue)"),
+        primitive("u8", "Byte", "Gen.chooseNum(0, 0xff).map(_.toByte)"),
+        primitive(
+            "s16",
+            "Short",
+            "Gen.chooseNum(Short.MinValue, Short.MaxValue)",
+        ),
+        primitive("u16", "Short", "Gen.chooseNum(0, 0xffff).map(_.toShort)"),
+        primitive(
+            "s32",
+            "Integer",
+            "Gen.chooseNum(Int.MinValue, Int.MaxValue).map(n => Integer.valueOf(n))",
+        ),
+        primitive(
+            "u32",
+            "Integer",
+            "Gen.chooseNum(0L, 0xffffffffL).map(n => Integer.valueOf(n.toInt))",
+        ),
+        primitive("s64", "Long", "Gen.chooseNum(Long.MinValue, Long.MaxValue)"),
+        primitive(
+            "u64",
+            "Long",
+            "Gen.chooseNum(BigInt(0), BigInt(2).pow(64) - 1).map(_.longValue)",
+        ),
+        primitive("f32", "Float", "Arbitrary.arbFloat.arbitrary"),
+        primitive("f64", "Double", "Arbitrary.arbDouble.arbitrary"),
+        primitive(
+            "char",
+            "Char",
+            "Gen.frequency(9 -> Gen.choose('\\u0000', '\\u007f'), 1 -> Gen.oneOf(Gen.choose('\\u0080', '\\ud7ff'), Gen.choose('\\ue000', '\\uffff')))",
+        ),
+        primitive("string", "String", "Gen.listOf(char.arbitrary).map(_.mkString)"),
+    ];
+
+    stats.extend([
+        combinator(
+            "The generator of `list<T>`, given the generator of `T`",
+            "list",
+            &["A"],
+            vec![Param::new("item", "Arbitrary[A]")],
+            "js.Array[A]",
+            "Gen.listOf(item.arbitrary).map(_.toJSArray)",
+        ),
+        combinator(
+            "The generator of `option<T>`, given the generator of `T`",
+            "option",
+            &["A"],
+            vec![Param::new("some", "Arbitrary[A]")],
+            "js.UndefOr[A]",
+            "Gen.option(some.arbitrary).map(_.fold[js.UndefOr[A]](js.undefined)(value => value))",
+        ),
+        combinator(
+            "The generator of `result<T, E>`, given the optional generators of `T` and `E`, of which only `ok` values are generated",
+            "result",
+            &["A", "E"],
+            vec![
+                Param::new("ok", "Option[Arbitrary[A]]"),
+                Param::new("err", "Option[Arbitrary[E]]"),
+            ],
+            "A",
+            "ok.fold(Gen.const(().asInstanceOf[A]))(_.arbitrary)",
+        ),
+        combinator(
+            "The generator of a tuple, given the generators of its items",
+            "tuple",
+            &["A"],
+            vec![Param::new("items", "Arbitrary[_]*")],
+            "A",
+            "sequence(items).map(values => js.Array(values: _*).asInstanceOf[A])",
+        ),
+        combinator(
+            "The generator of a record, given its fields",
+            "record",
+            &["A"],
+            vec![Param::new("fields", "Field*")],
+            "A",
+            "sequence(fields.map(_.arbitrary)).map(values => js.Dictionary(fields.map(_.name).zip(values): _*).asInstanceOf[A])",
+        ),
+        combinator(
+            "The generator of a variant, given its cases",
+            "variant",
+            &["A"],
+            vec![Param::new("cases", "Case*")],
+            "A",
+            "Gen.oneOf(cases).flatMap(chosen => chosen.payload.fold(Gen.const[Any](js.undefined))(_.arbitrary).map(value => js.Dictionary[Any](\"tag\" -> chosen.label, \"val\" -> value).asInstanceOf[A]))",
+        ),
+        combinator(
+            "The generator of an enum, given the WIT names of its cases",
+            "enumeration",
+            &["A"],
+            vec![Param::new("cases", "String*")],
+            "A",
+            "Gen.oneOf(cases).map(_.asInstanceOf[A])",
+        ),
+        combinator(
+            "The generator of flags, given its flags",
+            "flags",
+            &["A"],
+            vec![Param::new("flags", "Flag*")],
+            "A",
+            "Gen.listOfN(flags.length, bool.arbitrary).map(set => js.Dictionary(flags.map(_.name).zip(set): _*).asInstanceOf[A])",
+        ),
+    ]);
+    stats.extend(instances);
+
+    vec![Stat::Object(
+        Template::new("Arbitraries")
+            .doc(doc(GENERATION))
+            .body(stats),
+    )]
+}
+
+/// The `ValidArbitraries` properties, which check that the values generated for each type
+/// pass its validator, when the types get both generators and validators
+pub fn properties(names: &[String]) -> Vec<Stat> {
+    let properties = names
+        .iter()
+        .map(|name| {
+            Stat::Expr(Expr::raw(format!(
+                "property(\"{name}\") = org.scalacheck.Prop.forAll(Arbitraries.arbitrary{name}.arbitrary)(value => org.scalacheck.Prop({name}.validator(value, \"value\").isEmpty))"
+            )))
+        })
+        .collect();
+
+    vec![Stat::Object(
+        Template::new("ValidArbitraries")
+            .doc(Some(
+                "The properties checking that the generated values pass the validators of their types".to_owned(),
+            ))
+            .extends("org.scalacheck.Properties(\"ValidArbitraries\")")
+            .body(properties),
+    )]
+}
@@ -0,0 +1,267 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ast::{Def, Expr, Param, Stat, Template, Val};
+
+/// The object comparing, hashing and printing the JS representation of WIT values
+pub const STRUCTURAL: &str = "Structural";
+
+/// The `equal`, `hash` and `show` methods of the ops of a record or a variant named `name`,
+/// wrapping `self_name`, whose printed form starts with `prefix`
+pub fn operations(name: &str, self_name: &str, prefix: &str) -> Vec<Stat> {
+    vec![
+        Stat::Def(
+            Def::new("equal")
+                .doc(Some(
+                    "Whether `other` has the same members, compared structurally".to_owned(),
+                ))
+                .params(vec![Param::new("other", name)])
+                .returns("Boolean")
+                .body(Expr::raw(format!("{STRUCTURAL}.equal({self_name}, other)"))),
+        ),
+        Stat::Def(
+            Def::new("hash")
+                .doc(Some(
+                    "The hash of the members, consistent with `equal`".to_owned(),
+                ))
+                .returns("Int")
+                .body(Expr::raw(format!("{STRUCTURAL}.hash({self_name})"))),
+        ),
+        Stat::Def(
+            Def::new("show")
+                .doc(Some(format!("A readable representation of the `{name}`")))
+                .returns("String")
+                .body(Expr::raw(format!(
+                    "\"{prefix}\" + {STRUCTURAL}.show({self_name})"
+                ))),
+        ),
+    ]
+}
+
+/// The `Structural` object, which compares, hashes and prints WIT values through their
+/// JS representation, generated once per file
+pub fn runtime() -> Vec<Stat> {
+    let doc = |doc: &str| Some(doc.to_owned());
+
+    vec![Stat::Object(
+        Template::new(STRUCTURAL)
+            .doc(doc(
+                "Compares, hashes and prints WIT values through their JS representation: objects by\n\
+                their members that are not undefined, and arrays by their items",
+            ))
+            .body(vec![
+                Stat::Def(
+                    Def::new("isObject")
+                        .doc(doc("Whether a value is a JS object that is not an array, such as a record, flags\nor a variant"))
+                        .modifier("private")
+                        .params(vec![Param::new("value", "Any")])
+                        .returns("Boolean")
+                        .body(Expr::infix(
+                            "&&",
+                            vec![
+                                Expr::raw("js.typeOf(value) == \"object\""),
+                                Expr::raw("value != null"),
+                                Expr::raw("!value.isInstanceOf[Long]"),
+                                Expr::raw("!value.isInstanceOf[Char]"),
+                                Expr::raw("!js.Array.isArray(value)"),
+                            ],
+                        )),
+                ),
+                Stat::Def(
+                    Def::new("members")
+                        .doc(doc("The members of an object that are not undefined, in order"))
+                        .modifier("private")
+                        .params(vec![Param::new("value", "Any")])
+                        .returns("List[(String, Any)]")
+                        .body(Expr::Block(vec![
+                            Stat::Val(
+                                Val::new("dictionary")
+                                    .rhs(Expr::raw("value.asInstanceOf[js.Dictionary[Any]]")),
+                            ),
+                            Stat::Expr(
+                                Expr::raw("js.Object.keys")
+                                    .call(vec![Expr::raw("value.asInstanceOf[js.Object]")])
+                                    .method(
+                                        "toList.map",
+                                        vec![Expr::lambda("key", Expr::raw("key -> dictionary(key)"))],
+                                    )
+                                    .method(
+                                        "filterNot",
+                                        vec![Expr::lambda(
+                                            "member",
+                                            Expr::raw("js.isUndefined(member._2)"),
+                                        )],
+                                    ),
+                            ),
+                        ])),
+                ),
+                Stat::Def(
+                    Def::new("items")
+                        .doc(doc("The items of an array"))
+                        .modifier("private")
+                        .params(vec![Param::new("value", "Any")])
+                        .returns("List[Any]")
+                        .body(Expr::raw("value.asInstanceOf[js.Array[Any]].toList")),
+                ),
+                Stat::Def(
+                    Def::new("equal")
+                        .doc(doc("Whether two values are structurally equal"))
+                        .params(vec![Param::new("left", "Any"), Param::new("right", "Any")])
+                        .returns("Boolean")
+                        .body(Expr::Match {
+                            scrutinee: "left".to_owned(),
+                            cases: vec![
+                                (
+                                    "_ if js.Array.isArray(left) && js.Array.isArray(right)".to_owned(),
+                                    Expr::infix(
+                                        "&&",
+                                        vec![
+                                            Expr::raw("items(left).length == items(right).length"),
+                                            Expr::raw("items(left).zip(items(right))").method(
+                                                "forall",
+                                                vec![Expr::braced("case (l, r)", Expr::raw("equal(l, r)"))],
+                                            ),
+                                        ],
+                                    ),
+                                ),
+                                (
+                                    "_ if isObject(left) && isObject(right)".to_owned(),
+                                    Expr::Block(vec![
+                                        Stat::Val(Val::new("l").rhs(Expr::raw("members(left).toMap"))),
+                                        Stat::Val(Val::new("r").rhs(Expr::raw("members(right).toMap"))),
+                                        Stat::Expr(Expr::infix(
+                                            "&&",
+                                            vec![
+                                                Expr::raw("l.keySet == r.keySet"),
+                                                Expr::raw("l").method(
+                                                    "forall",
+                                                    vec![Expr::braced(
+                                                        "case (key, member)",
+                                                        Expr::raw("equal(member, r(key))"),
+                                                    )],
+                                                ),
+                                            ],
+                                        )),
+                                    ]),
+                                ),
+                                ("_".to_owned(), Expr::raw("left == right")),
+                            ],
+                        }),
+                ),
+                Stat::Def(
+                    Def::new("hash")
+                        .doc(doc("The hash of a value, consistent with `equal`"))
+                        .params(vec![Param::new("value", "Any")])
+                        .returns("Int")
+                        .body(Expr::Match {
+                            scrutinee: "value".to_owned(),
+                            cases: vec![
+                                (
+                                    "_ if js.Array.isArray(value)".to_owned(),
+                                    Expr::raw("scala.util.hashing.MurmurHash3.orderedHash")
+                                        .call(vec![Expr::raw("items(value).map(hash)")]),
+                                ),
+                                (
+                                    "_ if isObject(value)".to_owned(),
+                                    Expr::raw("scala.util.hashing.MurmurHash3.unorderedHash").call(vec![
+                                        Expr::raw("members(value)").method(
+                                            "map",
+                                            vec![Expr::braced(
+                                                "case (key, member)",
+                                                Expr::raw("(key, hash(member))"),
+                                            )],
+                                        ),
+                                    ]),
+                                ),
+                                ("_".to_owned(), Expr::raw("value.##")),
+                            ],
+                        }),
+                ),
+                Stat::Def(
+                    Def::new("show")
+                        .doc(doc(
+                            "A readable representation of a value, in which records and flags are\n\
+                            `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`",
+                        ))
+                        .params(vec![Param::new("value", "Any")])
+                        .returns("String")
+                        .body(Expr::Match {
+                            scrutinee: "value".to_owned(),
+                            cases: vec![
+                                (
+                                    "text: String".to_owned(),
+                                    Expr::raw("\"\\\"\" + text + \"\\\"\""),
+                                ),
+                                ("char: Char".to_owned(), Expr::raw("\"'\" + char + \"'\"")),
+                                (
+                                    "_ if js.Array.isArray(value)".to_owned(),
+                                    Expr::raw("items(value).map(show).mkString(\"[\", \", \", \"]\")"),
+                                ),
+                                (
+                                    "_ if isObject(value)".to_owned(),
+                                    Expr::raw("showMembers(members(value))"),
+                                ),
+                                ("_".to_owned(), Expr::raw("value.toString")),
+                            ],
+                        }),
+                ),
+                Stat::Def(
+                    Def::new("showMembers")
+                        .doc(doc("A readable representation of the members of an object"))
+                        .modifier("private")
+                        .params(vec![Param::new("fields", "List[(String, Any)]")])
+                        .returns("String")
+                        .body(Expr::Match {
+                            scrutinee: "fields".to_owned(),
+                            cases: vec![
+                                ("List((\"tag\", tag: String))".to_owned(), Expr::raw("tag")),
+                                (
+                                    "List((\"tag\", tag: String), (\"val\", payload))".to_owned(),
+                                    Expr::if_else(
+                                        Expr::infix(
+                                            "&&",
+                                            vec![
+                                                Expr::raw("isObject(payload)"),
+                                                Expr::raw("!members(payload).exists(_._1 == \"tag\")"),
+                                            ],
+                                        ),
+                                        Expr::raw("tag + show(payload)"),
+                                        Expr::raw("tag + \"(\" + show(payload) + \")\""),
+                                    ),
+                                ),
+                                (
+                                    "_".to_owned(),
+                                    Expr::raw("fields")
+                                        .method(
+                                            "map",
+                                            vec![Expr::braced(
+                                                "case (key, member)",
+                                                Expr::raw("key + \" = \" + show(member)"),
+                                            )],
+                                        )
+                                        .method(
+                                            "mkString",
+                                            vec![
+                                                Expr::raw("\"(\""),
+                                                Expr::raw("\", \""),
+                                                Expr::raw("\")\""),
+                                            ],
+                                        ),
+                                ),
+                            ],
+                        }),
+                ),
+            ]),
+    )]
+}
@@ -0,0 +1,572 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use super::ast::{Def, Expr, Param, Stat, Template, Val};
+use super::Encoding;
+use crate::types::{Type, TypeName};
+
+/// The checks of the WIT types, documenting the generated object
+const CHECKS: &str = "The validators of the JS representation of the WIT types.
+
+A validator returns the mismatches between a value and its WIT type, each of which
+starts with the WIT path of the mismatching value, such as `user.addresses[2].zip`.
+Record fields are selected by their WIT name, list items by their index in brackets,
+tuple items by their position, and variant payloads by the WIT name of their case.
+Unsigned integers are held in the Scala type of the same width as their two's
+complement, as the generators, WAVE and circe codecs do, so their validators accept the
+numbers in the unsigned range or in the range of their two's complement, both of which
+lower to the same WIT value. 64-bit integers are either `Long`s or `BigInt`s in that
+range. Results only check `ok` values, since errors are thrown rather than represented.";
+
+/// The types of an interface whose validator is declared in their companion object
+pub struct Validated(HashSet<String>);
+
+impl Validated {
+    /// Constructs a `Validated` from the names of the types that have a validator
+    pub fn new(names: HashSet<String>) -> Self {
+        Self(names)
+    }
+
+    /// The names of the types that have a validator
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        self.0.iter()
+    }
+
+    /// Adds types declared elsewhere in the file that have a validator
+    pub fn extend(&mut self, names: &HashSet<String>) {
+        self.0.extend(names.iter().cloned());
+    }
+
+    /// The Scala expression of the validator of a type, unless it refers to a type that does
+    /// not have one, such as a resource or a type declared in another interface
+    pub fn validator(&self, ty: &Type) -> Option<String> {
+        self.validates(ty).then(|| Encoding::Validation.codec(ty))
+    }
+
+    /// Whether a type and the types it refers to have a validator
+    fn validates(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Primitive(_) => true,
+            Type::Custom(TypeName::Concrete(name)) => self.0.contains(&name.to_string()),
+            Type::Custom(TypeName::Constructor(constructor)) => constructor
+                .params()
+                .iter()
+                .flatten()
+                .all(|param| self.validates(param)),
+        }
+    }
+}
+
+/// A method of the `Validation` object returning a validator
+fn combinator(
+    doc: &str,
+    name: &str,
+    tparams: &[&str],
+    params: Vec<Param>,
+    ty: &str,
+    check: Expr,
+) -> Stat {
+    Stat::Def(
+        Def::new(name)
+            .doc(Some(doc.to_owned()))
+            .tparams(tparams.iter().map(|tparam| (*tparam).to_owned()).collect())
+            .params(params)
+            .returns(format!("Validator[{ty}]"))
+            .body(validator(check)),
+    )
+}
+
+/// A validator running `check` on the `value` and its `path`
+fn validator(check: Expr) -> Expr {
+    Expr::raw("Validator").call(vec![Expr::braced("(value, path)", check)])
+}
+
+/// The mismatch of a value that is not `expected`, a Scala string
+fn mismatch(expected: &str) -> Expr {
+    Expr::raw(format!("expect({expected})(_ => false)(value, path)"))
+}
+
+/// A value of the `Validation` object that is the validator of a primitive, which
+/// checks `valid` on values described as `expected` in the mismatches
+fn primitive(name: &str, ty: &str, expected: &str, valid: Expr) -> Stat {
+    Stat::Val(
+        Val::new(name)
+            .doc(Some(format!("The validator of `{name}`")))
+            .ty(format!("Validator[{ty}]"))
+            .rhs(Expr::raw(format!("expect(\"{expected}\")")).call(vec![valid])),
+    )
+}
+
+/// A value of the `Validation` object that is the validator of an integer,
+/// which is a JS number between `min` and `max`
+fn integer(name: &str, ty: &str, min: &str, max: &str) -> Stat {
+    primitive(
+        name,
+        ty,
+        &format!("an integer between {min} and {max}"),
+        Expr::lambda(
+            "value",
+            Expr::infix(
+                "&&",
+                vec![
+                    Expr::raw("js.typeOf(value) == \"number\""),
+                    Expr::raw("value.asInstanceOf[Double].isWhole"),
+                    Expr::raw(format!("value.asInstanceOf[Double] >= {min}d")),
+                    Expr::raw(format!("value.asInstanceOf[Double] <= {max}d")),
+                ],
+            ),
+        ),
+    )
+}
+
+/// A value of the `Validation` object that is the validator of a 64-bit integer,
+/// which is a `Long` or a JS `BigInt` between `min` and `max`
+fn big_integer(name: &str, min: &str, max: &str) -> Stat {
+    primitive(
+        name,
+        "Long",
+        &format!("a Long or a BigInt between {min} and {max}"),
+        Expr::lambda(
+            "value",
+            Expr::infix(
+                "||",
+                vec![
+                    Expr::raw("value.isInstanceOf[Long]"),
+                    Expr::infix(
+                        "&&",
+                        vec![
+                            Expr::raw("js.typeOf(value) == \"bigint\""),
+                            Expr::raw(format!(
+                                "value.asInstanceOf[js.BigInt] >= js.BigInt(\"{min}\")"
+                            )),
+                            Expr::raw(format!(
+                                "value.asInstanceOf[js.BigInt] <= js.BigInt(\"{max}\")"
+                            )),
+                        ],
+                    ),
+                ],
+            ),
+        ),
+    )
+}
+
+/// The `Validation` object, which holds the validators of the WIT primitives, the combinators
+/// building the validators of the other WIT types, and the checks run at the component boundary
+pub fn runtime() -> Vec<Stat> {
+    let doc = |doc: &str| Some(doc.to_owned());
+
+    let mut stats = vec![
+        Stat::Trait(
+            Template::new("Validator")
+                .doc(doc(
+                    "A check of the JS representation of a value of type `A`, returning the mismatches\n\
+                    found under the WIT path `path`",
+                ))
+                .tparams(vec!["A".to_owned()])
+                .body(vec![Stat::Def(
+                    Def::new("apply")
+                        .params(vec![Param::new("value", "Any"), Param::new("path", "String")])
+                        .returns("List[String]"),
+                )]),
+        ),
+        Stat::Object(
+            Template::new("Validator").body(vec![Stat::Def(
+                Def::new("apply")
+                    .doc(doc("A validator running `check` on the value and its WIT path"))
+                    .tparams(vec!["A".to_owned()])
+                    .params(vec![Param::new("check", "(Any, String) => List[String]")])
+                    .returns("Validator[A]")
+                    .body(Expr::New {
+                        parents: vec!["Validator[A]".to_owned()],
+                        body: vec![Stat::Def(
+                            Def::new("apply")
+                                .params(vec![
+                                    Param::new("value", "Any"),
+                                    Param::new("path", "String"),
+                                ])
+                                .returns("List[String]")
+                                .body(Expr::raw("check(value, path)")),
+                        )],
+                    }),
+            )]),
+        ),
+        Stat::Class(
+            Template::new("ValidationError")
+                .doc(doc(
+                    "The error thrown when values crossing the component boundary do not fit their\n\
+                    WIT types",
+                ))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("context", "String"),
+                    Param::new("mismatches", "List[String]"),
+                ])
+                .extends("IllegalArgumentException(s\"$context: ${mismatches.mkString(\"; \")}\")"),
+        ),
+        Stat::Class(
+            Template::new("Field")
+                .doc(doc(
+                    "A record field, with its JS name, its WIT name and the validator of its value",
+                ))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("name", "String"),
+                    Param::new("label", "String"),
+                    Param::new("validator", "Validator[_]"),
+                ]),
+        ),
+        Stat::Class(
+            Template::new("Case")
+                .doc(doc(
+                    "A variant case, with its WIT name and the validator of its payload",
+                ))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("label", "String"),
+                    Param::new("payload", "Option[Validator[_]]"),
+                ]),
+        ),
+        Stat::Class(
+            Template::new("Flag")
+                .doc(doc("A flag, with its JS name and its WIT name"))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("name", "String"),
+                    Param::new("label", "String"),
+                ]),
+        ),
+        Stat::Def(
+            Def::new("describe")
+                .doc(doc("A short description of a value in the mismatches"))
+                .modifier("private")
+                .params(vec![Param::new("value", "Any")])
+                .returns("String")
+                .body(Expr::Match {
+                    scrutinee: "value".to_owned(),
+                    cases: vec![
+                        ("null".to_owned(), Expr::raw("\"null\"")),
+                        ("text: String".to_owned(), Expr::raw("\"\\\"\" + text + \"\\\"\"")),
+                        (
+                            "_ if js.isUndefined(value)".to_owned(),
+                            Expr::raw("\"undefined\""),
+                        ),
+                        (
+                            "_ if js.Array.isArray(value)".to_owned(),
+                            Expr::raw("\"an array\""),
+                        ),
+                        (
+                            "_ if js.typeOf(value) == \"object\"".to_owned(),
+                            Expr::raw("\"an object\""),
+                        ),
+                        ("_".to_owned(), Expr::raw("value.toString")),
+                    ],
+                }),
+        ),
+        Stat::Def(
+            Def::new("expect")
+                .doc(doc(
+                    "A validator checking `valid` on values, which are described as `expected` in the\n\
+                    mismatches",
+                ))
+                .modifier("private")
+                .tparams(vec!["A".to_owned()])
+                .params(vec![Param::new("expected", "String")])
+                .params(vec![Param::new("valid", "Any => Boolean")])
+                .returns("Validator[A]")
+                .body(validator(Expr::if_else(
+                    Expr::raw("valid(value)"),
+                    Expr::raw("Nil"),
+                    Expr::raw("List(s\"$path: expected $expected, got ${describe(value)}\")"),
+                ))),
+        ),
+        Stat::Def(
+            Def::new("isObject")
+                .doc(doc("Whether a value is a JS object that is not an array"))
+                .modifier("private")
+                .params(vec![Param::new("value", "Any")])
+                .returns("Boolean")
+                .body(Expr::infix(
+                    "&&",
+                    vec![
+                        Expr::raw("js.typeOf(value) == \"object\""),
+                        Expr::raw("value != null"),
+                        Expr::raw("!js.Array.isArray(value)"),
+                    ],
+                )),
+        ),
+        Stat::Def(
+            Def::new("fail")
+                .doc(doc("Throws the mismatches, if any, as a `ValidationError`"))
+                .modifier("private")
+                .params(vec![
+                    Param::new("context", "String"),
+                    Param::new("mismatches", "List[String]"),
+                ])
+                .returns("Unit")
+                .body(Expr::raw(
+                    "if (mismatches.nonEmpty) throw new ValidationError(context, mismatches)",
+                )),
+        ),
+        Stat::Def(
+            Def::new("arguments")
+                .doc(doc(
+                    "Checks the arguments of `function`, given with their WIT name and their\n\
+                    validator",
+                ))
+                .params(vec![
+                    Param::new("function", "String"),
+                    Param::new("arguments", "(String, Validator[_], Any)*"),
+                ])
+                .returns("Unit")
+                .body(Expr::raw("fail").call(vec![
+                    Expr::raw("s\"Invalid arguments of $function\""),
+                    Expr::raw("arguments.toList").method(
+                        "flatMap",
+                        vec![Expr::braced(
+                            "case (name, validator, value)",
+                            Expr::raw("validator(value, name)"),
+                        )],
+                    ),
+                ])),
+        ),
+        Stat::Def(
+            Def::new("results")
+                .doc(doc(
+                    "Checks the results of `function`, which are returned if they fit their WIT\n\
+                    type",
+                ))
+                .tparams(vec!["A".to_owned()])
+                .params(vec![
+                    Param::new("function", "String"),
+                    Param::new("validator", "Validator[A]"),
+                ])
+                .params(vec![Param::new("value", "A")])
+                .returns("A")
+                .body(Expr::Block(vec![
+                    Stat::Expr(Expr::raw(
+                        "fail(s\"Invalid results of $function\", validator(value, \"result\"))",
+                    )),
+                    Stat::Expr(Expr::raw("value")),
+                ])),
+        ),
+        primitive(
+            "bool",
+            "Boolean",
+            "a bool",
+            Expr::lambda("value", Expr::raw("js.typeOf(value) == \"boolean\"")),
+        ),
+        integer("s8", "Byte", "-128", "127"),
+        integer("u8", "Byte", "-128", "255"),
+        integer("s16", "Short", "-32768", "32767"),
+        integer("u16", "Short", "-32768", "65535"),
+        integer("s32", "Integer", "-2147483648", "2147483647"),
+        integer("u32", "Integer", "-2147483648", "4294967295"),
+        big_integer("s64", "-9223372036854775808", "9223372036854775807"),
+        big_integer("u64", "-9223372036854775808", "18446744073709551615"),
+        primitive("f32", "Float", "an f32", Expr::raw("_.isInstanceOf[Float]")),
+        primitive(
+            "f64",
+            "Double",
+            "an f64",
+            Expr::lambda("value", Expr::raw("js.typeOf(value) == \"number\"")),
+        ),
+        primitive(
+            "char",
+            "Char",
+            "a char that is not a surrogate",
+            Expr::lambda(
+                "value",
+                Expr::infix(
+                    "&&",
+                    vec![
+                        Expr::raw("value.isInstanceOf[Char]"),
+                        Expr::raw("!value.asInstanceOf[Char].isSurrogate"),
+                    ],
+                ),
+            ),
+        ),
+        primitive(
+            "string",
+            "String",
+            "a string",
+            Expr::lambda("value", Expr::raw("js.typeOf(value) == \"string\"")),
+        ),
+    ];
+
+    stats.extend([
+        combinator(
+            "The validator of `list<T>`, given the validator of `T`",
+            "list",
+            &["A"],
+            vec![Param::new("item", "Validator[A]")],
+            "js.Array[A]",
+            Expr::if_else(
+                Expr::raw("js.Array.isArray(value)"),
+                Expr::raw("value.asInstanceOf[js.Array[Any]].toList.zipWithIndex").method(
+                    "flatMap",
+                    vec![Expr::braced(
+                        "case (element, i)",
+                        Expr::raw("item(element, s\"$path[$i]\")"),
+                    )],
+                ),
+                mismatch("\"a list\""),
+            ),
+        ),
+        combinator(
+            "The validator of `option<T>`, given the validator of `T`",
+            "option",
+            &["A"],
+            vec![Param::new("some", "Validator[A]")],
+            "js.UndefOr[A]",
+            Expr::if_else(
+                Expr::raw("js.isUndefined(value)"),
+                Expr::raw("Nil"),
+                Expr::raw("some(value, path)"),
+            ),
+        ),
+        combinator(
+            "The validator of `result<T, E>`, given the optional validators of `T` and `E`, of which\n\
+            only `ok` values are checked",
+            "result",
+            &["A", "E"],
+            vec![
+                Param::new("ok", "Option[Validator[A]]"),
+                Param::new("err", "Option[Validator[E]]"),
+            ],
+            "A",
+            Expr::raw("ok.fold(List.empty[String])(_(value, path))"),
+        ),
+        combinator(
+            "The validator of a tuple, given the validators of its items",
+            "tuple",
+            &["A"],
+            vec![Param::new("items", "Validator[_]*")],
+            "A",
+            Expr::if_else(
+                Expr::infix(
+                    "&&",
+                    vec![
+                        Expr::raw("js.Array.isArray(value)"),
+                        Expr::raw("value.asInstanceOf[js.Array[Any]].length == items.length"),
+                    ],
+                ),
+                Expr::raw("items.toList.zip")
+                    .call(vec![Expr::raw("value.asInstanceOf[js.Array[Any]].toList")])
+                    .method(
+                        "zipWithIndex.flatMap",
+                        vec![Expr::braced(
+                            "case ((item, element), i)",
+                            Expr::raw("item(element, s\"$path.$i\")"),
+                        )],
+                    ),
+                mismatch("s\"a tuple of ${items.length} items\""),
+            ),
+        ),
+        combinator(
+            "The validator of a record, given its fields",
+            "record",
+            &["A"],
+            vec![Param::new("fields", "Field*")],
+            "A",
+            Expr::if_else(
+                Expr::raw("isObject(value)"),
+                Expr::raw("fields.toList").method(
+                    "flatMap",
+                    vec![Expr::lambda(
+                        "field",
+                        Expr::raw("field.validator").call(vec![
+                            Expr::raw("value.asInstanceOf[js.Dynamic].selectDynamic(field.name)"),
+                            Expr::raw("s\"$path.${field.label}\""),
+                        ]),
+                    )],
+                ),
+                mismatch("\"a record\""),
+            ),
+        ),
+        combinator(
+            "The validator of a variant, given its cases",
+            "variant",
+            &["A"],
+            vec![Param::new("cases", "Case*")],
+            "A",
+            Expr::if_else(
+                Expr::raw("isObject(value)"),
+                Expr::Block(vec![
+                    Stat::Val(
+                        Val::new("variant").rhs(Expr::raw("value.asInstanceOf[js.Dynamic]")),
+                    ),
+                    Stat::Expr(Expr::Match {
+                        scrutinee: "cases.find(_.label == variant.tag.asInstanceOf[Any])".to_owned(),
+                        cases: vec![
+                            (
+                                "Some(Case(label, payload))".to_owned(),
+                                Expr::raw("payload.fold(List.empty[String])")
+                                    .call(vec![Expr::raw("_(variant.`val`, s\"$path.$label\")")]),
+                            ),
+                            (
+                                "None".to_owned(),
+                                Expr::raw("List(s\"$path: unknown case ${describe(variant.tag)}\")"),
+                            ),
+                        ],
+                    }),
+                ]),
+                mismatch("\"a variant\""),
+            ),
+        ),
+        combinator(
+            "The validator of an enum, given the WIT names of its cases",
+            "enumeration",
+            &["A"],
+            vec![Param::new("cases", "String*")],
+            "A",
+            Expr::if_else(
+                Expr::raw("cases.contains(value)"),
+                Expr::raw("Nil"),
+                Expr::raw("List(s\"$path: unknown case ${describe(value)}\")"),
+            ),
+        ),
+        combinator(
+            "The validator of flags, given its flags",
+            "flags",
+            &["A"],
+            vec![Param::new("flags", "Flag*")],
+            "A",
+            Expr::if_else(
+                Expr::raw("isObject(value)"),
+                Expr::raw("flags.toList").method(
+                    "flatMap",
+                    vec![Expr::lambda(
+                        "flag",
+                        Expr::raw("expect(\"a bool\")")
+                            .call(vec![Expr::lambda(
+                                "set",
+                                Expr::raw("js.isUndefined(set) || js.typeOf(set) == \"boolean\""),
+                            )])
+                            .call(vec![
+                                Expr::raw("value.asInstanceOf[js.Dynamic].selectDynamic(flag.name)"),
+                                Expr::raw("s\"$path.${flag.label}\""),
+                            ]),
+                    )],
+                ),
+                mismatch("\"flags\""),
+            ),
+        ),
+    ]);
+
+    vec![Stat::Object(
+        Template::new("Validation").doc(doc(CHECKS)).body(stats),
+    )]
+}
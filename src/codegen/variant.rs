@@ -16,13 +16,16 @@ use std::fmt::Display;
 
 use color_eyre::Result;
 use convert_case::{Case, Casing};
-use wit_parser::{Case as WitCase, Variant as WitVariant};
+use wit_parser::{Case as WitCase, Docs, Variant as WitVariant};
 
-use super::Render;
-use crate::types::{ConcreteName, Type, TypeMap, TypeName};
+use super::ast::{Def, Expr, Param, Stat, Template, TypeAlias, Val};
+use super::docs::scaladoc;
+use super::{structural, Encoding, Layer, Render, IDIOMATIC_HELPERS};
+use crate::naming::{identifiers, ScalaName, Scope, VARIANT_HELPERS};
+use crate::types::{Type, TypeMap, TypeName};
 
 /// Represents the name of a variant case in Scala
-struct CaseName(String);
+struct CaseName(ScalaName);
 
 impl Display for CaseName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -32,7 +35,7 @@ impl Display for CaseName {
 
 impl From<String> for CaseName {
     fn from(name: String) -> Self {
-        Self(name.to_case(Case::Camel))
+        Self(ScalaName::new(&name, Case::Camel, VARIANT_HELPERS))
     }
 }
 
@@ -43,6 +46,9 @@ struct VariantCase {
 
     /// The internal case type
     ty: Option<Type>,
+
+    /// The Scaladoc of the case
+    doc: Option<String>,
 }
 
 impl VariantCase {
@@ -54,6 +60,7 @@ impl VariantCase {
                 Some(ty) => Some(Type::from_wit(ty, type_map)?),
                 None => None,
             },
+            doc: scaladoc(&case.docs),
         })
     }
 }
@@ -65,11 +72,22 @@ pub struct Variant {
 
     /// The variant cases
     cases: Vec<VariantCase>,
+
+    /// The Scaladoc of the variant
+    doc: Option<String>,
+
+    /// The implicit instances declared in the companion object
+    instances: Vec<Stat>,
 }
 
 impl Variant {
     /// Constructs a `Variant` from WIT
-    pub fn from_wit(name: &str, variant: &WitVariant, type_map: &TypeMap) -> Result<Self> {
+    pub fn from_wit(
+        name: TypeName,
+        variant: &WitVariant,
+        docs: &Docs,
+        type_map: &TypeMap,
+    ) -> Result<Self> {
         let cases: Result<Vec<VariantCase>> = variant
             .cases
             .iter()
@@ -77,63 +95,396 @@ impl Variant {
             .collect();
 
         Ok(Self {
-            name: TypeName::Concrete(ConcreteName::from(name.to_owned())),
+            name,
             cases: cases?,
+            doc: scaladoc(docs),
+            instances: Vec::new(),
         })
     }
+
+    /// Declares the codec of the variant in its companion object
+    pub fn codec(mut self, encoding: Encoding) -> Self {
+        let instance = self.instance(encoding);
+        self.instances.push(instance);
+        self
+    }
+
+    /// The implicit codec of the variant
+    pub fn instance(&self, encoding: Encoding) -> Stat {
+        let runtime = encoding.runtime();
+        let cases = self
+            .cases
+            .iter()
+            .map(|case| {
+                Expr::raw(format!("{runtime}.Case")).call(vec![
+                    Expr::raw(format!("\"{}\"", case.name.0.wit())),
+                    case.ty.as_ref().map_or(Expr::raw("None"), |ty| {
+                        Expr::raw("Some").call(vec![Expr::raw(encoding.codec(ty))])
+                    }),
+                ])
+            })
+            .collect();
+
+        let name = self.name.to_string();
+        encoding.instance(
+            &name,
+            Expr::raw(format!("{runtime}.variant[{name}]")).call(cases),
+        )
+    }
+
+    /// The `Case` hierarchy with one subtype per case, and the `fold` and `toCase` methods
+    /// added to the variant, so that matches on it are checked for exhaustiveness, along with
+    /// its structural operations
+    fn cases(&self, name: &str) -> Vec<Stat> {
+        // The names declared in the companion object must not shadow the types of the payloads
+        let referenced = identifiers(
+            self.cases
+                .iter()
+                .filter_map(|case| case.ty.as_ref())
+                .map(Type::to_string),
+        );
+        let mut companion = Scope::new(referenced.iter().map(String::as_str).chain([name]));
+        let case_trait = companion.fresh("Case");
+        let ops = companion.fresh("Ops");
+        let tparam = companion.fresh("A");
+
+        let mut subtypes = Scope::new(referenced.iter().map(String::as_str).chain([name]));
+        subtypes.extend([case_trait.as_str()]);
+        let subtype_names: Vec<String> = self
+            .cases
+            .iter()
+            .map(|case| subtypes.fresh(&case.name.0.wit().to_case(Case::Pascal)))
+            .collect();
+
+        let mut terms = Scope::new(self.cases.iter().map(|case| case.name.0.unescaped()));
+        let self_name = terms.fresh("self");
+        let other = terms.fresh("tag");
+
+        let subtypes = self
+            .cases
+            .iter()
+            .zip(&subtype_names)
+            .map(|(case, subtype)| match &case.ty {
+                Some(ty) => Stat::Class(
+                    Template::new(subtype)
+                        .doc(case.doc.clone())
+                        .modifier("final case")
+                        .params(vec![Param::new("value", ty.to_string())])
+                        .extends(&case_trait),
+                ),
+                None => Stat::Object(
+                    Template::new(subtype)
+                        .doc(case.doc.clone())
+                        .modifier("case")
+                        .extends(&case_trait),
+                ),
+            })
+            .collect();
+
+        let fold_params = self
+            .cases
+            .iter()
+            .map(|case| match &case.ty {
+                Some(ty) => Param::new(case.name.to_string(), format!("{ty} => {tparam}")),
+                None => Param::new(case.name.to_string(), format!("=> {tparam}")),
+            })
+            .collect();
+
+        let mut fold_cases: Vec<(String, Expr)> = self
+            .cases
+            .iter()
+            .map(|case| {
+                let body = match &case.ty {
+                    Some(ty) => format!("{}({self_name}.`val`.asInstanceOf[{ty}])", case.name),
+                    None => case.name.to_string(),
+                };
+                (format!("\"{}\"", case.name.0.wit()), Expr::raw(body))
+            })
+            .collect();
+        fold_cases.push((
+            other.clone(),
+            Expr::raw(format!(
+                "throw new IllegalStateException(s\"Unknown case ${other} of {name}\")"
+            )),
+        ));
+
+        let to_case = self
+            .cases
+            .iter()
+            .zip(&subtype_names)
+            .map(|(case, subtype)| match case.ty {
+                Some(_) => format!("{case_trait}.{subtype}(_)"),
+                None => format!("{case_trait}.{subtype}"),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        vec![
+            Stat::Trait(
+                Template::new(&case_trait)
+                    .doc(Some(format!(
+                        "A case of `{name}`, matches on which are checked for exhaustiveness"
+                    )))
+                    .modifier("sealed"),
+            ),
+            Stat::Object(Template::new(&case_trait).body(subtypes)),
+            Stat::Class(
+                Template::new(&ops)
+                    .doc(Some(format!(
+                        "The pattern matching and structural methods of `{name}`"
+                    )))
+                    .modifier("implicit final")
+                    .params(vec![Param::new(&self_name, name)])
+                    .extends("AnyVal")
+                    .body(
+                        vec![
+                        Stat::Def(
+                            Def::new("fold")
+                                .doc(Some(
+                                    "Applies the function of the case of the variant to its payload"
+                                        .to_owned(),
+                                ))
+                                .tparams(vec![tparam.clone()])
+                                .params(fold_params)
+                                .returns(&tparam)
+                                .body(Expr::Match {
+                                    scrutinee: format!("{self_name}.tag"),
+                                    cases: fold_cases,
+                                }),
+                        ),
+                        Stat::Def(
+                            Def::new("toCase")
+                                .doc(Some(
+                                    "The case of the variant, which can be matched exhaustively"
+                                        .to_owned(),
+                                ))
+                                .returns(&case_trait)
+                                .body(Expr::raw(format!("fold({to_case})"))),
+                        ),
+                    ]
+                        .into_iter()
+                        .chain(structural::operations(
+                            name,
+                            &self_name,
+                            &format!("{name}."),
+                        ))
+                        .collect(),
+                    ),
+            ),
+        ]
+    }
+
+    /// The idiomatic counterpart of the variant, a sealed trait with a case class or a case object
+    /// per case, whose companion object converts it from and to the JS representation
+    pub fn idiomatic(&self, layer: &Layer) -> Vec<Stat> {
+        let name = self.name.to_string();
+        if !layer.converts(&name) {
+            return Vec::new();
+        }
+
+        let js = layer.js_name(&name);
+
+        let mut scope = Scope::new(
+            identifiers(
+                self.cases
+                    .iter()
+                    .filter_map(|case| case.ty.as_ref())
+                    .map(|ty| layer.ty(ty)),
+            )
+            .into_iter()
+            .chain(IDIOMATIC_HELPERS.iter().map(|helper| (*helper).to_owned()))
+            .chain([name.clone()]),
+        );
+        let subtypes: Vec<String> = self
+            .cases
+            .iter()
+            .map(|case| scope.fresh(&case.name.0.wit().to_case(Case::Pascal)))
+            .collect();
+
+        let mut members: Vec<Stat> = self
+            .cases
+            .iter()
+            .zip(&subtypes)
+            .map(|(case, subtype)| match &case.ty {
+                Some(ty) => Stat::Class(
+                    Template::new(subtype)
+                        .doc(case.doc.clone())
+                        .modifier("final case")
+                        .params(vec![Param::new("value", layer.ty(ty))])
+                        .extends(&name),
+                ),
+                None => Stat::Object(
+                    Template::new(subtype)
+                        .doc(case.doc.clone())
+                        .modifier("case")
+                        .extends(&name),
+                ),
+            })
+            .collect();
+
+        let to_js = self
+            .cases
+            .iter()
+            .zip(&subtypes)
+            .map(|(case, subtype)| match &case.ty {
+                Some(ty) => (
+                    format!("{subtype}(payload)"),
+                    Expr::raw(format!(
+                        "{js}.{}({})",
+                        case.name,
+                        layer.to_js(ty, "payload")
+                    )),
+                ),
+                None => (subtype.clone(), Expr::raw(format!("{js}.{}", case.name))),
+            })
+            .collect();
+
+        let mut to_idiomatic: Vec<(String, Expr)> = self
+            .cases
+            .iter()
+            .zip(&subtypes)
+            .map(|(case, subtype)| {
+                let body = match &case.ty {
+                    Some(ty) => format!(
+                        "{subtype}({})",
+                        layer.to_idiomatic(
+                            ty,
+                            &format!("value.`val`.asInstanceOf[{}]", layer.js_ty(ty))
+                        )
+                    ),
+                    None => subtype.clone(),
+                };
+                (format!("\"{}\"", case.name.0.wit()), Expr::raw(body))
+            })
+            .collect();
+        to_idiomatic.push((
+            "tag".to_owned(),
+            Expr::raw(format!(
+                "throw new IllegalStateException(s\"Unknown case $tag of {name}\")"
+            )),
+        ));
+
+        members.extend([
+            Stat::Def(
+                Def::new("toJS")
+                    .doc(Some(format!("Converts `{name}` to its JS representation")))
+                    .params(vec![Param::new("value", &name)])
+                    .returns(&js)
+                    .body(Expr::Match {
+                        scrutinee: "value".to_owned(),
+                        cases: to_js,
+                    }),
+            ),
+            Stat::Def(
+                Def::new("fromJS")
+                    .doc(Some(format!("Converts the JS representation of `{name}`")))
+                    .params(vec![Param::new("value", &js)])
+                    .returns(&name)
+                    .body(Expr::Match {
+                        scrutinee: "value.tag".to_owned(),
+                        cases: to_idiomatic,
+                    }),
+            ),
+        ]);
+
+        vec![
+            Stat::Trait(
+                Template::new(&name)
+                    .doc(self.doc.clone())
+                    .modifier("sealed"),
+            ),
+            Stat::Object(Template::new(&name).body(members)),
+        ]
+    }
+
+    /// The names of the cases, so that collisions between them can be resolved
+    pub fn case_names_mut(&mut self) -> impl Iterator<Item = &mut ScalaName> {
+        self.cases.iter_mut().map(|case| &mut case.name.0)
+    }
 }
 
 impl Render for Variant {
-    fn render(self) -> Result<String> {
-        let name = self.name;
+    fn render(self) -> Result<Vec<Stat>> {
+        let name = self.name.to_string();
+        let members = ["Type", "tag", "val"];
+        let self_alias = Scope::new(members).fresh("self");
 
-        let constructors = self
+        let mut constructors: Vec<Stat> = self
             .cases
             .iter()
             .map(
                 |VariantCase {
                      name: case_name,
                      ty,
+                     doc,
                  }| {
-                    let (def_or_val, param_list, val, type_tag) = if let Some(ty) = ty {
-                        (
-                            "def",
-                            format!("(value: {ty})"),
-                            "override val `val`: js.UndefOr[Type] = value",
-                            format!("type Type = {ty}"),
-                        )
-                    } else {
-                        ("val", String::new(), "", "type Type = Nothing".to_owned())
-                    };
-
-                    format!(
-                        "
-                            {def_or_val} {case_name}{param_list} = new {name} {{
-                                {type_tag}
-                                
-                                val tag: String = \"{case_name}\"
-                                {val}
-                            }}
-                        "
-                    )
+                    let tag = Stat::Val(
+                        Val::new("tag")
+                            .ty("String")
+                            .rhs(Expr::raw(format!("\"{}\"", case_name.0.wit()))),
+                    );
+
+                    match ty {
+                        Some(ty) => {
+                            let mut scope = Scope::new(members);
+                            scope.extend(self.cases.iter().map(|case| case.name.0.unescaped()));
+                            let value = scope.fresh("value");
+
+                            Stat::Def(
+                                Def::new(case_name.to_string())
+                                    .doc(doc.clone())
+                                    .params(vec![Param::new(&value, ty.to_string())])
+                                    .body(Expr::New {
+                                        parents: vec![name.clone()],
+                                        body: vec![
+                                            Stat::TypeAlias(
+                                                TypeAlias::new("Type").rhs(ty.to_string()),
+                                            ),
+                                            tag,
+                                            Stat::Val(
+                                                Val::new("`val`")
+                                                    .modifier("override")
+                                                    .ty("js.UndefOr[Type]")
+                                                    .rhs(Expr::Raw(value)),
+                                            ),
+                                        ],
+                                    }),
+                            )
+                        }
+                        None => Stat::Val(Val::new(case_name.to_string()).doc(doc.clone()).rhs(
+                            Expr::New {
+                                parents: vec![name.clone()],
+                                body: vec![
+                                    Stat::TypeAlias(TypeAlias::new("Type").rhs("Nothing")),
+                                    tag,
+                                ],
+                            },
+                        )),
+                    }
                 },
             )
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        Ok(format!(
-            "
-                sealed trait {name} extends js.Object {{ self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }}
-
-                object {name} {{
-                    {constructors}
-                }}
-            "
-        ))
+            .collect();
+        constructors.extend(self.cases(&name));
+        constructors.extend(self.instances);
+
+        Ok(vec![
+            Stat::Trait(
+                Template::new(&name)
+                    .doc(self.doc)
+                    .modifier("sealed")
+                    .extends("js.Object")
+                    .self_alias(self_alias)
+                    .body(vec![
+                        Stat::TypeAlias(TypeAlias::new("Type")),
+                        Stat::Val(Val::new("tag").ty("String")),
+                        Stat::Val(
+                            Val::new("`val`")
+                                .ty("js.UndefOr[Type]")
+                                .rhs(Expr::raw("js.undefined")),
+                        ),
+                    ]),
+            ),
+            Stat::Object(Template::new(&name).body(constructors)),
+        ])
     }
 }
@@ -0,0 +1,758 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ast::{Def, Expr, Param, Stat, Template, Val};
+
+/// A method with a single param list
+fn method(name: &str, params: Vec<Param>, returns: &str, body: Expr) -> Def {
+    Def::new(name).params(params).returns(returns).body(body)
+}
+
+/// A sequence of single-line statements, the last one being the value of the block
+fn block(lines: &[&str]) -> Expr {
+    Expr::Block(
+        lines
+            .iter()
+            .map(|line| Stat::Expr(Expr::raw(*line)))
+            .collect(),
+    )
+}
+
+/// The `write` and `read` methods of a codec of `ty`
+fn codec(ty: &str, write: Expr, read: Expr) -> Vec<Stat> {
+    vec![
+        Stat::Def(method(
+            "write",
+            vec![Param::new("value", ty), Param::new("out", "StringBuilder")],
+            "Unit",
+            write,
+        )),
+        Stat::Def(method("read", vec![Param::new("in", "Reader")], ty, read)),
+    ]
+}
+
+/// A method of the `Wave` object returning a codec
+fn combinator(
+    doc: &str,
+    name: &str,
+    tparams: &[&str],
+    params: Vec<Param>,
+    ty: &str,
+    body: Vec<Stat>,
+) -> Stat {
+    Stat::Def(
+        Def::new(name)
+            .doc(Some(doc.to_owned()))
+            .tparams(tparams.iter().map(|tparam| (*tparam).to_owned()).collect())
+            .params(params)
+            .returns(format!("Wave[{ty}]"))
+            .body(Expr::New {
+                parents: vec![format!("Wave[{ty}]")],
+                body,
+            }),
+    )
+}
+
+/// A value of the `Wave` object that is the codec of a primitive, written as a single token
+fn primitive(name: &str, wit: &str, ty: &str, show: &str, read: &str) -> Stat {
+    Stat::Val(
+        Val::new(name)
+            .doc(Some(format!("The codec of `{wit}`")))
+            .ty(format!("Wave[{ty}]"))
+            .rhs(Expr::raw(format!("primitive[{ty}]({show}, {read})"))),
+    )
+}
+
+/// The `Reader` class, which reads the tokens of a WAVE
+fn reader() -> Stat {
+    let doc = |doc: &str| Some(doc.to_owned());
+
+    Stat::Class(
+        Template::new("Reader")
+            .doc(doc("Reads the tokens of a WAVE, failing with a `ParseError`"))
+            .modifier("final")
+            .params(vec![Param::new("text", "String")])
+            .body(vec![
+                Stat::Var(Val::new("offset").modifier("private").ty("Int").rhs(Expr::raw("0"))),
+                Stat::Def(
+                    method("fail", vec![Param::new("message", "String")], "Nothing", Expr::raw("throw ParseError(message, offset)"))
+                        .doc(doc("Fails at the current offset")),
+                ),
+                Stat::Def(
+                    method(
+                        "skip",
+                        vec![],
+                        "Unit",
+                        Expr::raw("while (offset < text.length && text.charAt(offset).isWhitespace) offset += 1"),
+                    )
+                    .modifier("private"),
+                ),
+                Stat::Def(
+                    method(
+                        "peek",
+                        vec![Param::new("c", "Char")],
+                        "Boolean",
+                        block(&["skip()", "offset < text.length && text.charAt(offset) == c"]),
+                    )
+                    .doc(doc("Whether the next char after whitespace is `c`")),
+                ),
+                Stat::Def(
+                    method(
+                        "accept",
+                        vec![Param::new("c", "Char")],
+                        "Boolean",
+                        Expr::raw("peek(c) && { offset += 1; true }"),
+                    )
+                    .doc(doc("Reads the next char if it is `c`")),
+                ),
+                Stat::Def(
+                    method(
+                        "expect",
+                        vec![Param::new("c", "Char")],
+                        "Unit",
+                        Expr::raw("if (!accept(c)) fail(s\"expected '$c'\")"),
+                    )
+                    .doc(doc("Reads the next char, failing unless it is `c`")),
+                ),
+                Stat::Def(
+                    method(
+                        "token",
+                        vec![],
+                        "String",
+                        Expr::Block(vec![
+                            Stat::Expr(Expr::raw("skip()")),
+                            Stat::Val(Val::new("start").rhs(Expr::raw("offset"))),
+                            Stat::Expr(Expr::raw(
+                                "while (offset < text.length && (text.charAt(offset).isLetterOrDigit || \"-+.%\".contains(text.charAt(offset)))) offset += 1",
+                            )),
+                            Stat::Expr(Expr::raw("if (offset == start) fail(\"expected a value\")")),
+                            Stat::Expr(Expr::raw("text.substring(start, offset)")),
+                        ]),
+                    )
+                    .doc(doc("Reads a number or a label, such as `-1.5`, `nan` or `%true`")),
+                ),
+                Stat::Def(
+                    method("label", vec![], "String", Expr::raw("token().stripPrefix(\"%\")"))
+                        .doc(doc("Reads a label, without the `%` of the labels spelled like keywords")),
+                ),
+                Stat::Def(
+                    method(
+                        "quoted",
+                        vec![Param::new("quote", "Char")],
+                        "String",
+                        Expr::Block(vec![
+                            Stat::Expr(Expr::raw("expect(quote)")),
+                            Stat::Val(Val::new("out").rhs(Expr::raw("new StringBuilder"))),
+                            Stat::Expr(Expr::raw(
+                                "while (offset < text.length && text.charAt(offset) != quote) out.append(char())",
+                            )),
+                            Stat::Expr(Expr::raw("expect(quote)")),
+                            Stat::Expr(Expr::raw("out.toString")),
+                        ]),
+                    )
+                    .doc(doc("Reads a string or a char delimited by `quote`, unescaping it")),
+                ),
+                Stat::Def(
+                    method(
+                        "char",
+                        vec![],
+                        "String",
+                        Expr::Block(vec![
+                            Stat::Val(Val::new("c").rhs(Expr::raw("text.charAt(offset)"))),
+                            Stat::Expr(Expr::raw("offset += 1")),
+                            Stat::Expr(Expr::raw(
+                                "if (c == '\\\\' && offset < text.length) escape() else c.toString",
+                            )),
+                        ]),
+                    )
+                    .modifier("private"),
+                ),
+                Stat::Def(
+                    method(
+                        "escape",
+                        vec![],
+                        "String",
+                        Expr::Block(vec![
+                            Stat::Val(Val::new("c").rhs(Expr::raw("text.charAt(offset)"))),
+                            Stat::Expr(Expr::raw("offset += 1")),
+                            Stat::Expr(Expr::Match {
+                                scrutinee: "c".to_owned(),
+                                cases: vec![
+                                    ("'t'".to_owned(), Expr::raw("\"\\t\"")),
+                                    ("'n'".to_owned(), Expr::raw("\"\\n\"")),
+                                    ("'r'".to_owned(), Expr::raw("\"\\r\"")),
+                                    ("'u'".to_owned(), Expr::raw("unicode()")),
+                                    ("_".to_owned(), Expr::raw("c.toString")),
+                                ],
+                            }),
+                        ]),
+                    )
+                    .modifier("private"),
+                ),
+                Stat::Def(
+                    method(
+                        "unicode",
+                        vec![],
+                        "String",
+                        Expr::Block(vec![
+                            Stat::Val(Val::new("end").rhs(Expr::raw("text.indexOf('}', offset)"))),
+                            Stat::Expr(Expr::raw(
+                                "if (!accept('{') || end < 0) fail(\"invalid unicode escape\")",
+                            )),
+                            Stat::Val(Val::new("code").rhs(Expr::raw(
+                                "java.lang.Integer.parseInt(text.substring(offset, end), 16)",
+                            ))),
+                            Stat::Expr(Expr::raw("offset = end + 1")),
+                            Stat::Expr(Expr::raw("new String(Character.toChars(code))")),
+                        ]),
+                    )
+                    .modifier("private"),
+                ),
+                Stat::Def(
+                    Def::new("items")
+                        .doc(doc(
+                            "Reads comma-separated items up to `close`, the opening delimiter being already read",
+                        ))
+                        .tparams(vec!["A".to_owned()])
+                        .params(vec![Param::new("close", "Char")])
+                        .params(vec![Param::new("item", "=> A")])
+                        .returns("List[A]")
+                        .body(Expr::Block(vec![
+                            Stat::Val(Val::new("values").rhs(Expr::raw("List.newBuilder[A]"))),
+                            Stat::Expr(Expr::raw(
+                                "while (!accept(close)) { values += item; if (!peek(close)) expect(',') }",
+                            )),
+                            Stat::Expr(Expr::raw("values.result()")),
+                        ])),
+                ),
+                Stat::Def(
+                    Def::new("sequence")
+                        .doc(doc("Reads comma-separated items between delimiters"))
+                        .tparams(vec!["A".to_owned()])
+                        .params(vec![Param::new("open", "Char"), Param::new("close", "Char")])
+                        .params(vec![Param::new("item", "=> A")])
+                        .returns("List[A]")
+                        .body(block(&["expect(open)", "items(close)(item)"])),
+                ),
+                Stat::Def(
+                    Def::new("payload")
+                        .doc(doc("Reads the payload of a case, between parentheses"))
+                        .tparams(vec!["A".to_owned()])
+                        .params(vec![Param::new("codec", "Wave[A]")])
+                        .returns("A")
+                        .body(Expr::Block(vec![
+                            Stat::Expr(Expr::raw("expect('(')")),
+                            Stat::Val(Val::new("value").rhs(Expr::raw("codec.read(this)"))),
+                            Stat::Expr(Expr::raw("expect(')')")),
+                            Stat::Expr(Expr::raw("value")),
+                        ])),
+                ),
+                Stat::Def(
+                    method(
+                        "end",
+                        vec![],
+                        "Unit",
+                        block(&["skip()", "if (offset < text.length) fail(\"unexpected input\")"]),
+                    )
+                    .doc(doc("Fails unless the whole text was read")),
+                ),
+            ]),
+    )
+}
+
+/// The `Wave` type class and its companion object, which holds the codecs of the WIT primitives,
+/// the combinators building the codecs of the other WIT types and the `Reader` they share
+pub fn runtime() -> Vec<Stat> {
+    let doc = |doc: &str| Some(doc.to_owned());
+
+    let mut stats = vec![
+        Stat::Class(
+            Template::new("ParseError")
+                .doc(doc("A WAVE that could not be parsed, with the offset at which parsing failed"))
+                .modifier("final case")
+                .params(vec![Param::new("message", "String"), Param::new("offset", "Int")])
+                .extends("Exception(s\"$message at offset $offset\")"),
+        ),
+        Stat::Class(
+            Template::new("Field")
+                .doc(doc("A record field, with its JS name, its WIT name and the codec of its value"))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("name", "String"),
+                    Param::new("label", "String"),
+                    Param::new("codec", "Wave[_]"),
+                ]),
+        ),
+        Stat::Class(
+            Template::new("Case")
+                .doc(doc("A variant case, with its WIT name and the codec of its payload"))
+                .modifier("final case")
+                .params(vec![
+                    Param::new("label", "String"),
+                    Param::new("payload", "Option[Wave[_]]"),
+                ]),
+        ),
+        Stat::Class(
+            Template::new("Flag")
+                .doc(doc("A flag, with its JS name and its WIT name"))
+                .modifier("final case")
+                .params(vec![Param::new("name", "String"), Param::new("label", "String")]),
+        ),
+        reader(),
+        Stat::Val(
+            Val::new("keywords")
+                .modifier("private")
+                .ty("Set[String]")
+                .rhs(Expr::raw(
+                    "Set(\"true\", \"false\", \"some\", \"none\", \"ok\", \"err\", \"inf\", \"nan\")",
+                )),
+        ),
+        Stat::Def(
+            method(
+                "writeLabel",
+                vec![Param::new("label", "String"), Param::new("out", "StringBuilder")],
+                "Unit",
+                Expr::raw("out.append(if (keywords(label)) s\"%$label\" else label)"),
+            )
+            .modifier("private"),
+        ),
+        Stat::Def(
+            method(
+                "writeQuoted",
+                vec![
+                    Param::new("text", "String"),
+                    Param::new("quote", "Char"),
+                    Param::new("out", "StringBuilder"),
+                ],
+                "Unit",
+                block(&[
+                    "out.append(quote)",
+                    "text.foreach(c => out.append(escape(c, quote)))",
+                    "out.append(quote)",
+                ]),
+            )
+            .modifier("private"),
+        ),
+        Stat::Def(
+            method(
+                "escape",
+                vec![Param::new("c", "Char"), Param::new("quote", "Char")],
+                "String",
+                Expr::Match {
+                    scrutinee: "c".to_owned(),
+                    cases: vec![
+                        ("'\\t'".to_owned(), Expr::raw("\"\\\\t\"")),
+                        ("'\\n'".to_owned(), Expr::raw("\"\\\\n\"")),
+                        ("'\\r'".to_owned(), Expr::raw("\"\\\\r\"")),
+                        ("_ if c == '\\\\' || c == quote".to_owned(), Expr::raw("s\"\\\\$c\"")),
+                        ("_".to_owned(), Expr::raw("c.toString")),
+                    ],
+                },
+            )
+            .modifier("private"),
+        ),
+        Stat::Def(
+            Def::new("sequence")
+                .modifier("private")
+                .tparams(vec!["A".to_owned()])
+                .params(vec![
+                    Param::new("items", "Seq[A]"),
+                    Param::new("open", "Char"),
+                    Param::new("close", "Char"),
+                    Param::new("out", "StringBuilder"),
+                ])
+                .params(vec![Param::new("item", "A => Unit")])
+                .returns("Unit")
+                .body(block(&[
+                    "out.append(open)",
+                    "items.zipWithIndex.foreach { case (value, i) => if (i > 0) out.append(\", \"); item(value) }",
+                    "out.append(close)",
+                ])),
+        ),
+        Stat::Def(
+            Def::new("writePayload")
+                .modifier("private")
+                .tparams(vec!["A".to_owned()])
+                .params(vec![
+                    Param::new("codec", "Wave[A]"),
+                    Param::new("value", "A"),
+                    Param::new("out", "StringBuilder"),
+                ])
+                .returns("Unit")
+                .body(block(&["out.append('(')", "codec.write(value, out)", "out.append(')')"])),
+        ),
+        Stat::Def(
+            Def::new("primitive")
+                .modifier("private")
+                .tparams(vec!["A".to_owned()])
+                .params(vec![
+                    Param::new("toToken", "A => String"),
+                    Param::new("fromToken", "String => A"),
+                ])
+                .returns("Wave[A]")
+                .body(Expr::New {
+                    parents: vec!["Wave[A]".to_owned()],
+                    body: codec(
+                        "A",
+                        Expr::raw("out.append(toToken(value))"),
+                        Expr::Block(vec![
+                            Stat::Val(Val::new("token").rhs(Expr::raw("in.token()"))),
+                            Stat::Expr(Expr::raw(
+                                "try fromToken(token) catch { case _: IllegalArgumentException => in.fail(s\"invalid value '$token'\") }",
+                            )),
+                        ]),
+                    ),
+                }),
+        ),
+        Stat::Def(
+            method(
+                "unsigned",
+                vec![Param::new("token", "String"), Param::new("bits", "Int")],
+                "Int",
+                block(&[
+                    "if (!token.forall(_.isDigit) || token.toInt >= 1 << bits) throw new NumberFormatException(token)",
+                    "token.toInt",
+                ]),
+            )
+            .modifier("private"),
+        ),
+        Stat::Def(
+            Def::new("number")
+                .modifier("private")
+                .params(vec![Param::new("value", "Double"), Param::new("text", "=> String")])
+                .returns("String")
+                .body(Expr::raw(
+                    "if (value.isNaN) \"nan\" else if (value.isInfinite) (if (value > 0) \"inf\" else \"-inf\") else text",
+                )),
+        ),
+        Stat::Def(
+            method(
+                "float",
+                vec![Param::new("token", "String")],
+                "Double",
+                Expr::Match {
+                    scrutinee: "token".to_owned(),
+                    cases: vec![
+                        ("\"nan\"".to_owned(), Expr::raw("Double.NaN")),
+                        ("\"inf\"".to_owned(), Expr::raw("Double.PositiveInfinity")),
+                        ("\"-inf\"".to_owned(), Expr::raw("Double.NegativeInfinity")),
+                        ("_".to_owned(), Expr::raw("token.toDouble")),
+                    ],
+                },
+            )
+            .modifier("private"),
+        ),
+    ];
+
+    stats.extend([
+        primitive(
+            "bool",
+            "bool",
+            "Boolean",
+            "_.toString",
+            "token => if (token == \"true\") true else if (token == \"false\") false else throw new IllegalArgumentException(token)",
+        ),
+        primitive("s8", "s8", "Byte", "_.toString", "_.toByte"),
+        primitive("u8", "u8", "Byte", "value => (value & 0xff).toString", "unsigned(_, 8).toByte"),
+        primitive("s16", "s16", "Short", "_.toString", "_.toShort"),
+        primitive("u16", "u16", "Short", "value => (value & 0xffff).toString", "unsigned(_, 16).toShort"),
+        primitive("s32", "s32", "Integer", "_.toString", "token => token.toInt"),
+        primitive(
+            "u32",
+            "u32",
+            "Integer",
+            "value => java.lang.Integer.toUnsignedString(value)",
+            "token => java.lang.Integer.parseUnsignedInt(token)",
+        ),
+        primitive("s64", "s64", "Long", "_.toString", "_.toLong"),
+        primitive(
+            "u64",
+            "u64",
+            "Long",
+            "java.lang.Long.toUnsignedString(_)",
+            "java.lang.Long.parseUnsignedLong(_)",
+        ),
+        primitive("f32", "f32", "Float", "value => number(value.toDouble, value.toString)", "float(_).toFloat"),
+        primitive("f64", "f64", "Double", "value => number(value, value.toString)", "float(_)"),
+        Stat::Val(
+            Val::new("char")
+                .doc(doc("The codec of `char`"))
+                .ty("Wave[Char]")
+                .rhs(Expr::New {
+                    parents: vec!["Wave[Char]".to_owned()],
+                    body: codec(
+                        "Char",
+                        Expr::raw("writeQuoted(value.toString, '\\'', out)"),
+                        Expr::Block(vec![
+                            Stat::Val(Val::new("text").rhs(Expr::raw("in.quoted('\\'')"))),
+                            Stat::Expr(Expr::raw("if (text.length != 1) in.fail(\"expected a single char\")")),
+                            Stat::Expr(Expr::raw("text.charAt(0)")),
+                        ]),
+                    ),
+                }),
+        ),
+        Stat::Val(
+            Val::new("string")
+                .doc(doc("The codec of `string`"))
+                .ty("Wave[String]")
+                .rhs(Expr::New {
+                    parents: vec!["Wave[String]".to_owned()],
+                    body: codec(
+                        "String",
+                        Expr::raw("writeQuoted(value, '\"', out)"),
+                        Expr::raw("in.quoted('\"')"),
+                    ),
+                }),
+        ),
+        combinator(
+            "The codec of `list<T>`, given the codec of `T`",
+            "list",
+            &["A"],
+            vec![Param::new("item", "Wave[A]")],
+            "js.Array[A]",
+            codec(
+                "js.Array[A]",
+                Expr::raw("sequence(value.toList, '[', ']', out)(item.write(_, out))"),
+                Expr::raw("in.sequence('[', ']')(item.read(in)).toJSArray"),
+            ),
+        ),
+        Stat::Class(
+            Template::new("OptionWave")
+                .doc(doc("The codec of `option<T>`, record fields of which may be left out"))
+                .modifier("private final")
+                .tparams(vec!["A".to_owned()])
+                .params(vec![Param::new("some", "Wave[A]")])
+                .extends("Wave[js.UndefOr[A]]")
+                .body(codec(
+                    "js.UndefOr[A]",
+                    Expr::raw(
+                        "value.fold[Unit](out.append(\"none\"))(present => { out.append(\"some\"); writePayload(some, present, out) })",
+                    ),
+                    Expr::Match {
+                        scrutinee: "in.label()".to_owned(),
+                        cases: vec![
+                            ("\"none\"".to_owned(), Expr::raw("js.undefined")),
+                            ("\"some\"".to_owned(), Expr::raw("in.payload(some)")),
+                            (
+                                "other".to_owned(),
+                                Expr::raw("in.fail(s\"expected some or none, got $other\")"),
+                            ),
+                        ],
+                    },
+                )),
+        ),
+        Stat::Def(
+            Def::new("option")
+                .doc(doc("The codec of `option<T>`, given the codec of `T`"))
+                .tparams(vec!["A".to_owned()])
+                .params(vec![Param::new("some", "Wave[A]")])
+                .returns("Wave[js.UndefOr[A]]")
+                .body(Expr::raw("new OptionWave(some)")),
+        ),
+        combinator(
+            "The codec of `result<T, E>`, given the optional codecs of `T` and `E`, errors being thrown as with their JS representation",
+            "result",
+            &["A", "E"],
+            vec![
+                Param::new("ok", "Option[Wave[A]]"),
+                Param::new("err", "Option[Wave[E]]"),
+            ],
+            "A",
+            codec(
+                "A",
+                block(&["out.append(\"ok\")", "ok.foreach(writePayload(_, value, out))"]),
+                Expr::Match {
+                    scrutinee: "in.label()".to_owned(),
+                    cases: vec![
+                        ("\"ok\"".to_owned(), Expr::raw("ok.fold(().asInstanceOf[A])(in.payload(_))")),
+                        (
+                            "\"err\"".to_owned(),
+                            Expr::raw("throw js.JavaScriptException(err.fold[Any](js.undefined)(in.payload(_)))"),
+                        ),
+                        ("other".to_owned(), Expr::raw("in.fail(s\"expected ok or err, got $other\")")),
+                    ],
+                },
+            ),
+        ),
+        combinator(
+            "The codec of a tuple, given the codecs of its items",
+            "tuple",
+            &["A"],
+            vec![Param::new("items", "Wave[_]*")],
+            "A",
+            codec(
+                "A",
+                Expr::raw(
+                    "sequence(items.zip(value.asInstanceOf[js.Array[Any]].toList), '(', ')', out) { case (item, element) => item.asInstanceOf[Wave[Any]].write(element, out) }",
+                ),
+                Expr::Block(vec![
+                    Stat::Expr(Expr::raw("in.expect('(')")),
+                    Stat::Val(Val::new("values").rhs(Expr::raw(
+                        "items.zipWithIndex.map { case (item, i) => if (i > 0) in.expect(','); item.asInstanceOf[Wave[Any]].read(in) }",
+                    ))),
+                    Stat::Expr(Expr::raw("in.accept(',')")),
+                    Stat::Expr(Expr::raw("in.expect(')')")),
+                    Stat::Expr(Expr::raw("js.Array(values: _*).asInstanceOf[A]")),
+                ]),
+            ),
+        ),
+        combinator(
+            "The codec of a record, given its fields",
+            "record",
+            &["A"],
+            vec![Param::new("fields", "Field*")],
+            "A",
+            codec(
+                "A",
+                Expr::Block(vec![
+                    Stat::Val(Val::new("members").rhs(Expr::raw("value.asInstanceOf[js.Dynamic]"))),
+                    Stat::Expr(Expr::raw(
+                        "if (fields.isEmpty) out.append(\"{:}\") else sequence(fields, '{', '}', out) { field => writeLabel(field.label, out); out.append(\": \"); field.codec.asInstanceOf[Wave[Any]].write(members.selectDynamic(field.name), out) }",
+                    )),
+                ]),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("members").rhs(Expr::raw("js.Dictionary.empty[Any]"))),
+                    Stat::Expr(Expr::raw("in.expect('{')")),
+                    Stat::Expr(Expr::raw(
+                        "if (in.accept(':')) in.expect('}') else in.items('}') { val label = in.label(); in.expect(':'); val field = fields.find(_.label == label).getOrElse(in.fail(s\"unknown field $label\")); members(field.name) = field.codec.read(in) }",
+                    )),
+                    Stat::Expr(Expr::raw(
+                        "fields.find(field => !members.contains(field.name) && !field.codec.isInstanceOf[OptionWave[_]]).foreach(field => in.fail(s\"missing field ${field.label}\"))",
+                    )),
+                    Stat::Expr(Expr::raw("members.asInstanceOf[A]")),
+                ]),
+            ),
+        ),
+        combinator(
+            "The codec of a variant, given its cases",
+            "variant",
+            &["A"],
+            vec![Param::new("cases", "Case*")],
+            "A",
+            codec(
+                "A",
+                Expr::Block(vec![
+                    Stat::Val(Val::new("variant").rhs(Expr::raw("value.asInstanceOf[js.Dynamic]"))),
+                    Stat::Val(Val::new("tag").rhs(Expr::raw("variant.tag.asInstanceOf[String]"))),
+                    Stat::Expr(Expr::raw("writeLabel(tag, out)")),
+                    Stat::Expr(Expr::raw(
+                        "cases.find(_.label == tag).flatMap(_.payload).foreach(payload => writePayload(payload.asInstanceOf[Wave[Any]], variant.`val`, out))",
+                    )),
+                ]),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("tag").rhs(Expr::raw("in.label()"))),
+                    Stat::Val(Val::new("payload").rhs(Expr::raw(
+                        "cases.find(_.label == tag).getOrElse(in.fail(s\"unknown case $tag\")).payload",
+                    ))),
+                    Stat::Expr(Expr::raw(
+                        "js.Dictionary[Any](\"tag\" -> tag, \"val\" -> payload.fold[Any](js.undefined)(in.payload(_))).asInstanceOf[A]",
+                    )),
+                ]),
+            ),
+        ),
+        combinator(
+            "The codec of an enum, given the WIT names of its cases",
+            "enumeration",
+            &["A"],
+            vec![Param::new("cases", "String*")],
+            "A",
+            codec(
+                "A",
+                Expr::raw("writeLabel(value.asInstanceOf[String], out)"),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("label").rhs(Expr::raw("in.label()"))),
+                    Stat::Expr(Expr::raw("if (!cases.contains(label)) in.fail(s\"unknown case $label\")")),
+                    Stat::Expr(Expr::raw("label.asInstanceOf[A]")),
+                ]),
+            ),
+        ),
+        combinator(
+            "The codec of flags, given its flags",
+            "flags",
+            &["A"],
+            vec![Param::new("flags", "Flag*")],
+            "A",
+            codec(
+                "A",
+                Expr::Block(vec![
+                    Stat::Val(Val::new("members").rhs(Expr::raw("value.asInstanceOf[js.Dictionary[Boolean]]"))),
+                    Stat::Expr(Expr::raw(
+                        "sequence(flags.filter(flag => members.getOrElse(flag.name, false)), '{', '}', out)(flag => writeLabel(flag.label, out))",
+                    )),
+                ]),
+                Expr::Block(vec![
+                    Stat::Val(Val::new("set").rhs(Expr::raw("in.sequence('{', '}')(in.label())"))),
+                    Stat::Expr(Expr::raw(
+                        "set.find(label => !flags.exists(_.label == label)).foreach(label => in.fail(s\"unknown flag $label\"))",
+                    )),
+                    Stat::Expr(Expr::raw(
+                        "js.Dictionary(flags.map(flag => flag.name -> set.contains(flag.label)): _*).asInstanceOf[A]",
+                    )),
+                ]),
+            ),
+        ),
+    ]);
+
+    vec![
+        Stat::Trait(
+            Template::new("Wave")
+                .doc(doc(
+                    "A printer and parser of the values of a WIT type in the WebAssembly Value Encoding (WAVE)",
+                ))
+                .tparams(vec!["A".to_owned()])
+                .body(vec![
+                    Stat::Def(
+                        Def::new("write")
+                            .doc(doc("Writes the WAVE of a value"))
+                            .params(vec![Param::new("value", "A"), Param::new("out", "StringBuilder")])
+                            .returns("Unit"),
+                    ),
+                    Stat::Def(
+                        Def::new("read")
+                            .doc(doc("Reads a value from its WAVE"))
+                            .params(vec![Param::new("in", "Wave.Reader")])
+                            .returns("A"),
+                    ),
+                    Stat::Def(
+                        method(
+                            "print",
+                            vec![Param::new("value", "A")],
+                            "String",
+                            Expr::Block(vec![
+                                Stat::Val(Val::new("out").rhs(Expr::raw("new StringBuilder"))),
+                                Stat::Expr(Expr::raw("write(value, out)")),
+                                Stat::Expr(Expr::raw("out.toString")),
+                            ]),
+                        )
+                        .doc(doc("Prints a value in WAVE")),
+                    ),
+                    Stat::Def(
+                        method(
+                            "parse",
+                            vec![Param::new("text", "String")],
+                            "A",
+                            Expr::Block(vec![
+                                Stat::Val(Val::new("in").rhs(Expr::raw("new Wave.Reader(text)"))),
+                                Stat::Val(Val::new("value").rhs(Expr::raw("read(in)"))),
+                                Stat::Expr(Expr::raw("in.end()")),
+                                Stat::Expr(Expr::raw("value")),
+                            ]),
+                        )
+                        .doc(doc("Parses a value from its WAVE, failing with a `Wave.ParseError`")),
+                    ),
+                ]),
+        ),
+        Stat::Object(Template::new("Wave").body(stats)),
+    ]
+}
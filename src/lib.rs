@@ -13,28 +13,273 @@
 // limitations under the License.
 
 /// Module containing all the logic for Scala.js code generation
+mod bundled;
 mod codegen;
+mod naming;
+mod scaffold;
 mod types;
 
 pub mod generator {
-    use crate::codegen::Interface;
+    use crate::bundled::{BundledPackage, GOLEM_HOST, GOLEM_RPC_CLIENT, WASI};
+    use crate::codegen::{Bindings, Printer};
+    pub use crate::codegen::{MapRepresentation, Sources, Target, TypeOrder};
+    use crate::naming::{without_plain_comments, Collisions, Locator};
+    pub use crate::scaffold::Scaffolded;
+    use crate::scaffold::{self, ScaffoldFile};
     use color_eyre::{eyre::eyre, Section};
+    use std::collections::HashMap;
+    use std::fs;
     use std::path::Path;
-    use wit_parser::SourceMap;
+    use wit_parser::{Resolve, SourceMap};
 
-    pub fn generate(wit: &Path, package: &str) -> color_eyre::Result<String> {
-        let mut source = SourceMap::new();
-        source
-            .push_file(wit)
-            .map_err(|e| eyre!("{e:?}"))
+    /// The module specifier format matching how jco names imported interfaces
+    pub const DEFAULT_IMPORT_SPECIFIER: &str = "{namespace}:{package}/{interface}@{version}";
+
+    /// Options driving the code generation
+    pub struct Options {
+        /// The package of the generated Scala file
+        pub package: String,
+
+        /// Whether WIT names that collide after case conversion get a numeric suffix
+        /// instead of being reported as errors
+        pub disambiguate: bool,
+
+        /// The order in which the types of the interface are generated
+        pub type_order: TypeOrder,
+
+        /// The number of spaces per indentation level in the generated code
+        pub indent: usize,
+
+        /// The line width past which signatures, parameter lists and the bodies of definitions
+        /// are broken over several lines, expressions themselves never being broken
+        pub width: usize,
+
+        /// The format of the module specifier of imported interfaces, in which
+        /// `{namespace}`, `{package}`, `{interface}` and `{version}` are replaced
+        pub import_specifier: String,
+
+        /// Whether the bundled Golem host API is resolved, and facades and wrappers generated for it
+        pub golem_host: bool,
+
+        /// Whether the bundled WASI 0.2 packages are resolved, facades being generated
+        /// for the interfaces the world imports from them
+        pub wasi: bool,
+
+        /// What the generated code does with the interfaces of the package
+        pub target: Target,
+
+        /// Whether the generated types get a printer and a parser of the WebAssembly Value Encoding
+        pub wave: bool,
+
+        /// Whether the generated types get circe codecs
+        pub circe: bool,
+
+        /// Whether the generated types get ScalaCheck generators
+        pub scalacheck: bool,
+
+        /// Whether the generated types get validators, which the exports run on the results
+        /// of the implemented interfaces and the facades run on the arguments of imported ones
+        pub validate: bool,
+
+        /// Whether the implemented interface is written against case classes, sealed ADTs
+        /// and Scala collections, which the export converts from and to the JS representations
+        pub idiomatic: bool,
+
+        /// How the lists of string-keyed pairs, `list<tuple<string, V>>`, are represented
+        /// in the idiomatic layer, if they are all maps
+        pub maps: Option<MapRepresentation>,
+
+        /// How the lists of string-keyed pairs within the aliases of the given WIT names
+        /// are represented in the idiomatic layer, overriding `maps`. Every name must be
+        /// the one of such an alias declared by a generated interface
+        pub map_types: HashMap<String, MapRepresentation>,
+
+        /// Whether the implemented interfaces get an effect-polymorphic counterpart,
+        /// which is adapted to them for common effects or a given natural transformation
+        pub tagless: bool,
+
+        /// Whether the implemented interfaces get a stub and a mock for testing
+        pub test_doubles: bool,
+    }
+
+    impl Options {
+        /// Creates the default options for the given package
+        pub fn new(package: &str) -> Self {
+            Self {
+                package: package.to_owned(),
+                disambiguate: false,
+                type_order: TypeOrder::default(),
+                indent: 2,
+                width: 80,
+                import_specifier: DEFAULT_IMPORT_SPECIFIER.to_owned(),
+                golem_host: false,
+                wasi: false,
+                target: Target::default(),
+                wave: false,
+                circe: false,
+                scalacheck: false,
+                validate: false,
+                idiomatic: false,
+                maps: None,
+                map_types: HashMap::new(),
+                tagless: false,
+                test_doubles: false,
+            }
+        }
+    }
+
+    /// Generates the Scala.js bindings of the WIT file at `wit`, along with the test sources
+    /// the options ask for
+    pub fn generate(wit: &Path, options: &Options) -> color_eyre::Result<Sources> {
+        let (bindings, _) = bindings(wit, options)?;
+        bindings.render(&options.package, &printer(options))
+    }
+
+    /// Writes a starter project implementing the WIT file at `wit` to `dir`, which are the
+    /// bindings, the skeleton of the implementation, the test sources the options ask for, a copy
+    /// of the WIT files, an sbt build with the Scala.js settings, and the script turning the
+    /// linked JS into a component. The WIT files are the one at `wit`, which declares every package
+    /// but the bundled ones, and the bundled packages under `wit/deps`.
+    /// Files that already exist are never overwritten.
+    pub fn init(wit: &Path, dir: &Path, options: &Options) -> color_eyre::Result<Vec<Scaffolded>> {
+        let (bindings, bundled) = bindings(wit, options)?;
+        let world = bindings
+            .world()
+            .ok_or(eyre!("No world of the package exports interface api"))
+            .with_suggestion(|| "Export interface api from a world")?
+            .to_owned();
+        let name = bindings.name().to_owned();
+
+        let wit_file = wit
+            .file_name()
+            .ok_or(eyre!("{} is not a WIT file", wit.display()))?;
+        let wit_source =
+            fs::read_to_string(wit).map_err(|e| eyre!("Cannot read {}: {e}", wit.display()))?;
+
+        let printer = printer(options);
+        let main_dir = scaffold::source_dir("main", &options.package);
+        let skeleton = bindings.skeleton(&options.package, &printer)?;
+        let sources = bindings.render(&options.package, &printer)?;
+
+        let mut files = vec![
+            ScaffoldFile::new(Path::new("wit").join(wit_file), wit_source),
+            ScaffoldFile::new(main_dir.join("Api.scala"), sources.main),
+            ScaffoldFile::new(main_dir.join("ApiImpl.scala"), skeleton),
+        ];
+        if let Some(test) = sources.test {
+            let test_dir = scaffold::source_dir("test", &options.package);
+            files.push(ScaffoldFile::new(test_dir.join("ApiTest.scala"), test));
+        }
+        // Bundled packages are resolved from `wit/deps` by componentize-js
+        files.extend(bundled.into_iter().map(|package| {
+            let file = package.path.trim_start_matches("wit/").replace('/', "-");
+            ScaffoldFile::new(Path::new("wit/deps").join(file), package.source)
+        }));
+        files.extend(scaffold::build_files(&name, &world, options.scalacheck));
+
+        scaffold::write(dir, files)
+    }
+
+    /// The printer of the generated code
+    fn printer(options: &Options) -> Printer {
+        Printer::new(options.indent, options.width)
+    }
+
+    /// Resolves the WIT file at `wit` along with the bundled packages the options enable,
+    /// which are returned with the bindings of the package
+    fn bindings(
+        wit: &Path,
+        options: &Options,
+    ) -> color_eyre::Result<(Bindings, Vec<&'static BundledPackage>)> {
+        // Only doc comments end up in the Scaladoc, while wit-parser takes any comment as docs
+        let contents = fs::read_to_string(wit)
+            .map_err(|e| eyre!("Cannot read {}: {e}", wit.display()))
             .with_suggestion(|| "Provide a WIT file that actually exists")?;
+        let mut source = SourceMap::new();
+        source.push(wit, without_plain_comments(&contents));
 
-        let unresolved_package = source
+        let unresolved_package_group = source
             .parse()
-            .map(|g| g.main)
             .map_err(|e| eyre!("{e:?}"))
             .with_suggestion(|| "Make sure the provided WIT file is valid")?;
 
-        Interface::from_wit(&unresolved_package, "api")?.render(package)
+        let mut resolve = Resolve::new();
+        let mut locators = HashMap::new();
+        let mut bundled_interfaces = Vec::new();
+
+        // Packages shared by several bundles are only pushed once
+        let mut bundled: Vec<&'static BundledPackage> = Vec::new();
+        let bundles = [
+            (options.wasi, WASI),
+            (options.golem_host, GOLEM_HOST),
+            (options.target == Target::RpcClient, GOLEM_RPC_CLIENT),
+        ];
+        for package in bundles
+            .into_iter()
+            .filter(|(enabled, _)| *enabled)
+            .flat_map(|(_, packages)| packages.iter().copied())
+        {
+            if !bundled.iter().any(|other| other.path == package.path) {
+                bundled.push(package);
+            }
+        }
+
+        for package in &bundled {
+            let package_id = resolve
+                .push_str(package.path, &without_plain_comments(package.source))
+                .map_err(|e| eyre!("{e:?}"))?;
+
+            bundled_interfaces.extend(
+                package
+                    .generated
+                    .iter()
+                    .filter_map(|name| resolve.packages[package_id].interfaces.get(*name)),
+            );
+            locators.insert(
+                package_id,
+                Locator::from_source(package.path, package.source),
+            );
+        }
+
+        // Only the WIT file and the bundled packages are resolved, which are all `init` copies
+        let declared: Vec<_> = std::iter::once(&unresolved_package_group.main)
+            .chain(&unresolved_package_group.nested)
+            .collect();
+        if let Some(dependency) = declared
+            .iter()
+            .flat_map(|package| package.foreign_deps.keys())
+            .find(|name| {
+                !resolve.package_names.contains_key(*name)
+                    && !declared.iter().any(|package| package.name == **name)
+            })
+        {
+            return Err(eyre!(
+                "Package `{dependency}` that the WIT file depends on is neither declared in it nor bundled"
+            ))
+            .with_suggestion(|| {
+                "Declare the package in the WIT file as a nested package, or bundle it such as with --wasi or --golem-host"
+            });
+        }
+
+        let package_id = resolve
+            .push_group(unresolved_package_group)
+            .map_err(|e| eyre!("{e:?}"))
+            .with_suggestion(|| {
+                "Make sure the packages the WIT file depends on are bundled, such as with --wasi or --golem-host"
+            })?;
+        locators.insert(package_id, Locator::new(wit));
+
+        let collisions = Collisions::new(&locators[&package_id], options.disambiguate);
+
+        let bindings = Bindings::from_wit(
+            &resolve,
+            package_id,
+            &bundled_interfaces,
+            &locators,
+            &collisions,
+            options,
+        )?;
+
+        Ok((bindings, bundled))
     }
 }
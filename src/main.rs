@@ -12,21 +12,248 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use clap::Parser;
-use color_eyre::Result;
-use golem_scalajs_wit_bindgen::generator;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use color_eyre::{eyre::eyre, Result, Section};
+use golem_scalajs_wit_bindgen::generator::{
+    self, MapRepresentation, Options, Scaffolded, Target, TypeOrder,
+};
+use std::fs;
 use std::path::Path;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct CliArgs {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    generation: GenerationArgs,
+
+    /// The path the test sources are written to, which are the ScalaCheck generators of
+    /// `--scalacheck` and the test doubles of `--test-doubles`, the bindings being printed
+    /// to the standard output
+    #[arg(long, value_name = "PATH")]
+    test_out: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Write a starter project implementing the WIT file, with the bindings, the skeleton of the
+    /// implementation, an sbt build and the componentize-js script, never overwriting files
+    Init {
+        /// The directory of the project
+        #[arg(long, default_value = ".")]
+        dir: String,
+
+        #[command(flatten)]
+        generation: GenerationArgs,
+    },
+}
+
+#[derive(Args)]
+struct GenerationArgs {
     /// The path to the input WIT file
-    #[arg(short, long)]
-    wit: String,
+    #[arg(short, long, required = true)]
+    wit: Option<String>,
 
     /// The package of the generated Api.scala file
-    #[arg(short, long)]
-    package: String,
+    #[arg(short, long, required = true)]
+    package: Option<String>,
+
+    /// Add a numeric suffix to WIT names that collide after case conversion, instead of failing
+    #[arg(long)]
+    disambiguate: bool,
+
+    /// The order in which the types of the interface are generated
+    #[arg(long, value_enum, default_value_t = TypeOrderArg::Source)]
+    type_order: TypeOrderArg,
+
+    /// The number of spaces per indentation level in the generated code
+    #[arg(long, default_value_t = 2)]
+    indent: usize,
+
+    /// The line width past which signatures, parameter lists and the bodies of definitions are
+    /// broken over several lines. Expressions are never broken, so the lines holding long ones,
+    /// such as conversions and codecs, may exceed it
+    #[arg(long, default_value_t = 80)]
+    width: usize,
+
+    /// The module specifier of imported interfaces, in which `{namespace}`, `{package}`,
+    /// `{interface}` and `{version}` are replaced (`@{version}` is dropped for unversioned packages)
+    #[arg(long, default_value = generator::DEFAULT_IMPORT_SPECIFIER)]
+    import_specifier: String,
+
+    /// Resolve WIT against the bundled Golem host API (`golem:api@0.2.0`, Golem 1.0),
+    /// and generate facades and idiomatic wrappers for it
+    #[arg(long)]
+    golem_host: bool,
+
+    /// Resolve WIT against the bundled WASI 0.2 packages (`wasi:io`, `wasi:clocks`,
+    /// `wasi:random` and `wasi:http`), and generate facades for the interfaces the world imports
+    #[arg(long)]
+    wasi: bool,
+
+    /// What the generated code does with the interfaces of the WIT package
+    #[arg(long, value_enum, default_value_t = TargetArg::Component)]
+    target: TargetArg,
+
+    /// Generate a printer and a parser of the WebAssembly Value Encoding (WAVE)
+    /// for every record, variant, enum, flags and type alias
+    #[arg(long)]
+    wave: bool,
+
+    /// Generate circe JSON codecs for every record, variant, enum, flags and type alias,
+    /// variants being encoded as `{"tag": <case>, "val": <payload>}`
+    #[arg(long)]
+    circe: bool,
+
+    /// Generate ScalaCheck `Arbitrary` instances for every record, variant, enum, flags and type
+    /// alias, with values in the range of their WIT type, and along with `--validate`, the
+    /// `ValidArbitraries` properties checking that the generated values pass the validators,
+    /// into the test sources
+    #[arg(long)]
+    scalacheck: bool,
+
+    /// Generate validators for every record, variant, enum, flags and type alias, which the export
+    /// runs on results and the facades of imported interfaces run on arguments, failing with the
+    /// WIT paths of the values that do not fit their WIT type
+    #[arg(long)]
+    validate: bool,
+
+    /// Generate an `Idiomatic` object with case classes, sealed ADTs, `List`, `Option` and `Either`
+    /// for the types of the implemented interface, the export converting from and to JS
+    #[arg(long)]
+    idiomatic: bool,
+
+    /// Represent every `list<tuple<string, V>>` of the idiomatic layer as a map,
+    /// which the export converts from and to the list of pairs jco expects,
+    /// later pairs winning over earlier ones with the same key
+    #[arg(long, value_enum, requires = "idiomatic")]
+    maps: Option<MapRepresentationArg>,
+
+    /// Represent the `list<tuple<string, V>>` aliased by the WIT type as a map in the idiomatic
+    /// layer, `map` unless given as `<TYPE>=dictionary`, overriding `--maps`. Fails unless TYPE
+    /// is the WIT name of such an alias. Later pairs win over earlier ones with the same key
+    #[arg(long = "map-type", value_name = "TYPE[=REPRESENTATION]", value_parser = parse_map_type, requires = "idiomatic")]
+    map_types: Vec<(String, MapRepresentationArg)>,
+
+    /// Generate a `Tagless` object with an effect-polymorphic `Api[F[_]]` for the implemented
+    /// interface, and an adapter running it as the implemented trait with a natural transformation
+    #[arg(long)]
+    tagless: bool,
+
+    /// Generate `StubApi`, every method of which fails unless overridden, and `MockApi`,
+    /// which records its calls and returns scripted responses, for the implemented interface,
+    /// into the test sources
+    #[arg(long)]
+    test_doubles: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TypeOrderArg {
+    /// The order in which types are declared in WIT
+    Source,
+
+    /// Types come after the types they refer to
+    Dependency,
+}
+
+impl From<TypeOrderArg> for TypeOrder {
+    fn from(arg: TypeOrderArg) -> Self {
+        match arg {
+            TypeOrderArg::Source => TypeOrder::Source,
+            TypeOrderArg::Dependency => TypeOrder::Dependency,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TargetArg {
+    /// The bindings of the component implementing the `api` interface
+    Component,
+
+    /// Clients calling the interfaces exported by the worlds of the WIT package on other workers,
+    /// through the stubs generated by wasm-rpc, with a worker URI and blocking and
+    /// non-blocking variants of every function
+    RpcClient,
+
+    /// A client invoking the `api` interface on a worker through the Golem REST API,
+    /// with params and results in the typed JSON value format of Golem
+    RestClient,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MapRepresentationArg {
+    /// An immutable `Map[String, V]`
+    Map,
+
+    /// A mutable `js.Dictionary[V]`
+    Dictionary,
+}
+
+impl From<MapRepresentationArg> for MapRepresentation {
+    fn from(arg: MapRepresentationArg) -> Self {
+        match arg {
+            MapRepresentationArg::Map => MapRepresentation::Map,
+            MapRepresentationArg::Dictionary => MapRepresentation::Dictionary,
+        }
+    }
+}
+
+/// Parses the WIT name of a type represented as a map, optionally followed by `=` and
+/// the representation
+fn parse_map_type(arg: &str) -> std::result::Result<(String, MapRepresentationArg), String> {
+    match arg.split_once('=') {
+        Some((name, representation)) => Ok((
+            name.to_owned(),
+            MapRepresentationArg::from_str(representation, true)?,
+        )),
+        None => Ok((arg.to_owned(), MapRepresentationArg::Map)),
+    }
+}
+
+impl From<TargetArg> for Target {
+    fn from(arg: TargetArg) -> Self {
+        match arg {
+            TargetArg::Component => Target::Component,
+            TargetArg::RpcClient => Target::RpcClient,
+            TargetArg::RestClient => Target::RestClient,
+        }
+    }
+}
+
+impl GenerationArgs {
+    /// The path to the WIT file and the options, `--wit` and `--package` being required by clap
+    fn into_options(self) -> (String, Options) {
+        let wit = self.wit.unwrap_or_default();
+        let options = Options {
+            disambiguate: self.disambiguate,
+            type_order: self.type_order.into(),
+            indent: self.indent,
+            width: self.width,
+            import_specifier: self.import_specifier,
+            golem_host: self.golem_host,
+            wasi: self.wasi,
+            target: self.target.into(),
+            wave: self.wave,
+            circe: self.circe,
+            scalacheck: self.scalacheck,
+            validate: self.validate,
+            idiomatic: self.idiomatic,
+            maps: self.maps.map(Into::into),
+            map_types: self
+                .map_types
+                .into_iter()
+                .map(|(name, representation)| (name, representation.into()))
+                .collect(),
+            tagless: self.tagless,
+            test_doubles: self.test_doubles,
+            ..Options::new(&self.package.unwrap_or_default())
+        };
+
+        (wit, options)
+    }
 }
 
 fn main() -> Result<()> {
@@ -34,6 +261,37 @@ fn main() -> Result<()> {
 
     let cli_args = CliArgs::parse();
 
-    generator::generate(Path::new(&cli_args.wit), &cli_args.package)
-        .map(|code| println!("{}", code))
+    match cli_args.command {
+        Some(Command::Init { dir, generation }) => {
+            let (wit, options) = generation.into_options();
+
+            for scaffolded in generator::init(Path::new(&wit), Path::new(&dir), &options)? {
+                match scaffolded {
+                    Scaffolded::Written(path) => eprintln!("Wrote {}", path.display()),
+                    Scaffolded::Skipped(path) => {
+                        eprintln!("Skipped {}, which already exists", path.display())
+                    }
+                }
+            }
+
+            Ok(())
+        }
+        None => {
+            let (wit, options) = cli_args.generation.into_options();
+            let sources = generator::generate(Path::new(&wit), &options)?;
+
+            if let Some(test) = sources.test {
+                let path = cli_args
+                    .test_out
+                    .ok_or(eyre!(
+                        "The options generate test sources, which need a file of their own"
+                    ))
+                    .with_suggestion(|| "Pass the path of the test sources with --test-out")?;
+                fs::write(&path, test).map_err(|e| eyre!("Cannot write {path}: {e}"))?;
+            }
+            print!("{}", sources.main);
+
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,575 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::{eyre::eyre, Result, Section};
+use convert_case::{Case, Casing};
+
+/// Reserved words of Scala 2 and Scala 3 that cannot be used as plain identifiers
+const KEYWORDS: &[&str] = &[
+    "_",
+    "abstract",
+    "case",
+    "catch",
+    "class",
+    "def",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "for",
+    "forSome",
+    "given",
+    "if",
+    "implicit",
+    "import",
+    "lazy",
+    "macro",
+    "match",
+    "new",
+    "null",
+    "object",
+    "override",
+    "package",
+    "private",
+    "protected",
+    "return",
+    "sealed",
+    "super",
+    "then",
+    "this",
+    "throw",
+    "trait",
+    "true",
+    "try",
+    "type",
+    "val",
+    "var",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Type names that would clash with the helpers generated in the package object or next to the types
+pub const TYPE_HELPERS: &[&str] = &[
+    "WitResult",
+    "WitOption",
+    "WitList",
+    "Wave",
+    "CirceCodecs",
+    "Arbitraries",
+    "Validation",
+    "ValidArbitraries",
+    "Idiomatic",
+    "Structural",
+    "Tagless",
+    "ApiExport",
+    "Golem",
+    "ApiRestClient",
+    "StubApi",
+    "MockApi",
+    "ApiImpl",
+    "Main",
+];
+
+/// Type names that would clash with the facades of the bundled interfaces, which only types avoid
+pub const FACADE_NAMES: &[&str] = &["Host"];
+
+/// Method names that cannot be declared because they are final or protected in `java.lang.Object`
+pub const OBJECT_MEMBERS: &[&str] = &[
+    "clone",
+    "finalize",
+    "getClass",
+    "notify",
+    "notifyAll",
+    "wait",
+    "eq",
+    "ne",
+    "synchronized",
+    "isInstanceOf",
+    "asInstanceOf",
+];
+
+/// Member names that would clash with the implicit instances generated in companion objects
+pub const COMPANION_HELPERS: &[&str] = &["wave", "circe", "arbitrary", "validator"];
+
+/// Member names that would clash with the members generated for variants
+pub const VARIANT_HELPERS: &[&str] = &["tag", "val", "wave", "circe", "arbitrary", "validator"];
+
+/// Represents a Scala identifier derived from a WIT name
+#[derive(Clone)]
+pub struct ScalaName {
+    /// The name as written in WIT
+    wit: String,
+
+    /// The name after case conversion, which is also the name seen from JS
+    converted: String,
+
+    /// The identifier to use in Scala source code
+    ident: String,
+}
+
+impl ScalaName {
+    /// Converts a WIT name to the given case, escaping or renaming it when it is not usable as is
+    pub fn new(wit: &str, case: Case, helpers: &[&str]) -> Self {
+        let converted = wit.to_case(case);
+
+        let ident = if helpers.contains(&converted.as_str()) {
+            format!("{converted}_")
+        } else {
+            converted.clone()
+        };
+
+        Self {
+            wit: wit.to_owned(),
+            ident: escape(&ident),
+            converted,
+        }
+    }
+
+    /// Returns this name with a numeric suffix, used to tell apart colliding names in Scala,
+    /// the name seen from JS being left as is
+    pub fn with_suffix(&self, n: usize) -> Self {
+        Self {
+            wit: self.wit.clone(),
+            ident: escape(&format!("{}{n}", self.ident.trim_matches('`'))),
+            converted: self.converted.clone(),
+        }
+    }
+
+    /// The name as written in WIT
+    pub fn wit(&self) -> &str {
+        &self.wit
+    }
+
+    /// The Scala identifier without backticks, which the names generated next to it must not shadow
+    pub fn unescaped(&self) -> &str {
+        self.ident.trim_matches('`')
+    }
+
+    /// The name after case conversion, which is the JS name of the member
+    pub fn converted(&self) -> &str {
+        &self.converted
+    }
+
+    /// The JS name of the member, when it differs from what Scala.js would infer from the identifier,
+    /// which is the identifier without its backticks
+    pub fn js_name(&self) -> Option<&str> {
+        (self.ident.trim_matches('`') != self.converted).then_some(self.converted.as_str())
+    }
+}
+
+impl Display for ScalaName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.ident)
+    }
+}
+
+/// The Scala name of the trait or the facade object of a WIT interface
+pub fn interface_name(wit: &str) -> ScalaName {
+    ScalaName::new(wit, Case::UpperCamel, TYPE_HELPERS)
+}
+
+/// Wraps an identifier in backticks when it is a keyword or does not start like an identifier
+pub fn escape(ident: &str) -> String {
+    let starts_like_identifier = ident
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$');
+
+    if KEYWORDS.contains(&ident) || !starts_like_identifier {
+        format!("`{ident}`")
+    } else {
+        ident.to_owned()
+    }
+}
+
+/// Allocates identifiers for generated temporaries within a single Scala block,
+/// making sure they never clash with the names already declared in that block
+#[derive(Default)]
+pub struct Scope(HashSet<String>);
+
+impl Scope {
+    /// Creates a scope in which the given names are already taken
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self(names.into_iter().map(Into::into).collect())
+    }
+
+    /// Marks the given names as taken
+    pub fn extend<I, S>(&mut self, names: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.0.extend(names.into_iter().map(Into::into));
+    }
+
+    /// Returns an identifier based on `hint` that is not yet taken in this scope, and takes it
+    pub fn fresh(&mut self, hint: &str) -> String {
+        let name = if self.0.contains(hint) {
+            (1..)
+                .map(|n| format!("{hint}{n}"))
+                .find(|name| !self.0.contains(name))
+                .expect("an unbounded range always yields a free name")
+        } else {
+            hint.to_owned()
+        };
+
+        self.0.insert(name.clone());
+
+        escape(&name)
+    }
+}
+
+/// Finds where WIT names are declared, so that errors can point at them
+pub struct Locator {
+    /// The WIT file the names are looked up in
+    path: PathBuf,
+
+    /// The contents of the WIT file, if it could be read
+    source: Option<String>,
+}
+
+impl Locator {
+    /// Creates a `Locator` for the given WIT file
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_owned(),
+            source: fs::read_to_string(path).ok(),
+        }
+    }
+
+    /// Locates the last name of `path`, looking up each name after the previous one,
+    /// so that `["api", "user", "name"]` finds the `name` field of the `user` record.
+    /// A type of an interface is found where it is declared, rather than where it is first named.
+    pub fn locate(&self, path: &[&str]) -> Option<String> {
+        let source = self.source.as_ref()?;
+
+        let declared = match path {
+            [interface, name] => self.declaration(interface, name),
+            _ => None,
+        };
+        let offset = declared.or_else(|| {
+            path.iter().try_fold(0, |from, name| {
+                find_identifier(&source[from..], name).map(|offset| from + offset)
+            })
+        })?;
+
+        let line = source[..offset].matches('\n').count() + 1;
+        let column = offset - source[..offset].rfind('\n').map_or(0, |i| i + 1) + 1;
+
+        Some(format!("{}:{line}:{column}", self.path.display()))
+    }
+
+    /// Creates a `Locator` for WIT source that is not read from a file, such as a bundled package
+    pub fn from_source(path: &str, source: &str) -> Self {
+        Self {
+            path: PathBuf::from(path),
+            source: Some(source.to_owned()),
+        }
+    }
+
+    /// Finds the offset of the declaration of the type `name` in the body of the given interface,
+    /// which is used to preserve the WIT declaration order. The types brought in by `use`
+    /// are declared where they are used, and comments are never looked into.
+    pub fn declaration(&self, interface: &str, name: &str) -> Option<usize> {
+        const DECLARATIONS: &[&str] = &["record", "variant", "enum", "flags", "type", "resource"];
+
+        let tokens = tokens(self.source.as_ref()?);
+        let ident = |token: &str| token.strip_prefix('%').unwrap_or(token).to_owned();
+
+        // The body of the interface starts after `interface <name> {`
+        let start = tokens.windows(3).position(|window| {
+            window[0].1 == "interface" && ident(window[1].1) == interface && window[2].1 == "{"
+        })? + 3;
+
+        let mut depth = 0;
+        let mut i = start;
+        while i < tokens.len() {
+            match tokens[i].1 {
+                "{" => depth += 1,
+                "}" if depth == 0 => return None,
+                "}" => depth -= 1,
+                "use" if depth == 0 => {
+                    // `use path.{a, b as c};` declares `a` and `c`
+                    let end = tokens[i..]
+                        .iter()
+                        .position(|(_, token)| *token == ";")
+                        .map_or(tokens.len(), |end| i + end);
+                    let found = (i..end).find(|&j| {
+                        ident(tokens[j].1) == name
+                            && matches!(tokens[j - 1].1, "{" | "," | "as")
+                            && tokens.get(j + 1).is_some_and(|(_, next)| *next != "as")
+                    });
+                    if let Some(j) = found {
+                        return Some(tokens[j].0);
+                    }
+                    i = end;
+                }
+                keyword if depth == 0 && DECLARATIONS.contains(&keyword) => {
+                    if let Some((offset, declared)) = tokens.get(i + 1) {
+                        if ident(declared) == name {
+                            return Some(*offset);
+                        }
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+
+        None
+    }
+}
+
+/// The length of the comment `rest` starts with, if any
+fn comment(rest: &str) -> Option<usize> {
+    if rest.starts_with("//") {
+        Some(rest.find('\n').unwrap_or(rest.len()))
+    } else if rest.starts_with("/*") {
+        // Block comments nest in WIT
+        let mut depth = 0;
+        let mut j = 0;
+        while j < rest.len() {
+            if rest[j..].starts_with("/*") {
+                depth += 1;
+                j += 2;
+            } else if rest[j..].starts_with("*/") {
+                depth -= 1;
+                j += 2;
+                if depth == 0 {
+                    return Some(j);
+                }
+            } else {
+                j += rest[j..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        Some(rest.len())
+    } else {
+        None
+    }
+}
+
+/// Blanks out the comments of WIT source that are not doc comments, which wit-parser would
+/// otherwise take as docs, keeping the offsets and the lines of everything else
+pub fn without_plain_comments(source: &str) -> String {
+    let is_doc = |comment: &str| {
+        (comment.starts_with("///") && !comment.starts_with("////"))
+            || (comment.starts_with("/**") && !comment.starts_with("/***") && comment != "/**/")
+    };
+
+    let mut stripped = String::with_capacity(source.len());
+    let mut offset = 0;
+    while let Some(c) = source[offset..].chars().next() {
+        let rest = &source[offset..];
+        match comment(rest) {
+            Some(len) if !is_doc(&rest[..len]) => {
+                for c in rest[..len].chars() {
+                    match c {
+                        '\n' => stripped.push(c),
+                        _ => stripped.push_str(&" ".repeat(c.len_utf8())),
+                    }
+                }
+                offset += len;
+            }
+            Some(len) => {
+                stripped.push_str(&rest[..len]);
+                offset += len;
+            }
+            None => {
+                stripped.push(c);
+                offset += c.len_utf8();
+            }
+        }
+    }
+
+    stripped
+}
+
+/// Splits WIT source into identifiers and punctuation along with their offsets,
+/// leaving out whitespace and comments
+fn tokens(source: &str) -> Vec<(usize, &str)> {
+    fn is_identifier_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '%'
+    }
+
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+    while let Some(c) = source[offset..].chars().next() {
+        let rest = &source[offset..];
+        let len = if c.is_whitespace() {
+            c.len_utf8()
+        } else if let Some(len) = comment(rest) {
+            len
+        } else if is_identifier_char(c) {
+            let len = rest
+                .find(|c: char| !is_identifier_char(c))
+                .unwrap_or(rest.len());
+            tokens.push((offset, &rest[..len]));
+            len
+        } else {
+            let len = c.len_utf8();
+            tokens.push((offset, &rest[..len]));
+            len
+        };
+        offset += len;
+    }
+
+    tokens
+}
+
+/// Finds the offset of the first occurrence of `name` as a whole WIT identifier
+fn find_identifier(source: &str, name: &str) -> Option<usize> {
+    fn is_identifier_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '-'
+    }
+
+    source.match_indices(name).find_map(|(offset, _)| {
+        let before = source[..offset].trim_end_matches('%');
+        let after = &source[offset + name.len()..];
+
+        let standalone =
+            !before.ends_with(is_identifier_char) && !after.starts_with(is_identifier_char);
+
+        standalone.then_some(offset)
+    })
+}
+
+/// Detects WIT names that end up as the same Scala identifier after case conversion
+pub struct Collisions<'a> {
+    /// Used to point at the colliding declarations
+    locator: &'a Locator,
+
+    /// Whether colliding names are renamed rather than reported
+    disambiguate: bool,
+}
+
+impl<'a> Collisions<'a> {
+    /// Creates a new instance of `Collisions`
+    pub fn new(locator: &'a Locator, disambiguate: bool) -> Self {
+        Self {
+            locator,
+            disambiguate,
+        }
+    }
+
+    /// Checks that `names`, declared in the WIT item at `parent`, map to distinct Scala identifiers.
+    /// When disambiguating, every name that collides with a previous one gets a numeric suffix.
+    pub fn resolve<'n>(
+        &self,
+        kind: &str,
+        parent: &[&str],
+        names: impl IntoIterator<Item = &'n mut ScalaName>,
+    ) -> Result<()> {
+        self.resolve_across(names.into_iter().map(|name| (kind, parent.to_vec(), name)))
+    }
+
+    /// Checks that `names`, each of the given kind and declared in the WIT item at its parent,
+    /// map to distinct Scala identifiers, such as the types of all the generated interfaces
+    pub fn resolve_across<'n, 'p>(
+        &self,
+        names: impl IntoIterator<Item = (&'p str, Vec<&'p str>, &'n mut ScalaName)>,
+    ) -> Result<()> {
+        let mut names: Vec<_> = names.into_iter().collect();
+        let mut taken: HashMap<String, usize> = HashMap::new();
+
+        for i in 0..names.len() {
+            let ident = names[i].2.to_string();
+
+            match taken.get(&ident) {
+                None => {
+                    taken.insert(ident, i);
+                }
+                Some(_) if self.disambiguate => {
+                    let renamed = (1..)
+                        .map(|n| names[i].2.with_suffix(n))
+                        .find(|name| !taken.contains_key(&name.to_string()))
+                        .expect("an unbounded range always yields a free name");
+
+                    taken.insert(renamed.to_string(), i);
+                    *names[i].2 = renamed;
+                }
+                Some(&first) => {
+                    let path = |i: usize| {
+                        let (_, parent, name) = &names[i];
+                        parent
+                            .iter()
+                            .map(|segment| (*segment).to_owned())
+                            .chain([name.wit().to_owned()])
+                            .collect::<Vec<_>>()
+                    };
+                    let locate = |path: &[String]| {
+                        self.locator
+                            .locate(&path.iter().map(String::as_str).collect::<Vec<_>>())
+                            .map(|location| format!("\n  --> {location}"))
+                            .unwrap_or_default()
+                    };
+                    let (first_path, path) = (path(first), path(i));
+                    let (first_kind, kind) = (names[first].0, names[i].0);
+
+                    let message = if first_kind == kind && names[first].1 == names[i].1 {
+                        format!(
+                            "{kind} names `{}` and `{}` in `{}` both map to `{ident}` in Scala",
+                            names[first].2.wit(),
+                            names[i].2.wit(),
+                            names[i].1.join("."),
+                        )
+                    } else {
+                        format!(
+                            "{first_kind} `{}` and {} `{}` both map to `{ident}` in Scala",
+                            first_path.join("."),
+                            kind.to_lowercase(),
+                            path.join("."),
+                        )
+                    };
+
+                    return Err(eyre!(
+                        "{message}{}{}",
+                        locate(&first_path),
+                        locate(&path)
+                    ))
+                    .with_suggestion(|| {
+                        "Rename one of them in WIT, or pass --disambiguate to add a numeric suffix"
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The identifiers the given types refer to, which the names declared next to them must not shadow
+pub fn identifiers(types: impl Iterator<Item = String>) -> Vec<String> {
+    types
+        .flat_map(|ty| {
+            ty.split(|c: char| !c.is_alphanumeric() && c != '_')
+                .filter(|ident| !ident.is_empty())
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
@@ -0,0 +1,173 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::{eyre::eyre, Result, Section};
+
+/// The version of Scala the starter project is built with
+const SCALA_VERSION: &str = "2.13.14";
+
+/// The binary version of Scala, naming the directory the linked JS is written to
+const SCALA_BINARY_VERSION: &str = "2.13";
+
+/// The version of the Scala.js sbt plugin
+const SCALA_JS_VERSION: &str = "1.16.0";
+
+/// The version of sbt
+const SBT_VERSION: &str = "1.10.1";
+
+/// The version of ScalaCheck, which the generators of the test sources are built with
+const SCALACHECK_VERSION: &str = "1.18.1";
+
+/// The version range of componentize-js, which turns the linked JS into a component
+const COMPONENTIZE_JS_VERSION: &str = "^0.11.0";
+
+/// A file of a starter project, at a path relative to the project directory
+pub struct ScaffoldFile {
+    /// The path of the file, relative to the project directory
+    pub path: PathBuf,
+
+    /// The contents of the file
+    pub contents: String,
+}
+
+impl ScaffoldFile {
+    /// Constructs a `ScaffoldFile`
+    pub fn new(path: impl Into<PathBuf>, contents: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            contents: contents.into(),
+        }
+    }
+}
+
+/// What became of a file of a starter project
+pub enum Scaffolded {
+    /// The file was written at the given path
+    Written(PathBuf),
+
+    /// The file already existed at the given path, and was left untouched
+    Skipped(PathBuf),
+}
+
+/// The build files of a starter project named `name`, whose component
+/// is the world `world` of the WIT files in the `wit` directory, the tests
+/// depending on ScalaCheck if `scalacheck`
+pub fn build_files(name: &str, world: &str, scalacheck: bool) -> Vec<ScaffoldFile> {
+    let mut build_sbt = format!(
+        r#"enablePlugins(ScalaJSPlugin)
+
+name := "{name}"
+scalaVersion := "{SCALA_VERSION}"
+
+// The main method registers the implementation with the export when the module is loaded
+scalaJSUseMainModuleInitializer := true
+scalaJSLinkerConfig ~= (_.withModuleKind(ModuleKind.ESModule))
+"#
+    );
+    if scalacheck {
+        build_sbt.push_str(&format!(
+            "\nlibraryDependencies += \"org.scalacheck\" %%% \"scalacheck\" % \"{SCALACHECK_VERSION}\" % Test\n"
+        ));
+    }
+
+    let plugins_sbt =
+        format!("addSbtPlugin(\"org.scala-js\" % \"sbt-scalajs\" % \"{SCALA_JS_VERSION}\")\n");
+
+    let build_properties = format!("sbt.version={SBT_VERSION}\n");
+
+    let package_json = format!(
+        r#"{{
+  "name": "{name}",
+  "private": true,
+  "type": "module",
+  "scripts": {{
+    "build": "sbt fullLinkJS && node componentize.mjs"
+  }},
+  "devDependencies": {{
+    "@bytecodealliance/componentize-js": "{COMPONENTIZE_JS_VERSION}"
+  }}
+}}
+"#
+    );
+
+    let componentize = format!(
+        r#"// Turns the JS linked by `sbt fullLinkJS` into the component {name}.wasm
+import {{ componentize }} from "@bytecodealliance/componentize-js";
+import {{ readFile, writeFile }} from "node:fs/promises";
+
+const jsSource = await readFile("target/scala-{SCALA_BINARY_VERSION}/{name}-opt/main.js", "utf8");
+
+const {{ component }} = await componentize(jsSource, {{
+  witPath: "wit",
+  worldName: "{world}",
+}});
+
+await writeFile("{name}.wasm", component);
+"#
+    );
+
+    let gitignore = format!("target/\nnode_modules/\n{name}.wasm\n");
+
+    vec![
+        ScaffoldFile::new("build.sbt", build_sbt),
+        ScaffoldFile::new("project/plugins.sbt", plugins_sbt),
+        ScaffoldFile::new("project/build.properties", build_properties),
+        ScaffoldFile::new("package.json", package_json),
+        ScaffoldFile::new("componentize.mjs", componentize),
+        ScaffoldFile::new(".gitignore", gitignore),
+    ]
+}
+
+/// The directory of the Scala sources of `package` in the sbt configuration `scope`,
+/// such as `src/main/scala/com/example` for `main`
+pub fn source_dir(scope: &str, package: &str) -> PathBuf {
+    package.split('.').fold(
+        Path::new("src").join(scope).join("scala"),
+        |dir, segment| dir.join(segment),
+    )
+}
+
+/// Writes `files` under `dir`, leaving the ones that already exist untouched
+pub fn write(dir: &Path, files: Vec<ScaffoldFile>) -> Result<Vec<Scaffolded>> {
+    let mut scaffolded = Vec::new();
+
+    for file in files {
+        let path = dir.join(&file.path);
+        if path.exists() {
+            scaffolded.push(Scaffolded::Skipped(path));
+            continue;
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| eyre!("Cannot create directory {}: {e}", parent.display()))
+                .with_suggestion(|| "Make sure the project directory is writable")?;
+        }
+
+        // `create_new` fails instead of overwriting a file created in the meantime
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .and_then(|mut handle| std::io::Write::write_all(&mut handle, file.contents.as_bytes()))
+            .map_err(|e| eyre!("Cannot write {}: {e}", path.display()))?;
+
+        scaffolded.push(Scaffolded::Written(path));
+    }
+
+    Ok(scaffolded)
+}
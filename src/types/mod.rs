@@ -26,6 +26,7 @@ use color_eyre::{eyre::eyre, Result};
 use wit_parser::Type as WitType;
 
 /// Represents a Scala type
+#[derive(Clone)]
 pub enum Type {
     Primitive(Primitive),
     Custom(TypeName),
@@ -36,10 +37,14 @@ impl Type {
     pub fn from_wit(ty: WitType, type_map: &TypeMap) -> Result<Self> {
         match ty {
             WitType::Bool => Ok(Type::Primitive(Primitive::Boolean)),
-            WitType::U8 | WitType::S8 => Ok(Type::Primitive(Primitive::Byte)),
-            WitType::U16 | WitType::S16 => Ok(Type::Primitive(Primitive::Short)),
-            WitType::U32 | WitType::S32 => Ok(Type::Primitive(Primitive::Integer)),
-            WitType::U64 | WitType::S64 => Ok(Type::Primitive(Primitive::Long)),
+            WitType::U8 => Ok(Type::Primitive(Primitive::UnsignedByte)),
+            WitType::S8 => Ok(Type::Primitive(Primitive::Byte)),
+            WitType::U16 => Ok(Type::Primitive(Primitive::UnsignedShort)),
+            WitType::S16 => Ok(Type::Primitive(Primitive::Short)),
+            WitType::U32 => Ok(Type::Primitive(Primitive::UnsignedInteger)),
+            WitType::S32 => Ok(Type::Primitive(Primitive::Integer)),
+            WitType::U64 => Ok(Type::Primitive(Primitive::UnsignedLong)),
+            WitType::S64 => Ok(Type::Primitive(Primitive::Long)),
             WitType::F32 => Ok(Type::Primitive(Primitive::Float)),
             WitType::F64 => Ok(Type::Primitive(Primitive::Double)),
             WitType::Char => Ok(Type::Primitive(Primitive::Char)),
@@ -47,7 +52,17 @@ impl Type {
             WitType::Id(id) => type_map
                 .get(&id)
                 .map(|ty| Type::Custom(ty.clone()))
-                .ok_or(eyre!("Could not find type ID {id:?} in type_map")),
+                .ok_or(eyre!("Unsupported WIT type `{}`", type_map.wit_name(&ty))),
+        }
+    }
+
+    /// The Scala expression of the codec of the type, given the object holding the codecs of the
+    /// primitives and the combinators, and the path of the codec of a generated type
+    pub fn codec(&self, runtime: &str, path: &dyn Fn(String) -> String) -> String {
+        match self {
+            Type::Primitive(primitive) => format!("{runtime}.{}", primitive.codec()),
+            Type::Custom(TypeName::Concrete(name)) => path(name.to_string()),
+            Type::Custom(TypeName::Constructor(constructor)) => constructor.codec(runtime, path),
         }
     }
 }
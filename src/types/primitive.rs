@@ -14,27 +14,54 @@
 
 use std::fmt::Display;
 
-/// Represents a Scala primitive type
+/// Represents a Scala primitive type. Unsigned WIT integers share the Scala type of the signed ones,
+/// but are told apart since they are written differently.
+#[derive(Clone, Copy)]
 pub enum Primitive {
     Boolean,
     Byte,
+    UnsignedByte,
     Short,
+    UnsignedShort,
     Integer,
+    UnsignedInteger,
     Long,
+    UnsignedLong,
     Float,
     Double,
     Char,
     String,
 }
 
+impl Primitive {
+    /// The name of the codec of the primitive in the generated objects holding codecs
+    pub fn codec(&self) -> &'static str {
+        match self {
+            Primitive::Boolean => "bool",
+            Primitive::Byte => "s8",
+            Primitive::UnsignedByte => "u8",
+            Primitive::Short => "s16",
+            Primitive::UnsignedShort => "u16",
+            Primitive::Integer => "s32",
+            Primitive::UnsignedInteger => "u32",
+            Primitive::Long => "s64",
+            Primitive::UnsignedLong => "u64",
+            Primitive::Float => "f32",
+            Primitive::Double => "f64",
+            Primitive::Char => "char",
+            Primitive::String => "string",
+        }
+    }
+}
+
 impl Display for Primitive {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Primitive::Boolean => write!(f, "Boolean"),
-            Primitive::Byte => write!(f, "Byte"),
-            Primitive::Short => write!(f, "Short"),
-            Primitive::Integer => write!(f, "Integer"),
-            Primitive::Long => write!(f, "Long"),
+            Primitive::Byte | Primitive::UnsignedByte => write!(f, "Byte"),
+            Primitive::Short | Primitive::UnsignedShort => write!(f, "Short"),
+            Primitive::Integer | Primitive::UnsignedInteger => write!(f, "Integer"),
+            Primitive::Long | Primitive::UnsignedLong => write!(f, "Long"),
             Primitive::Float => write!(f, "Float"),
             Primitive::Double => write!(f, "Double"),
             Primitive::Char => write!(f, "Char"),
@@ -12,81 +12,301 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use color_eyre::Result;
 use id_arena::Id;
-use wit_parser::{Result_, TypeDef, TypeDefKind, UnresolvedPackage};
+use indexmap::IndexMap;
+use wit_parser::{
+    FunctionKind, Handle, Interface, Resolve, Result_, Results, Type as WitType, TypeDef,
+    TypeDefKind, TypeOwner,
+};
 
 use super::{ConcreteName, Constructor, TypeName};
+use crate::naming::{interface_name, Collisions, ScalaName};
 
 /// Contains mappings from type IDs to type names
-pub struct TypeMap(HashMap<Id<TypeDef>, TypeName>);
+pub struct TypeMap {
+    /// The Scala names of the types
+    names: HashMap<Id<TypeDef>, TypeName>,
+
+    /// The WIT spelling of the types, such as `list<u8>`
+    wit_names: HashMap<Id<TypeDef>, String>,
+
+    /// The names of the records returned by the functions with several named results,
+    /// by interface and WIT function name
+    result_names: HashMap<(Id<Interface>, String), TypeName>,
+
+    /// The names of the traits or facades of the generated interfaces
+    interface_names: HashMap<Id<Interface>, ScalaName>,
+}
 
 impl TypeMap {
     pub fn get(&self, k: &Id<TypeDef>) -> Option<&TypeName> {
-        self.0.get(k)
+        self.names.get(k)
+    }
+
+    /// The name of the trait or facade of a generated interface
+    pub fn interface_name(&self, interface: Id<Interface>) -> Option<&ScalaName> {
+        self.interface_names.get(&interface)
+    }
+
+    /// The name of the record returned by a function of an interface with several named results
+    pub fn result_name(&self, interface: Id<Interface>, function: &str) -> Option<&TypeName> {
+        self.result_names.get(&(interface, function.to_owned()))
     }
-}
 
-impl From<&UnresolvedPackage> for TypeMap {
-    fn from(unresolved_package: &UnresolvedPackage) -> Self {
-        let hash_map1 = unresolved_package
-            .types
+    /// Spells a type the way it is written in WIT, which is more precise than its Scala type
+    pub fn wit_name(&self, ty: &WitType) -> String {
+        match ty {
+            WitType::Bool => "bool".to_owned(),
+            WitType::U8 => "u8".to_owned(),
+            WitType::U16 => "u16".to_owned(),
+            WitType::U32 => "u32".to_owned(),
+            WitType::U64 => "u64".to_owned(),
+            WitType::S8 => "s8".to_owned(),
+            WitType::S16 => "s16".to_owned(),
+            WitType::S32 => "s32".to_owned(),
+            WitType::S64 => "s64".to_owned(),
+            WitType::F32 => "f32".to_owned(),
+            WitType::F64 => "f64".to_owned(),
+            WitType::Char => "char".to_owned(),
+            WitType::String => "string".to_owned(),
+            WitType::Id(id) => self.wit_names.get(id).cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Creates a `TypeMap` for all the resolved types, making sure that the names of the types
+    /// declared by the same interface or world do not collide. The types and the traits or
+    /// facades of the `generated` interfaces, the `roots` among them, all end up in the same Scala package, so their
+    /// names must not collide across interfaces either, except for the types brought in by `use`
+    /// under the same name, which refer to the type they are brought in from.
+    pub fn new(
+        resolve: &Resolve,
+        collisions: &Collisions,
+        roots: &[Id<Interface>],
+        generated: &HashSet<Id<Interface>>,
+    ) -> Result<Self> {
+        let mut named_types: IndexMap<TypeOwner, Vec<(Id<TypeDef>, ConcreteName)>> =
+            IndexMap::new();
+        let mut used = Vec::new();
+
+        for (id, ty) in resolve.types.iter() {
+            if let Some(name) = &ty.name {
+                match Self::used_as_is(resolve, id, generated) {
+                    Some(target) => used.push((id, target)),
+                    None => named_types
+                        .entry(ty.owner)
+                        .or_default()
+                        .push((id, ConcreteName::from(name.clone()))),
+                }
+            }
+        }
+
+        let mut generated: Vec<_> = generated.iter().copied().collect();
+        generated.sort_by_key(|id| id.index());
+        // Imported interfaces without functions get no facade of their own
+        let mut interface_names: Vec<_> = generated
             .iter()
-            .filter_map(|(id, ty)| {
-                ty.name
-                    .clone()
-                    .map(|name| (id, TypeName::Concrete(ConcreteName::from(name))))
+            .filter(|id| {
+                roots.contains(id)
+                    || resolve.interfaces[**id]
+                        .functions
+                        .values()
+                        .any(|function| function.kind == FunctionKind::Freestanding)
+            })
+            .filter_map(|id| {
+                Some((
+                    *id,
+                    interface_name(resolve.interfaces[*id].name.as_deref()?),
+                ))
             })
-            .collect::<HashMap<_, _>>();
+            .collect();
+        let mut across: Vec<_> = interface_names
+            .iter_mut()
+            .map(|(_, name)| ("Interface", Vec::new(), name))
+            .collect();
+
+        for (owner, names) in named_types.iter_mut() {
+            let owner_name = match owner {
+                TypeOwner::Interface(id) => resolve.interfaces[*id].name.as_deref(),
+                TypeOwner::World(id) => Some(resolve.worlds[*id].name.as_str()),
+                TypeOwner::None => None,
+            };
+            let parent: Vec<&str> = owner_name.into_iter().collect();
+
+            match owner {
+                TypeOwner::Interface(id) if generated.contains(id) => across.extend(
+                    names
+                        .iter_mut()
+                        .map(|(_, name)| ("Type", parent.clone(), name.as_mut())),
+                ),
+                _ => collisions.resolve(
+                    "Type",
+                    &parent,
+                    names.iter_mut().map(|(_, name)| name.as_mut()),
+                )?,
+            }
+        }
+        collisions.resolve_across(across)?;
 
-        let hash_map2 = unresolved_package
-            .types
+        let mut names: HashMap<Id<TypeDef>, TypeName> = named_types
+            .into_values()
+            .flatten()
+            .map(|(id, name)| (id, TypeName::Concrete(name)))
+            .collect();
+        for (id, target) in used {
+            if let Some(name) = names.get(&target).cloned() {
+                names.insert(id, name);
+            }
+        }
+
+        // Result records get a fresh name, a numeric suffix telling them apart from the other types
+        let mut taken: HashSet<String> = interface_names
             .iter()
-            .filter_map(|(id, ty)| {
-                if ty.name.is_none() {
-                    match ty.kind {
-                        TypeDefKind::List(ty) => Some((
-                            id,
-                            TypeName::Constructor(
-                                Constructor::new(
-                                    "WitList",
-                                    vec![Some(ty)],
-                                    &TypeMap(hash_map1.clone()),
-                                )
-                                .ok()?,
-                            ),
-                        )),
-                        TypeDefKind::Option(ty) => Some((
-                            id,
-                            TypeName::Constructor(
-                                Constructor::new(
-                                    "WitOption",
-                                    vec![Some(ty)],
-                                    &TypeMap(hash_map1.clone()),
-                                )
-                                .ok()?,
-                            ),
-                        )),
-                        TypeDefKind::Result(Result_ { ok, err }) => Some((
-                            id,
-                            TypeName::Constructor(
-                                Constructor::new(
-                                    "WitResult",
-                                    vec![ok, err],
-                                    &TypeMap(hash_map1.clone()),
-                                )
-                                .ok()?,
-                            ),
-                        )),
-                        _ => todo!("Unsupported constructor for type '{}'", ty.kind.as_str()),
+            .map(|(_, name)| name.to_string())
+            .chain(generated.iter().flat_map(|id| {
+                resolve.interfaces[*id]
+                    .types
+                    .values()
+                    .filter_map(|ty| names.get(ty).map(ToString::to_string))
+            }))
+            .collect();
+        let mut result_names = HashMap::new();
+        for id in &generated {
+            for function in resolve.interfaces[*id].functions.values() {
+                if matches!(&function.results, Results::Named(results) if results.len() >= 2) {
+                    let prefix = match function.kind {
+                        FunctionKind::Method(resource) | FunctionKind::Static(resource) => format!(
+                            "{}-{}",
+                            resolve.types[resource].name.as_deref().unwrap_or_default(),
+                            function.item_name()
+                        ),
+                        _ => function.item_name().to_owned(),
+                    };
+
+                    let mut name = ConcreteName::from(format!("{prefix}-result"));
+                    let base = name.as_mut().clone();
+                    let fresh = (0..)
+                        .map(|n| {
+                            if n == 0 {
+                                base.clone()
+                            } else {
+                                base.with_suffix(n)
+                            }
+                        })
+                        .find(|name| !taken.contains(&name.to_string()))
+                        .expect("an unbounded range always yields a free name");
+                    taken.insert(fresh.to_string());
+                    *name.as_mut() = fresh;
+
+                    result_names.insert((*id, function.name.clone()), TypeName::Concrete(name));
+                }
+            }
+        }
+
+        let mut type_map = Self {
+            names,
+            wit_names: HashMap::new(),
+            result_names,
+            interface_names: interface_names.into_iter().collect(),
+        };
+
+        // Anonymous types are listed in topological order, so the types they refer to are already mapped.
+        // Unsupported ones are left out, and reported when a generated signature refers to them.
+        for (id, ty) in resolve.types.iter() {
+            let wit_name = match &ty.name {
+                Some(name) => name.clone(),
+                None => type_map.anonymous_wit_name(&ty.kind),
+            };
+            type_map.wit_names.insert(id, wit_name);
+
+            if ty.name.is_none() {
+                let name = match &ty.kind {
+                    TypeDefKind::Handle(Handle::Own(resource) | Handle::Borrow(resource)) => {
+                        type_map.names.get(resource).cloned()
                     }
-                } else {
-                    None
+                    kind => Constructor::from_kind(kind, &type_map)
+                        .transpose()?
+                        .map(TypeName::Constructor),
+                };
+
+                if let Some(name) = name {
+                    type_map.names.insert(id, name);
                 }
-            })
-            .collect::<HashMap<_, _>>();
+            }
+        }
+
+        Ok(type_map)
+    }
+
+    /// Spells the definition of a type the way it is written in WIT, such as `tuple<u32>`
+    pub fn kind_wit_name(&self, kind: &TypeDefKind) -> String {
+        match kind {
+            TypeDefKind::Type(ty) => self.wit_name(ty),
+            kind => self.anonymous_wit_name(kind),
+        }
+    }
+
+    /// The type a type declared by a generated interface refers to, when it is brought in by `use`
+    /// from another generated interface under the same name
+    fn used_as_is(
+        resolve: &Resolve,
+        id: Id<TypeDef>,
+        generated: &HashSet<Id<Interface>>,
+    ) -> Option<Id<TypeDef>> {
+        let ty = &resolve.types[id];
+        let (TypeOwner::Interface(owner), TypeDefKind::Type(WitType::Id(target))) =
+            (ty.owner, &ty.kind)
+        else {
+            return None;
+        };
+        let target_ty = &resolve.types[*target];
+
+        match target_ty.owner {
+            TypeOwner::Interface(target_owner)
+                if target_owner != owner
+                    && generated.contains(&owner)
+                    && generated.contains(&target_owner)
+                    && target_ty.name == ty.name =>
+            {
+                Some(*target)
+            }
+            _ => None,
+        }
+    }
+
+    fn anonymous_wit_name(&self, kind: &TypeDefKind) -> String {
+        let optional =
+            |ty: &Option<WitType>| ty.as_ref().map_or("_".to_owned(), |ty| self.wit_name(ty));
 
-        Self(hash_map1.into_iter().chain(hash_map2).collect())
+        match kind {
+            TypeDefKind::List(ty) => format!("list<{}>", self.wit_name(ty)),
+            TypeDefKind::Option(ty) => format!("option<{}>", self.wit_name(ty)),
+            TypeDefKind::Result(Result_ {
+                ok: None,
+                err: None,
+            }) => "result".to_owned(),
+            TypeDefKind::Result(Result_ { ok, err: None }) => format!("result<{}>", optional(ok)),
+            TypeDefKind::Result(Result_ { ok, err }) => {
+                format!("result<{}, {}>", optional(ok), optional(err))
+            }
+            TypeDefKind::Tuple(tuple) => format!(
+                "tuple<{}>",
+                tuple
+                    .types
+                    .iter()
+                    .map(|ty| self.wit_name(ty))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            TypeDefKind::Handle(Handle::Own(id)) => {
+                format!("own<{}>", self.wit_name(&WitType::Id(*id)))
+            }
+            TypeDefKind::Handle(Handle::Borrow(id)) => {
+                format!("borrow<{}>", self.wit_name(&WitType::Id(*id)))
+            }
+            kind => kind.as_str().to_owned(),
+        }
     }
 }
@@ -15,10 +15,11 @@
 use std::fmt::Display;
 
 use color_eyre::Result;
-use convert_case::{Case, Casing};
-use wit_parser::Type as WitType;
+use convert_case::Case;
+use wit_parser::{Result_, Type as WitType, TypeDefKind};
 
 use super::{Type, TypeMap};
+use crate::naming::{ScalaName, FACADE_NAMES, TYPE_HELPERS};
 
 /// Represents the name of a Scala type
 #[derive(Clone)]
@@ -36,13 +37,33 @@ impl Display for TypeName {
     }
 }
 
+impl TypeName {
+    /// The name of the type as seen from JS, which matters for classes such as resources
+    pub fn js_name(&self) -> String {
+        match self {
+            TypeName::Concrete(name) => name.0.converted().to_owned(),
+            TypeName::Constructor(constructor) => constructor.to_string(),
+        }
+    }
+}
+
 /// Represents the name of a concrete Scala type
 #[derive(Clone)]
-pub struct ConcreteName(String);
+pub struct ConcreteName(ScalaName);
 
 impl From<String> for ConcreteName {
     fn from(name: String) -> Self {
-        Self(name.to_case(Case::UpperCamel))
+        Self(ScalaName::new(
+            &name,
+            Case::UpperCamel,
+            &[TYPE_HELPERS, FACADE_NAMES].concat(),
+        ))
+    }
+}
+
+impl AsMut<ScalaName> for ConcreteName {
+    fn as_mut(&mut self) -> &mut ScalaName {
+        &mut self.0
     }
 }
 
@@ -58,8 +79,8 @@ pub struct Constructor {
     /// The name of the type-constructor
     name: String,
 
-    /// The params of the type-constructor
-    params: Vec<String>,
+    /// The params of the type-constructor, missing ones standing for `Unit`
+    params: Vec<Option<Type>>,
 }
 
 impl Constructor {
@@ -80,23 +101,80 @@ impl Constructor {
 
         Ok(Self {
             name: name.to_owned(),
-            params: params?
-                .iter()
-                .map(|param| {
-                    param
-                        .as_ref()
-                        .map_or_else(|| "Unit".to_owned(), Type::to_string)
-                })
-                .collect(),
+            params: params?,
         })
     }
+
+    /// The name of the type-constructor, such as `WitList` or `js.Tuple2`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The params of the type-constructor, missing ones standing for `Unit`
+    pub fn params(&self) -> &[Option<Type>] {
+        &self.params
+    }
+
+    /// The Scala expression of the codec of the type, given the object holding the combinators
+    /// and the path of the codec of a generated type
+    pub fn codec(&self, runtime: &str, path: &dyn Fn(String) -> String) -> String {
+        let codecs = self
+            .params
+            .iter()
+            .map(|param| param.as_ref().map(|param| param.codec(runtime, path)));
+        let optional = |codec: Option<String>| {
+            codec.map_or("None".to_owned(), |codec| format!("Some({codec})"))
+        };
+
+        match self.name.as_str() {
+            "WitList" => format!("{runtime}.list({})", codecs.flatten().collect::<String>()),
+            "WitOption" => format!("{runtime}.option({})", codecs.flatten().collect::<String>()),
+            "WitResult" => format!(
+                "{runtime}.result[{}]({})",
+                self.params_string(),
+                codecs.map(optional).collect::<Vec<_>>().join(", ")
+            ),
+            _ => format!(
+                "{runtime}.tuple[{self}]({})",
+                codecs.flatten().collect::<Vec<_>>().join(", ")
+            ),
+        }
+    }
+
+    /// The params as written in Scala
+    fn params_string(&self) -> String {
+        self.params
+            .iter()
+            .map(|param| {
+                param
+                    .as_ref()
+                    .map_or_else(|| "Unit".to_owned(), Type::to_string)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Creates the type-constructor representing a list, an option, a result or a tuple,
+    /// tuples being arrays on the JS side
+    pub fn from_kind(kind: &TypeDefKind, type_map: &TypeMap) -> Option<Result<Self>> {
+        match kind {
+            TypeDefKind::Tuple(tuple) if (2..=22).contains(&tuple.types.len()) => Some(Self::new(
+                &format!("js.Tuple{}", tuple.types.len()),
+                tuple.types.iter().copied().map(Some).collect(),
+                type_map,
+            )),
+            TypeDefKind::List(ty) => Some(Self::new("WitList", vec![Some(*ty)], type_map)),
+            TypeDefKind::Option(ty) => Some(Self::new("WitOption", vec![Some(*ty)], type_map)),
+            TypeDefKind::Result(Result_ { ok, err }) => {
+                Some(Self::new("WitResult", vec![*ok, *err], type_map))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl Display for Constructor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let name = &self.name;
-        let params = self.params.join(", ");
-
-        write!(f, "{name}[{params}]")
+        write!(f, "{}[{}]", self.name, self.params_string())
     }
 }
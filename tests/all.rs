@@ -6,26 +6,92 @@
 //! to run
 //!
 //!     cargo test --test all foo.wit
+//!
+//! The options of a test are looked up in `VARIANTS` by the directory holding it, tests under the
+//! `fail` directory being expected to fail, their error message being compared against the `.err`
+//! file next to them. A directory listed several times shares its fixtures between several sets of
+//! options, the output of each being compared against `<fixture>.<variant>.scala`. The test sources,
+//! when the options generate any, are compared against the `.test.scala` file next to the output
 
 use anyhow::{bail, Context, Result};
-use golem_scalajs_wit_bindgen::generator::generate;
+use golem_scalajs_wit_bindgen::generator::{
+    generate, MapRepresentation, Options, Target, TypeOrder,
+};
 use libtest_mimic::{Arguments, Trial};
 use pretty_assertions::StrComparison;
+use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str;
 
+/// Sets the options of a variant
+type Configure = fn(&mut Options);
+
+/// The directories whose tests get options other than the defaults, each with the name of the
+/// variant when the directory shares its fixtures between several of them
+const VARIANTS: &[(&str, Option<&str>, Configure)] = &[
+    ("disambiguate", None, |options| options.disambiguate = true),
+    ("order", Some("source"), |_| {}),
+    ("order", Some("dependency"), |options| {
+        options.type_order = TypeOrder::Dependency
+    }),
+    ("import-specifier", None, |options| {
+        options.import_specifier = "./{package}-{version}/{interface}.js".to_owned()
+    }),
+    ("golem-host", None, |options| options.golem_host = true),
+    ("wasi", None, |options| options.wasi = true),
+    ("rpc-client", None, |options| {
+        options.target = Target::RpcClient
+    }),
+    ("rest-client", None, |options| {
+        options.target = Target::RestClient
+    }),
+    ("validate", None, |options| options.validate = true),
+    ("idiomatic", None, |options| options.idiomatic = true),
+    ("maps", None, |options| {
+        options.idiomatic = true;
+        options.maps = Some(MapRepresentation::Map);
+        options.map_types = HashMap::from([("labels".to_owned(), MapRepresentation::Dictionary)]);
+    }),
+    ("encodings", Some("wave"), |options| options.wave = true),
+    ("encodings", Some("circe"), |options| options.circe = true),
+    ("encodings", Some("scalacheck"), |options| {
+        options.scalacheck = true
+    }),
+    ("encodings", Some("scalacheck-validate"), |options| {
+        options.scalacheck = true;
+        options.validate = true;
+    }),
+    ("effects", Some("tagless"), |options| options.tagless = true),
+    ("effects", Some("test-doubles"), |options| {
+        options.test_doubles = true
+    }),
+];
+
+/// A fixture, generated with the options of a variant
+#[derive(Debug)]
+struct Test {
+    path: PathBuf,
+    variant: Option<&'static str>,
+    configure: Option<Configure>,
+}
+
 fn main() {
     env_logger::init();
 
     let trials: Vec<_> = find_tests()
         .into_iter()
         .map(|test| {
-            Trial::test(format!("{test:?}"), move || {
+            let name = match test.variant {
+                Some(variant) => format!("{:?} ({variant})", test.path),
+                None => format!("{:?}", test.path),
+            };
+
+            Trial::test(name.clone(), move || {
                 Runner {}
                     .run(&test)
-                    .context(format!("test {:?} failed", test))
+                    .context(format!("test {name} failed"))
                     .map_err(|e| format!("{e:?}").into())
             })
         })
@@ -44,7 +110,7 @@ fn main() {
     libtest_mimic::run(&args, trials).exit();
 }
 
-fn find_tests() -> Vec<PathBuf> {
+fn find_tests() -> Vec<Test> {
     fn find_tests_in_dir(path: &Path) -> Vec<PathBuf> {
         path.read_dir()
             .unwrap()
@@ -64,35 +130,83 @@ fn find_tests() -> Vec<PathBuf> {
             .collect()
     }
 
-    let mut tests = Vec::new();
     let root = "tests/wit".as_ref();
-    tests.extend(find_tests_in_dir(root));
-    tests.sort();
-    tests
+    let mut paths = find_tests_in_dir(root);
+    paths.sort();
+
+    paths
+        .into_iter()
+        .flat_map(|path| {
+            let variants: Vec<_> = VARIANTS
+                .iter()
+                .filter(|(dir, _, _)| Runner::in_dir(&path, dir))
+                .map(|(_, variant, configure)| (*variant, Some(*configure)))
+                .collect();
+            let variants = if variants.is_empty() {
+                vec![(None, None)]
+            } else {
+                variants
+            };
+
+            variants.into_iter().map(move |(variant, configure)| Test {
+                path: path.clone(),
+                variant,
+                configure,
+            })
+        })
+        .collect()
 }
 
 struct Runner {}
 
 impl Runner {
-    fn run(&mut self, test: &Path) -> Result<()> {
-        let result = generate(test, "tests");
-        let result: String = match result {
-            Ok(s) => s,
-            Err(e) => bail!("{:?}", e),
-        };
+    fn run(&mut self, test: &Test) -> Result<()> {
+        let mut options = Options::new("tests");
+        if let Some(configure) = test.configure {
+            configure(&mut options);
+        }
+        let result = generate(&test.path, &options);
+
+        if test
+            .path
+            .components()
+            .any(|component| component.as_os_str() == "fail")
+        {
+            match result {
+                Ok(_) => bail!("expected generation to fail"),
+                Err(e) => self.assert_equals(test, &e.to_string(), "err"),
+            }
+        } else {
+            let result = match result {
+                Ok(sources) => sources,
+                Err(e) => bail!("{:?}", e),
+            };
 
-        self.assert_equals(test, &result, "scala")
+            if let Some(tests) = &result.test {
+                self.assert_equals(test, tests, "test.scala")?;
+            }
+            self.assert_equals(test, &result.main, "scala")
+        }
+    }
+
+    fn in_dir(test: &Path, dir: &str) -> bool {
+        test.parent()
+            .and_then(Path::file_name)
+            .is_some_and(|name| name == dir)
     }
 
     fn assert_equals(
         &mut self,
-        test: &Path,
+        test: &Test,
         result: &str,
         extension: &str,
     ) -> Result<(), anyhow::Error> {
-        let result_file = test.with_extension(extension);
+        let result_file = match test.variant {
+            Some(variant) => test.path.with_extension(format!("{variant}.{extension}")),
+            None => test.path.with_extension(extension),
+        };
         if env::var_os("BLESS").is_some() {
-            fs::write(&result_file, &result).map_err(|e| e.into())
+            fs::write(&result_file, result).map_err(|e| e.into())
         } else {
             let expected = fs::read_to_string(&result_file).context(format!(
                 "failed to read test expectation file {:?}\nthis can be fixed with BLESS=1",
@@ -0,0 +1,100 @@
+//! Tests of the starter project written by `init`, which you can run with:
+//!
+//!     cargo test --test init
+
+use golem_scalajs_wit_bindgen::generator::{generate, init, Options, Scaffolded};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The WIT file the starter project implements
+const WIT: &str = "tests/wit/effects/shop.wit";
+
+/// The paths of the written or skipped files, relative to `dir`
+fn relative(dir: &Path, scaffolded: &[Scaffolded], written: bool) -> Vec<PathBuf> {
+    scaffolded
+        .iter()
+        .filter_map(|scaffolded| match (scaffolded, written) {
+            (Scaffolded::Written(path), true) | (Scaffolded::Skipped(path), false) => {
+                Some(path.strip_prefix(dir).unwrap().to_owned())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn init_twice() -> color_eyre::Result<()> {
+    let dir = std::env::temp_dir().join(format!("golem-scalajs-init-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let options = Options::new("com.example");
+    let read = |path: &str| fs::read_to_string(dir.join(path)).unwrap();
+
+    let first = init(Path::new(WIT), &dir, &options)?;
+    let expected: Vec<PathBuf> = [
+        "wit/shop.wit",
+        "src/main/scala/com/example/Api.scala",
+        "src/main/scala/com/example/ApiImpl.scala",
+        "build.sbt",
+        "project/plugins.sbt",
+        "project/build.properties",
+        "package.json",
+        "componentize.mjs",
+        ".gitignore",
+    ]
+    .iter()
+    .map(PathBuf::from)
+    .collect();
+    assert_eq!(relative(&dir, &first, true), expected);
+
+    assert_eq!(read("wit/shop.wit"), fs::read_to_string(WIT)?);
+    assert_eq!(
+        read("src/main/scala/com/example/Api.scala"),
+        generate(Path::new(WIT), &options)?.main
+    );
+    let skeleton = read("src/main/scala/com/example/ApiImpl.scala");
+    assert!(skeleton.contains("package com.example"));
+    assert!(skeleton.contains("object ApiImpl extends Api"));
+    assert!(read("build.sbt").contains("name := \"shop\""));
+    assert!(read("project/plugins.sbt").contains("\"sbt-scalajs\""));
+    assert!(read("project/build.properties").starts_with("sbt.version="));
+    assert!(read("package.json").contains("\"@bytecodealliance/componentize-js\""));
+    assert!(read("componentize.mjs").contains("worldName: \"shop\""));
+    assert_eq!(read(".gitignore"), "target/\nnode_modules/\nshop.wasm\n");
+
+    let edited = "package com.example\n\nobject ApiImpl\n";
+    fs::write(dir.join("src/main/scala/com/example/ApiImpl.scala"), edited)?;
+
+    let second = init(Path::new(WIT), &dir, &options)?;
+    assert!(relative(&dir, &second, true).is_empty());
+    assert_eq!(relative(&dir, &second, false), expected);
+    assert_eq!(read("src/main/scala/com/example/ApiImpl.scala"), edited);
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn init_test_sources() -> color_eyre::Result<()> {
+    let dir = std::env::temp_dir().join(format!("golem-scalajs-init-test-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let options = Options {
+        scalacheck: true,
+        ..Options::new("com.example")
+    };
+    let read = |path: &str| fs::read_to_string(dir.join(path)).unwrap();
+
+    let written = init(Path::new(WIT), &dir, &options)?;
+    assert!(relative(&dir, &written, true)
+        .contains(&PathBuf::from("src/test/scala/com/example/ApiTest.scala")));
+
+    let sources = generate(Path::new(WIT), &options)?;
+    assert_eq!(read("src/main/scala/com/example/Api.scala"), sources.main);
+    assert_eq!(
+        Some(read("src/test/scala/com/example/ApiTest.scala")),
+        sources.test
+    );
+    assert!(read("build.sbt").contains("\"scalacheck\" % \"1.18.1\" % Test"));
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -1,141 +1,357 @@
+// Generated by golem-scalajs-wit-bindgen
 
-                // Generated by golem-scalajs-wit-bindgen
-                package tests
-
-                import scala.scalajs.js
-                import scala.scalajs.js.JSConverters._
-
-                
-                sealed trait AuthResult extends js.Object {
-                    val userId: Integer
-val token: String
-                }
-                object AuthResult {
-                    def apply(userId: Integer, token: String): AuthResult = {
-                        val userId0: Integer = userId
-val token0: String = token
-
-                        new AuthResult {
-                            val userId: Integer = userId0
-val token: String = token0
-                        }
-                    }
-                }
-            
-
-                sealed trait UserCredentials extends js.Object {
-                    val username: String
-val passwordHash: String
-val salt: String
-                }
-                object UserCredentials {
-                    def apply(username: String, passwordHash: String, salt: String): UserCredentials = {
-                        val username0: String = username
-val passwordHash0: String = passwordHash
-val salt0: String = salt
-
-                        new UserCredentials {
-                            val username: String = username0
-val passwordHash: String = passwordHash0
-val salt: String = salt0
-                        }
-                    }
-                }
-            
-
-                
-                sealed trait LoginResult extends js.Object { self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }
-
-                object LoginResult {
-                    
-                            def success(value: AuthResult) = new LoginResult {
-                                type Type = AuthResult
-                                
-                                val tag: String = "success"
-                                override val `val`: js.UndefOr[Type] = value
-                            }
-                        
-
-                            def failure(value: LoginError) = new LoginResult {
-                                type Type = LoginError
-                                
-                                val tag: String = "failure"
-                                override val `val`: js.UndefOr[Type] = value
-                            }
-                        
-                }
-            
-
-                sealed trait AuthStatus extends js.Object { self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }
-
-                object AuthStatus {
-                    
-                            def valid(value: Integer) = new AuthStatus {
-                                type Type = Integer
-                                
-                                val tag: String = "valid"
-                                override val `val`: js.UndefOr[Type] = value
-                            }
-                        
-
-                            val expired = new AuthStatus {
-                                type Type = Nothing
-                                
-                                val tag: String = "expired"
-                                
-                            }
-                        
-
-                            val invalid = new AuthStatus {
-                                type Type = Nothing
-                                
-                                val tag: String = "invalid"
-                                
-                            }
-                        
-                }
-            
-
-                trait Api {
-                    type WitResult[+Ok, +Err] = Ok
-                    object WitResult {
-                        def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
-
-                        def err[Err](value: Err): WitResult[Nothing, Err] = throw js.JavaScriptException(value)
-
-                        val unit: WitResult[Unit, Nothing] = ()
-                    }
-
-                    type WitOption[+A] = js.UndefOr[A]
-                    object WitOption {
-                        def some[A](value: A): WitOption[A] = value
-
-                        val none: WitOption[Nothing] = js.undefined
-
-                        def fromOption[A](option: Option[A]) =
-                        option match {
-                            case Some(value) => value.asInstanceOf[js.UndefOr[A]]
-                            case None        => js.undefined
-                        }
-                    }
-
-                    type WitList[A] = js.Array[A]
-                    object WitList {
-                        def fromList[A](list: List[A]): WitList[A] = list.toJSArray
-                    }
-                    
-                    def registerUser(username: String, password: String, email: String): AuthResult
-def login(username: String, password: String): LoginResult
-def validateToken(token: String): AuthStatus
-                }
-            
\ No newline at end of file
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
+
+package tests {
+  sealed trait AuthResult extends js.Object {
+    val userId: Integer
+    val token: String
+  }
+
+  object AuthResult {
+    def apply(userId: Integer, token: String): AuthResult = {
+      val userId0: Integer = userId
+      val token0: String = token
+
+      new AuthResult {
+        val userId: Integer = userId0
+        val token: String = token0
+      }
+    }
+
+    /** The structural methods of `AuthResult`, which leave its JS representation untouched */
+    implicit final class Ops(self: AuthResult) extends AnyVal {
+      /** A new `AuthResult` with the given fields replaced */
+      def copy(
+          userId: Integer = self.userId,
+          token: String = self.token
+      ): AuthResult = AuthResult(userId, token)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: AuthResult): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `AuthResult` */
+      def show: String = "AuthResult" + Structural.show(self)
+    }
+  }
+
+  sealed trait UserCredentials extends js.Object {
+    val username: String
+    val passwordHash: String
+    val salt: String
+  }
+
+  object UserCredentials {
+    def apply(
+        username: String,
+        passwordHash: String,
+        salt: String
+    ): UserCredentials = {
+      val username0: String = username
+      val passwordHash0: String = passwordHash
+      val salt0: String = salt
+
+      new UserCredentials {
+        val username: String = username0
+        val passwordHash: String = passwordHash0
+        val salt: String = salt0
+      }
+    }
+
+    /** The structural methods of `UserCredentials`, which leave its JS representation untouched */
+    implicit final class Ops(self: UserCredentials) extends AnyVal {
+      /** A new `UserCredentials` with the given fields replaced */
+      def copy(
+          username: String = self.username,
+          passwordHash: String = self.passwordHash,
+          salt: String = self.salt
+      ): UserCredentials = UserCredentials(username, passwordHash, salt)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: UserCredentials): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `UserCredentials` */
+      def show: String = "UserCredentials" + Structural.show(self)
+    }
+  }
+
+  sealed trait LoginResult extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object LoginResult {
+    def success(value: AuthResult) = new LoginResult {
+      type Type = AuthResult
+      val tag: String = "success"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def failure(value: LoginError) = new LoginResult {
+      type Type = LoginError
+      val tag: String = "failure"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `LoginResult`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Success(value: AuthResult) extends Case
+      final case class Failure(value: LoginError) extends Case
+    }
+
+    /** The pattern matching and structural methods of `LoginResult` */
+    implicit final class Ops(self: LoginResult) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](success: AuthResult => A, failure: LoginError => A): A = self.tag match {
+        case "success" => success(self.`val`.asInstanceOf[AuthResult])
+        case "failure" => failure(self.`val`.asInstanceOf[LoginError])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of LoginResult")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Success(_), Case.Failure(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: LoginResult): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `LoginResult` */
+      def show: String = "LoginResult." + Structural.show(self)
+    }
+  }
+
+  @js.native
+  sealed trait LoginError extends js.Any
+
+  object LoginError {
+    val invalidCredentials: LoginError =
+      "invalid-credentials".asInstanceOf[LoginError]
+
+    val userNotFound: LoginError = "user-not-found".asInstanceOf[LoginError]
+    val accountLocked: LoginError = "account-locked".asInstanceOf[LoginError]
+
+    val values: js.Array[LoginError] =
+      js.Array(invalidCredentials, userNotFound, accountLocked)
+  }
+
+  sealed trait AuthStatus extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object AuthStatus {
+    def valid(value: Integer) = new AuthStatus {
+      type Type = Integer
+      val tag: String = "valid"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val expired = new AuthStatus {
+      type Type = Nothing
+      val tag: String = "expired"
+    }
+
+    val invalid = new AuthStatus {
+      type Type = Nothing
+      val tag: String = "invalid"
+    }
+
+    /** A case of `AuthStatus`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Valid(value: Integer) extends Case
+      case object Expired extends Case
+      case object Invalid extends Case
+    }
+
+    /** The pattern matching and structural methods of `AuthStatus` */
+    implicit final class Ops(self: AuthStatus) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](valid: Integer => A, expired: => A, invalid: => A): A = self.tag match {
+        case "valid" => valid(self.`val`.asInstanceOf[Integer])
+        case "expired" => expired
+        case "invalid" => invalid
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of AuthStatus")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Valid(_), Case.Expired, Case.Invalid)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: AuthStatus): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `AuthStatus` */
+      def show: String = "AuthStatus." + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def registerUser(
+        username: String,
+        password: String,
+        email: String
+    ): AuthResult
+
+    def login(username: String, password: String): LoginResult
+    def validateToken(token: String): AuthStatus
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `pack:name/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("registerUser")
+    override def registerUser(
+        username: String,
+        password: String,
+        email: String
+    ): AuthResult = registered.registerUser(username, password, email)
+
+    @JSExport("login")
+    override def login(username: String, password: String): LoginResult =
+      registered.login(username, password)
+
+    @JSExport("validateToken")
+    override def validateToken(token: String): AuthStatus =
+      registered.validateToken(token)
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
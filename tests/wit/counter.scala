@@ -1,43 +1,64 @@
+// Generated by golem-scalajs-wit-bindgen
 
-                // Generated by golem-scalajs-wit-bindgen
-                package tests
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
 
-                import scala.scalajs.js
-                import scala.scalajs.js.JSConverters._
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
 
-                
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
 
-                
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
 
-                trait Api {
-                    type WitResult[+Ok, +Err] = Ok
-                    object WitResult {
-                        def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+    val unit: WitResult[Unit, Nothing] = ()
+  }
 
-                        def err[Err](value: Err): WitResult[Nothing, Err] = throw js.JavaScriptException(value)
+  type WitOption[+A] = js.UndefOr[A]
 
-                        val unit: WitResult[Unit, Nothing] = ()
-                    }
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
 
-                    type WitOption[+A] = js.UndefOr[A]
-                    object WitOption {
-                        def some[A](value: A): WitOption[A] = value
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
 
-                        val none: WitOption[Nothing] = js.undefined
+  type WitList[A] = js.Array[A]
 
-                        def fromOption[A](option: Option[A]) =
-                        option match {
-                            case Some(value) => value.asInstanceOf[js.UndefOr[A]]
-                            case None        => js.undefined
-                        }
-                    }
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
 
-                    type WitList[A] = js.Array[A]
-                    object WitList {
-                        def fromList[A](list: List[A]): WitList[A] = list.toJSArray
-                    }
-                    
-                    def add(value: Long): Unit
-def get(): Long
-                }
-            
\ No newline at end of file
+package tests {
+  trait Api {
+    def add(value: Long): Unit
+    def get(): Long
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `pack:name/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("add")
+    override def add(value: Long): Unit = registered.add(value)
+
+    @JSExport("get")
+    override def get(): Long = registered.get()
+  }
+}
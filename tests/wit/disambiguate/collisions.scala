@@ -0,0 +1,209 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type T1 = Integer
+  type T11 = String
+}
+
+package tests {
+  sealed trait Request extends js.Object {
+    val httpUrl: String
+    @JSName("httpUrl") val httpUrl1: String
+  }
+
+  object Request {
+    def apply(httpUrl: String, httpUrl1: String): Request = {
+      val httpUrl0: String = httpUrl
+      val httpUrl10: String = httpUrl1
+
+      new Request {
+        val httpUrl: String = httpUrl0
+        @JSName("httpUrl") val httpUrl1: String = httpUrl10
+      }
+    }
+
+    /** The structural methods of `Request`, which leave its JS representation untouched */
+    implicit final class Ops(self: Request) extends AnyVal {
+      /** A new `Request` with the given fields replaced */
+      def copy(
+          httpUrl: String = self.httpUrl,
+          httpUrl1: String = self.httpUrl1
+      ): Request = Request(httpUrl, httpUrl1)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Request): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Request` */
+      def show: String = "Request" + Structural.show(self)
+    }
+  }
+
+  sealed trait Response extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Response {
+    val notFound = new Response {
+      type Type = Nothing
+      val tag: String = "not-found"
+    }
+
+    def notFound1(value: String) = new Response {
+      type Type = String
+      val tag: String = "NOT-FOUND"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `Response`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object NotFound extends Case
+      final case class NotFound1(value: String) extends Case
+    }
+
+    /** The pattern matching and structural methods of `Response` */
+    implicit final class Ops(self: Response) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](notFound: => A, notFound1: String => A): A = self.tag match {
+        case "not-found" => notFound
+        case "NOT-FOUND" => notFound1(self.`val`.asInstanceOf[String])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Response")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.NotFound, Case.NotFound1(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Response): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Response` */
+      def show: String = "Response." + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def get(req: Request, userId: T1, userId1: T11): Response
+    def get1(): T11
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -0,0 +1,198 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type StockLevel = Level
+}
+
+package tests {
+  sealed trait Level1 extends js.Object {
+    val name: String
+  }
+
+  object Level1 {
+    def apply(name: String): Level1 = {
+      val name0: String = name
+
+      new Level1 {
+        val name: String = name0
+      }
+    }
+
+    /** The structural methods of `Level1`, which leave its JS representation untouched */
+    implicit final class Ops(self: Level1) extends AnyVal {
+      /** A new `Level1` with the given fields replaced */
+      def copy(name: String = self.name): Level1 = Level1(name)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Level1): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Level1` */
+      def show: String = "Level1" + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def check(stock: StockLevel): Level1
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `foo:levels/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("check")
+    override def check(stock: StockLevel): Level1 = registered.check(stock)
+  }
+
+  sealed trait Level extends js.Object {
+    val amount: Integer
+  }
+
+  object Level {
+    def apply(amount: Integer): Level = {
+      val amount0: Integer = amount
+
+      new Level {
+        val amount: Integer = amount0
+      }
+    }
+
+    /** The structural methods of `Level`, which leave its JS representation untouched */
+    implicit final class Ops(self: Level) extends AnyVal {
+      /** A new `Level` with the given fields replaced */
+      def copy(amount: Integer = self.amount): Level = Level(amount)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Level): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Level` */
+      def show: String = "Level" + Structural.show(self)
+    }
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -0,0 +1,385 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  /** An amount in cents */
+  type Amount = Integer
+}
+
+package tests {
+  /** A customer of the shop */
+  sealed trait Customer extends js.Object {
+    /** The unique ID, as in `&#47;* id *&#47;` */
+    val id: Long
+
+    /** The display name */
+    val name: String
+
+    val addresses: WitList[String]
+  }
+
+  object Customer {
+    def apply(id: Long, name: String, addresses: WitList[String]): Customer = {
+      val id0: Long = id
+      val name0: String = name
+      val addresses0: WitList[String] = addresses
+
+      new Customer {
+        val id: Long = id0
+        val name: String = name0
+        val addresses: WitList[String] = addresses0
+      }
+    }
+
+    /** The structural methods of `Customer`, which leave its JS representation untouched */
+    implicit final class Ops(self: Customer) extends AnyVal {
+      /** A new `Customer` with the given fields replaced */
+      def copy(
+          id: Long = self.id,
+          name: String = self.name,
+          addresses: WitList[String] = self.addresses
+      ): Customer = Customer(id, name, addresses)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Customer): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Customer` */
+      def show: String = "Customer" + Structural.show(self)
+    }
+  }
+
+  /** The state of an order */
+  sealed trait Status extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Status {
+    /** Not paid yet */
+    val pending = new Status {
+      type Type = Nothing
+      val tag: String = "pending"
+    }
+
+    /** Shipped with a tracking number */
+    def shipped(value: String) = new Status {
+      type Type = String
+      val tag: String = "shipped"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def cancelled(value: WitOption[String]) = new Status {
+      type Type = WitOption[String]
+      val tag: String = "cancelled"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `Status`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      /** Not paid yet */
+      case object Pending extends Case
+
+      /** Shipped with a tracking number */
+      final case class Shipped(value: String) extends Case
+
+      final case class Cancelled(value: WitOption[String]) extends Case
+    }
+
+    /** The pattern matching and structural methods of `Status` */
+    implicit final class Ops(self: Status) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          pending: => A,
+          shipped: String => A,
+          cancelled: WitOption[String] => A
+      ): A = self.tag match {
+        case "pending" => pending
+        case "shipped" => shipped(self.`val`.asInstanceOf[String])
+        case "cancelled" =>
+          cancelled(self.`val`.asInstanceOf[WitOption[String]])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Status")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Pending, Case.Shipped(_), Case.Cancelled(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Status): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Status` */
+      def show: String = "Status." + Structural.show(self)
+    }
+  }
+
+  /** A currency code */
+  @js.native
+  sealed trait Currency extends js.Any
+
+  object Currency {
+    /** Euro */
+    val eur: Currency = "eur".asInstanceOf[Currency]
+
+    val usd: Currency = "usd".asInstanceOf[Currency]
+    val values: js.Array[Currency] = js.Array(eur, usd)
+  }
+
+  /** Permissions of a customer */
+  sealed trait Permissions extends js.Object {
+    /** Can place orders */
+    val order: Boolean
+
+    val refund: Boolean
+  }
+
+  object Permissions {
+    def apply(order: Boolean = false, refund: Boolean = false): Permissions = {
+      val order0: Boolean = order
+      val refund0: Boolean = refund
+
+      new Permissions {
+        val order: Boolean = order0
+        val refund: Boolean = refund0
+      }
+    }
+
+    /** The structural methods of `Permissions`, which leave its JS representation untouched */
+    implicit final class Ops(self: Permissions) extends AnyVal {
+      /** A new `Permissions` with the given fields replaced */
+      def copy(
+          order: Boolean = self.order,
+          refund: Boolean = self.refund
+      ): Permissions = Permissions(order, refund)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Permissions): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Permissions` */
+      def show: String = "Permissions" + Structural.show(self)
+    }
+  }
+
+  /** The results of `stats` */
+  sealed trait StatsResult extends js.Object {
+    val orders: Integer
+    val revenue: WitOption[Amount]
+  }
+
+  object StatsResult {
+    def apply(orders: Integer, revenue: WitOption[Amount]): StatsResult = {
+      val orders0: Integer = orders
+      val revenue0: WitOption[Amount] = revenue
+
+      new StatsResult {
+        val orders: Integer = orders0
+        val revenue: WitOption[Amount] = revenue0
+      }
+    }
+
+    /** The structural methods of `StatsResult`, which leave its JS representation untouched */
+    implicit final class Ops(self: StatsResult) extends AnyVal {
+      /** A new `StatsResult` with the given fields replaced */
+      def copy(
+          orders: Integer = self.orders,
+          revenue: WitOption[Amount] = self.revenue
+      ): StatsResult = StatsResult(orders, revenue)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: StatsResult): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `StatsResult` */
+      def show: String = "StatsResult" + Structural.show(self)
+    }
+  }
+
+  /** The API of a tiny shop.
+    *
+    * Prices are in **cents**.
+    */
+  trait Api {
+    /** Places an order.
+      *
+      * Comments such as `*&#47;` must not end the Scaladoc early:
+      * - one item
+      * - another item
+      *
+      * @param customer `customer`
+      * @param items `list<string>`
+      * @param currency `currency`
+      * @return `result<u64, string>`
+      */
+    def placeOrder(
+        customer: Customer,
+        items: WitList[String],
+        currency: Currency
+    ): WitResult[Long, String]
+
+    /** Cancels all orders */
+    def cancelAll(): Unit
+
+    /** Returns the stats of the shop
+      *
+      * @return `(orders: u32, revenue: option<amount>)`
+      */
+    def stats(): StatsResult
+
+    def undocumented(id: Long): Boolean
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:docs/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("placeOrder")
+    override def placeOrder(
+        customer: Customer,
+        items: WitList[String],
+        currency: Currency
+    ): WitResult[Long, String] =
+      registered.placeOrder(customer, items, currency)
+
+    @JSExport("cancelAll")
+    override def cancelAll(): Unit = registered.cancelAll()
+
+    @JSExport("stats")
+    override def stats(): StatsResult = registered.stats()
+
+    @JSExport("undocumented")
+    override def undocumented(id: Long): Boolean = registered.undocumented(id)
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
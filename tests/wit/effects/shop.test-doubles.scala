@@ -0,0 +1,229 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
+
+package tests {
+  /** An item in the cart */
+  sealed trait Item extends js.Object {
+    val sku: String
+    val quantity: Integer
+  }
+
+  object Item {
+    def apply(sku: String, quantity: Integer): Item = {
+      val sku0: String = sku
+      val quantity0: Integer = quantity
+
+      new Item {
+        val sku: String = sku0
+        val quantity: Integer = quantity0
+      }
+    }
+
+    /** The structural methods of `Item`, which leave its JS representation untouched */
+    implicit final class Ops(self: Item) extends AnyVal {
+      /** A new `Item` with the given fields replaced */
+      def copy(
+          sku: String = self.sku,
+          quantity: Integer = self.quantity
+      ): Item = Item(sku, quantity)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Item): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Item` */
+      def show: String = "Item" + Structural.show(self)
+    }
+  }
+
+  /** The results of `totals` */
+  sealed trait TotalsResult extends js.Object {
+    val count: Integer
+    val amount: Double
+  }
+
+  object TotalsResult {
+    def apply(count: Integer, amount: Double): TotalsResult = {
+      val count0: Integer = count
+      val amount0: Double = amount
+
+      new TotalsResult {
+        val count: Integer = count0
+        val amount: Double = amount0
+      }
+    }
+
+    /** The structural methods of `TotalsResult`, which leave its JS representation untouched */
+    implicit final class Ops(self: TotalsResult) extends AnyVal {
+      /** A new `TotalsResult` with the given fields replaced */
+      def copy(
+          count: Integer = self.count,
+          amount: Double = self.amount
+      ): TotalsResult = TotalsResult(count, amount)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: TotalsResult): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `TotalsResult` */
+      def show: String = "TotalsResult" + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    /** Looks up an item, which may call another service
+      *
+      * @param sku `string`
+      * @return `option<item>`
+      */
+    def getItem(sku: String): WitOption[Item]
+
+    def checkout(items: WitList[Item]): WitResult[Long, String]
+    def totals(): TotalsResult
+    def clear(): Unit
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:shop/api@1.2.0` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("getItem")
+    override def getItem(sku: String): WitOption[Item] = registered.getItem(sku)
+
+    @JSExport("checkout")
+    override def checkout(items: WitList[Item]): WitResult[Long, String] =
+      registered.checkout(items)
+
+    @JSExport("totals")
+    override def totals(): TotalsResult = registered.totals()
+
+    @JSExport("clear")
+    override def clear(): Unit = registered.clear()
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -0,0 +1,57 @@
+// Generated by golem-scalajs-wit-bindgen
+package tests
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+/** A stub of `Api`, every method of which fails unless overridden */
+class StubApi extends Api {
+  override def getItem(sku: String): WitOption[Item] = ???
+  override def checkout(items: WitList[Item]): WitResult[Long, String] = ???
+  override def totals(): TotalsResult = ???
+  override def clear(): Unit = ???
+}
+
+/** A mock of `Api`, which records its calls and returns the responses scripted for them */
+class MockApi extends Api {
+  /** The calls made so far, as the name of the function and its arguments, in order */
+  val calls: scala.collection.mutable.ArrayBuffer[(String, List[Any])] =
+    scala.collection.mutable.ArrayBuffer.empty
+
+  /** The response of `getItem` to its arguments, which fails until scripted */
+  var getItemResponse: (String) => WitOption[Item] =
+    (_) => throw new NotImplementedError("No response was scripted for getItem")
+
+  override def getItem(sku: String): WitOption[Item] = {
+    calls += (("getItem", List(sku)))
+    getItemResponse(sku)
+  }
+
+  /** The response of `checkout` to its arguments, which fails until scripted */
+  var checkoutResponse: (WitList[Item]) => WitResult[Long, String] =
+    (_) => throw new NotImplementedError("No response was scripted for checkout")
+
+  override def checkout(items: WitList[Item]): WitResult[Long, String] = {
+    calls += (("checkout", List(items)))
+    checkoutResponse(items)
+  }
+
+  /** The response of `totals`, which fails until scripted */
+  var totalsResponse: () => TotalsResult =
+    () => throw new NotImplementedError("No response was scripted for totals")
+
+  override def totals(): TotalsResult = {
+    calls += (("totals", List()))
+    totalsResponse()
+  }
+
+  /** The response of `clear`, which fails until scripted */
+  var clearResponse: () => Unit =
+    () => throw new NotImplementedError("No response was scripted for clear")
+
+  override def clear(): Unit = {
+    calls += (("clear", List()))
+    clearResponse()
+  }
+}
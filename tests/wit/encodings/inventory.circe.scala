@@ -0,0 +1,605 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type Sku = String
+
+  object Sku {
+    implicit val circe: io.circe.Codec[Sku] = CirceCodecs.string
+  }
+
+  type Quantity = Integer
+
+  object Quantity {
+    implicit val circe: io.circe.Codec[Quantity] = CirceCodecs.u32
+  }
+
+  type Outcome = WitResult[WitList[Item], String]
+
+  object Outcome {
+    implicit val circe: io.circe.Codec[Outcome] =
+      CirceCodecs.result[WitList[Item], String](Some(CirceCodecs.list(Item.circe)), Some(CirceCodecs.string))
+  }
+}
+
+package tests {
+  @js.native
+  sealed trait Measure extends js.Any
+
+  object Measure {
+    val piece: Measure = "piece".asInstanceOf[Measure]
+    val kilogram: Measure = "kilogram".asInstanceOf[Measure]
+    val wave_: Measure = "wave".asInstanceOf[Measure]
+    val values: js.Array[Measure] = js.Array(piece, kilogram, wave_)
+
+    implicit val circe: io.circe.Codec[Measure] =
+      CirceCodecs.enumeration[Measure]("piece", "kilogram", "wave")
+  }
+
+  sealed trait Tags extends js.Object {
+    val fragile: Boolean
+    val perishable: Boolean
+    val `true`: Boolean
+  }
+
+  object Tags {
+    def apply(
+        fragile: Boolean = false,
+        perishable: Boolean = false,
+        `true`: Boolean = false
+    ): Tags = {
+      val fragile0: Boolean = fragile
+      val perishable0: Boolean = perishable
+      val true0: Boolean = `true`
+
+      new Tags {
+        val fragile: Boolean = fragile0
+        val perishable: Boolean = perishable0
+        val `true`: Boolean = true0
+      }
+    }
+
+    /** The structural methods of `Tags`, which leave its JS representation untouched */
+    implicit final class Ops(self: Tags) extends AnyVal {
+      /** A new `Tags` with the given fields replaced */
+      def copy(
+          fragile: Boolean = self.fragile,
+          perishable: Boolean = self.perishable,
+          `true`: Boolean = self.`true`
+      ): Tags = Tags(fragile, perishable, `true`)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Tags): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Tags` */
+      def show: String = "Tags" + Structural.show(self)
+    }
+
+    implicit val circe: io.circe.Codec[Tags] = CirceCodecs.flags[Tags](
+      CirceCodecs.Flag("fragile", "fragile"),
+      CirceCodecs.Flag("perishable", "perishable"),
+      CirceCodecs.Flag("true", "true")
+    )
+  }
+
+  sealed trait Item extends js.Object {
+    val sku: Sku
+    val name: String
+    val quantity: Quantity
+    val measure: Measure
+    val tags: Tags
+    val weight: WitOption[Double]
+    val dimensions: js.Tuple3[Short, Short, Short]
+    val initial: Char
+    val offset: Byte
+  }
+
+  object Item {
+    def apply(
+        sku: Sku,
+        name: String,
+        quantity: Quantity,
+        measure: Measure,
+        tags: Tags,
+        weight: WitOption[Double],
+        dimensions: js.Tuple3[Short, Short, Short],
+        initial: Char,
+        offset: Byte
+    ): Item = {
+      val sku0: Sku = sku
+      val name0: String = name
+      val quantity0: Quantity = quantity
+      val measure0: Measure = measure
+      val tags0: Tags = tags
+      val weight0: WitOption[Double] = weight
+      val dimensions0: js.Tuple3[Short, Short, Short] = dimensions
+      val initial0: Char = initial
+      val offset0: Byte = offset
+
+      new Item {
+        val sku: Sku = sku0
+        val name: String = name0
+        val quantity: Quantity = quantity0
+        val measure: Measure = measure0
+        val tags: Tags = tags0
+        val weight: WitOption[Double] = weight0
+        val dimensions: js.Tuple3[Short, Short, Short] = dimensions0
+        val initial: Char = initial0
+        val offset: Byte = offset0
+      }
+    }
+
+    /** The structural methods of `Item`, which leave its JS representation untouched */
+    implicit final class Ops(self: Item) extends AnyVal {
+      /** A new `Item` with the given fields replaced */
+      def copy(
+          sku: Sku = self.sku,
+          name: String = self.name,
+          quantity: Quantity = self.quantity,
+          measure: Measure = self.measure,
+          tags: Tags = self.tags,
+          weight: WitOption[Double] = self.weight,
+          dimensions: js.Tuple3[Short, Short, Short] = self.dimensions,
+          initial: Char = self.initial,
+          offset: Byte = self.offset
+      ): Item =
+        Item(sku, name, quantity, measure, tags, weight, dimensions, initial, offset)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Item): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Item` */
+      def show: String = "Item" + Structural.show(self)
+    }
+
+    implicit val circe: io.circe.Codec[Item] = CirceCodecs.record[Item](
+      CirceCodecs.Field("sku", "sku", Sku.circe),
+      CirceCodecs.Field("name", "name", CirceCodecs.string),
+      CirceCodecs.Field("quantity", "quantity", Quantity.circe),
+      CirceCodecs.Field("measure", "measure", Measure.circe),
+      CirceCodecs.Field("tags", "tags", Tags.circe),
+      CirceCodecs.Field(
+        "weight",
+        "weight",
+        CirceCodecs.option(CirceCodecs.f64)
+      ),
+      CirceCodecs.Field(
+        "dimensions",
+        "dimensions",
+        CirceCodecs.tuple[js.Tuple3[Short, Short, Short]](CirceCodecs.u16, CirceCodecs.u16, CirceCodecs.u16)
+      ),
+      CirceCodecs.Field("initial", "initial", CirceCodecs.char),
+      CirceCodecs.Field("offset", "offset", CirceCodecs.s8)
+    )
+  }
+
+  sealed trait Change extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Change {
+    def added(value: Item) = new Change {
+      type Type = Item
+      val tag: String = "added"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def removed(value: WitList[Sku]) = new Change {
+      type Type = WitList[Sku]
+      val tag: String = "removed"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val cleared = new Change {
+      type Type = Nothing
+      val tag: String = "cleared"
+    }
+
+    def ok(value: Long) = new Change {
+      type Type = Long
+      val tag: String = "ok"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `Change`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Added(value: Item) extends Case
+      final case class Removed(value: WitList[Sku]) extends Case
+      case object Cleared extends Case
+      final case class Ok(value: Long) extends Case
+    }
+
+    /** The pattern matching and structural methods of `Change` */
+    implicit final class Ops(self: Change) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          added: Item => A,
+          removed: WitList[Sku] => A,
+          cleared: => A,
+          ok: Long => A
+      ): A = self.tag match {
+        case "added" => added(self.`val`.asInstanceOf[Item])
+        case "removed" => removed(self.`val`.asInstanceOf[WitList[Sku]])
+        case "cleared" => cleared
+        case "ok" => ok(self.`val`.asInstanceOf[Long])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Change")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case =
+        fold(Case.Added(_), Case.Removed(_), Case.Cleared, Case.Ok(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Change): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Change` */
+      def show: String = "Change." + Structural.show(self)
+    }
+
+    implicit val circe: io.circe.Codec[Change] = CirceCodecs.variant[Change](
+      CirceCodecs.Case("added", Some(Item.circe)),
+      CirceCodecs.Case("removed", Some(CirceCodecs.list(Sku.circe))),
+      CirceCodecs.Case("cleared", None),
+      CirceCodecs.Case("ok", Some(CirceCodecs.s64))
+    )
+  }
+
+  sealed trait Stocked extends js.Object {
+    val item: Item
+    val warehouse: Warehouse
+  }
+
+  object Stocked {
+    def apply(item: Item, warehouse: Warehouse): Stocked = {
+      val item0: Item = item
+      val warehouse0: Warehouse = warehouse
+
+      new Stocked {
+        val item: Item = item0
+        val warehouse: Warehouse = warehouse0
+      }
+    }
+
+    /** The structural methods of `Stocked`, which leave its JS representation untouched */
+    implicit final class Ops(self: Stocked) extends AnyVal {
+      /** A new `Stocked` with the given fields replaced */
+      def copy(
+          item: Item = self.item,
+          warehouse: Warehouse = self.warehouse
+      ): Stocked = Stocked(item, warehouse)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Stocked): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Stocked` */
+      def show: String = "Stocked" + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def apply(change: Change): Outcome
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:inventory/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("apply")
+    override def apply(change: Change): Outcome = registered.apply(change)
+  }
+
+  @js.native
+  @JSImport("golem:inventory/warehouses", "Warehouse")
+  class Warehouse(name: String) extends js.Object {
+    def capacity(): Integer = js.native
+  }
+
+  /** The circe codecs of the WIT types.
+    *
+    * Records are objects keyed by the WIT names of their fields, variants are objects with the WIT name
+    * of the case in `tag` and its payload, if any, in `val`, enums are the WIT names of their cases and
+    * flags are arrays of the WIT names of the flags that are set. Options are `null` when absent, which
+    * record fields may also be, results are objects with either an `ok` or an `err` member, errors being
+    * thrown as with their JS representation when decoded, tuples are arrays and chars are strings.
+    */
+  object CirceCodecs {
+    import io.circe.{ACursor, Codec, Decoder, DecodingFailure, Encoder, HCursor, Json}
+
+    /** A record field, with its JS name, its WIT name and the codec of its value */
+    final case class Field(name: String, label: String, codec: Codec[_])
+
+    /** A variant case, with its WIT name and the codec of its payload */
+    final case class Case(label: String, payload: Option[Codec[_]])
+
+    /** A flag, with its JS name and its WIT name */
+    final case class Flag(name: String, label: String)
+
+    private def sequence(
+        results: Seq[Decoder.Result[Any]]
+    ): Decoder.Result[List[Any]] =
+      results.foldRight[Decoder.Result[List[Any]]](Right(Nil))((result, rest) => result.flatMap(head => rest.map(head :: _)))
+
+    private def unsigned(n: Long, max: Long): Either[String, Long] =
+      if (n >= 0 && n <= max) Right(n) else Left(s"$n is out of range")
+
+    /** The codec of `bool` */
+    val bool: Codec[Boolean] =
+      Codec.from(Decoder.decodeBoolean, Encoder.encodeBoolean)
+
+    /** The codec of `s8` */
+    val s8: Codec[Byte] = Codec.from(Decoder.decodeByte, Encoder.encodeByte)
+
+    /** The codec of `u8` */
+    val u8: Codec[Byte] =
+      Codec.from(Decoder.decodeLong.emap(unsigned(_, 0xffL)).map(_.toByte), Encoder.instance[Byte](value => Json.fromInt(value & 0xff)))
+
+    /** The codec of `s16` */
+    val s16: Codec[Short] = Codec.from(Decoder.decodeShort, Encoder.encodeShort)
+
+    /** The codec of `u16` */
+    val u16: Codec[Short] =
+      Codec.from(Decoder.decodeLong.emap(unsigned(_, 0xffffL)).map(_.toShort), Encoder.instance[Short](value => Json.fromInt(value & 0xffff)))
+
+    /** The codec of `s32` */
+    val s32: Codec[Integer] =
+      Codec.from(Decoder.decodeJavaInteger, Encoder.encodeJavaInteger)
+
+    /** The codec of `u32` */
+    val u32: Codec[Integer] =
+      Codec.from(Decoder.decodeLong.emap(unsigned(_, 0xffffffffL)).map(n => Integer.valueOf(n.toInt)), Encoder.instance[Integer](value => Json.fromLong(Integer.toUnsignedLong(value))))
+
+    /** The codec of `s64` */
+    val s64: Codec[Long] = Codec.from(Decoder.decodeLong, Encoder.encodeLong)
+
+    /** The codec of `u64` */
+    val u64: Codec[Long] =
+      Codec.from(Decoder.decodeBigInt.emap(n => if (n >= 0 && n.bitLength <= 64) Right(n.longValue) else Left(s"$n is out of range")), Encoder.instance[Long](value => Json.fromBigInt(BigInt(java.lang.Long.toUnsignedString(value)))))
+
+    /** The codec of `f32` */
+    val f32: Codec[Float] = Codec.from(Decoder.decodeFloat, Encoder.encodeFloat)
+
+    /** The codec of `f64` */
+    val f64: Codec[Double] =
+      Codec.from(Decoder.decodeDouble, Encoder.encodeDouble)
+
+    /** The codec of `char` */
+    val char: Codec[Char] = Codec.from(Decoder.decodeChar, Encoder.encodeChar)
+
+    /** The codec of `string` */
+    val string: Codec[String] =
+      Codec.from(Decoder.decodeString, Encoder.encodeString)
+
+    /** The codec of `list<T>`, given the codec of `T` */
+    def list[A](item: Codec[A]): Codec[js.Array[A]] = new Codec[js.Array[A]] {
+      def apply(value: js.Array[A]): Json =
+        Json.fromValues(value.toList.map(element => item(element)))
+
+      def apply(cursor: HCursor): Decoder.Result[js.Array[A]] =
+        cursor.as(Decoder.decodeList(item)).map(_.toJSArray)
+    }
+
+    /** The codec of `option<T>`, record fields of which may be left out */
+    private final class OptionCodec[A](
+        some: Codec[A]
+    ) extends Codec[js.UndefOr[A]] {
+      def apply(value: js.UndefOr[A]): Json =
+        value.fold(Json.Null)(present => some(present))
+
+      def apply(cursor: HCursor): Decoder.Result[js.UndefOr[A]] =
+        if (cursor.value.isNull) Right(js.undefined) else some(cursor).map(value => value: js.UndefOr[A])
+
+      override def tryDecode(cursor: ACursor): Decoder.Result[js.UndefOr[A]] = cursor match {
+        case cursor: HCursor => apply(cursor)
+        case _ => Right(js.undefined)
+      }
+    }
+
+    /** The codec of `option<T>`, given the codec of `T` */
+    def option[A](some: Codec[A]): Codec[js.UndefOr[A]] = new OptionCodec(some)
+
+    /** The codec of `result<T, E>`, given the optional codecs of `T` and `E` */
+    def result[A, E](ok: Option[Codec[A]], err: Option[Codec[E]]): Codec[A] = new Codec[A] {
+      def apply(value: A): Json =
+        Json.obj("ok" -> ok.fold(Json.Null)(codec => codec(value)))
+
+      def apply(cursor: HCursor): Decoder.Result[A] = cursor.downField("err").success match {
+        case Some(error) =>
+          err.fold[Decoder.Result[Any]](Right(js.undefined))(error.as(_)).flatMap(error => throw js.JavaScriptException(error))
+        case None =>
+          ok.fold[Decoder.Result[A]](Right(().asInstanceOf[A]))(cursor.downField("ok").as(_))
+      }
+    }
+
+    /** The codec of a tuple, given the codecs of its items */
+    def tuple[A](items: Codec[_]*): Codec[A] = new Codec[A] {
+      def apply(value: A): Json =
+        Json.fromValues(items.zip(value.asInstanceOf[js.Array[Any]].toList).map { case (item, element) => item.asInstanceOf[Codec[Any]].apply(element) })
+
+      def apply(cursor: HCursor): Decoder.Result[A] =
+        sequence(items.zipWithIndex.map { case (item, i) => cursor.downN(i).as(item.asInstanceOf[Codec[Any]]) }).map(values => js.Array(values: _*).asInstanceOf[A])
+    }
+
+    /** The codec of a record, given its fields */
+    def record[A](fields: Field*): Codec[A] = new Codec[A] {
+      def apply(value: A): Json = {
+        val members = value.asInstanceOf[js.Dynamic]
+        Json.fromFields(fields.map(field => field.label -> field.codec.asInstanceOf[Codec[Any]].apply(members.selectDynamic(field.name))))
+      }
+
+      def apply(cursor: HCursor): Decoder.Result[A] =
+        sequence(fields.map(field => cursor.downField(field.label).as(field.codec.asInstanceOf[Codec[Any]]))).map(values => js.Dictionary(fields.map(_.name).zip(values): _*).asInstanceOf[A])
+    }
+
+    /** The codec of a variant, given its cases */
+    def variant[A](cases: Case*): Codec[A] = new Codec[A] {
+      def apply(value: A): Json = {
+        val variant = value.asInstanceOf[js.Dynamic]
+        val tag = variant.tag.asInstanceOf[String]
+
+        val payload =
+          cases.find(_.label == tag).flatMap(_.payload).map(payload => "val" -> payload.asInstanceOf[Codec[Any]].apply(variant.`val`))
+
+        Json.fromFields(("tag" -> Json.fromString(tag)) :: payload.toList)
+      }
+
+      def apply(cursor: HCursor): Decoder.Result[A] =
+        for { tag <- cursor.downField("tag").as[String]; payload <- cases.find(_.label == tag).map(_.payload).toRight(DecodingFailure(s"unknown case $tag", cursor.history)); value <- payload.fold[Decoder.Result[Any]](Right(js.undefined))(codec => cursor.downField("val").as(codec.asInstanceOf[Codec[Any]])) } yield js.Dictionary[Any]("tag" -> tag, "val" -> value).asInstanceOf[A]
+    }
+
+    /** The codec of an enum, given the WIT names of its cases */
+    def enumeration[A](cases: String*): Codec[A] = new Codec[A] {
+      def apply(value: A): Json = Json.fromString(value.asInstanceOf[String])
+
+      def apply(cursor: HCursor): Decoder.Result[A] =
+        cursor.as[String].flatMap(label => if (cases.contains(label)) Right(label.asInstanceOf[A]) else Left(DecodingFailure(s"unknown case $label", cursor.history)))
+    }
+
+    /** The codec of flags, given its flags */
+    def flags[A](flags: Flag*): Codec[A] = new Codec[A] {
+      def apply(value: A): Json = {
+        val members = value.asInstanceOf[js.Dictionary[Boolean]]
+        Json.fromValues(flags.filter(flag => members.getOrElse(flag.name, false)).map(flag => Json.fromString(flag.label)))
+      }
+
+      def apply(cursor: HCursor): Decoder.Result[A] =
+        cursor.as[List[String]].flatMap(set => set.find(label => !flags.exists(_.label == label)).map(label => DecodingFailure(s"unknown flag $label", cursor.history)).toLeft(js.Dictionary(flags.map(flag => flag.name -> set.contains(flag.label)): _*).asInstanceOf[A]))
+    }
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -0,0 +1,714 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type Sku = String
+
+  object Sku {
+    implicit val validator: Validation.Validator[Sku] = Validation.string
+  }
+
+  type Quantity = Integer
+
+  object Quantity {
+    implicit val validator: Validation.Validator[Quantity] = Validation.u32
+  }
+
+  type Outcome = WitResult[WitList[Item], String]
+
+  object Outcome {
+    implicit val validator: Validation.Validator[Outcome] =
+      Validation.result[WitList[Item], String](Some(Validation.list(Item.validator)), Some(Validation.string))
+  }
+}
+
+package tests {
+  @js.native
+  sealed trait Measure extends js.Any
+
+  object Measure {
+    val piece: Measure = "piece".asInstanceOf[Measure]
+    val kilogram: Measure = "kilogram".asInstanceOf[Measure]
+    val wave_: Measure = "wave".asInstanceOf[Measure]
+    val values: js.Array[Measure] = js.Array(piece, kilogram, wave_)
+
+    implicit val validator: Validation.Validator[Measure] =
+      Validation.enumeration[Measure]("piece", "kilogram", "wave")
+  }
+
+  sealed trait Tags extends js.Object {
+    val fragile: Boolean
+    val perishable: Boolean
+    val `true`: Boolean
+  }
+
+  object Tags {
+    def apply(
+        fragile: Boolean = false,
+        perishable: Boolean = false,
+        `true`: Boolean = false
+    ): Tags = {
+      val fragile0: Boolean = fragile
+      val perishable0: Boolean = perishable
+      val true0: Boolean = `true`
+
+      new Tags {
+        val fragile: Boolean = fragile0
+        val perishable: Boolean = perishable0
+        val `true`: Boolean = true0
+      }
+    }
+
+    /** The structural methods of `Tags`, which leave its JS representation untouched */
+    implicit final class Ops(self: Tags) extends AnyVal {
+      /** A new `Tags` with the given fields replaced */
+      def copy(
+          fragile: Boolean = self.fragile,
+          perishable: Boolean = self.perishable,
+          `true`: Boolean = self.`true`
+      ): Tags = Tags(fragile, perishable, `true`)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Tags): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Tags` */
+      def show: String = "Tags" + Structural.show(self)
+    }
+
+    implicit val validator: Validation.Validator[Tags] = Validation.flags[Tags](
+      Validation.Flag("fragile", "fragile"),
+      Validation.Flag("perishable", "perishable"),
+      Validation.Flag("true", "true")
+    )
+  }
+
+  sealed trait Item extends js.Object {
+    val sku: Sku
+    val name: String
+    val quantity: Quantity
+    val measure: Measure
+    val tags: Tags
+    val weight: WitOption[Double]
+    val dimensions: js.Tuple3[Short, Short, Short]
+    val initial: Char
+    val offset: Byte
+  }
+
+  object Item {
+    def apply(
+        sku: Sku,
+        name: String,
+        quantity: Quantity,
+        measure: Measure,
+        tags: Tags,
+        weight: WitOption[Double],
+        dimensions: js.Tuple3[Short, Short, Short],
+        initial: Char,
+        offset: Byte
+    ): Item = {
+      val sku0: Sku = sku
+      val name0: String = name
+      val quantity0: Quantity = quantity
+      val measure0: Measure = measure
+      val tags0: Tags = tags
+      val weight0: WitOption[Double] = weight
+      val dimensions0: js.Tuple3[Short, Short, Short] = dimensions
+      val initial0: Char = initial
+      val offset0: Byte = offset
+
+      new Item {
+        val sku: Sku = sku0
+        val name: String = name0
+        val quantity: Quantity = quantity0
+        val measure: Measure = measure0
+        val tags: Tags = tags0
+        val weight: WitOption[Double] = weight0
+        val dimensions: js.Tuple3[Short, Short, Short] = dimensions0
+        val initial: Char = initial0
+        val offset: Byte = offset0
+      }
+    }
+
+    /** The structural methods of `Item`, which leave its JS representation untouched */
+    implicit final class Ops(self: Item) extends AnyVal {
+      /** A new `Item` with the given fields replaced */
+      def copy(
+          sku: Sku = self.sku,
+          name: String = self.name,
+          quantity: Quantity = self.quantity,
+          measure: Measure = self.measure,
+          tags: Tags = self.tags,
+          weight: WitOption[Double] = self.weight,
+          dimensions: js.Tuple3[Short, Short, Short] = self.dimensions,
+          initial: Char = self.initial,
+          offset: Byte = self.offset
+      ): Item =
+        Item(sku, name, quantity, measure, tags, weight, dimensions, initial, offset)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Item): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Item` */
+      def show: String = "Item" + Structural.show(self)
+    }
+
+    implicit val validator: Validation.Validator[Item] =
+      Validation.record[Item](
+        Validation.Field("sku", "sku", Sku.validator),
+        Validation.Field("name", "name", Validation.string),
+        Validation.Field("quantity", "quantity", Quantity.validator),
+        Validation.Field("measure", "measure", Measure.validator),
+        Validation.Field("tags", "tags", Tags.validator),
+        Validation.Field("weight", "weight", Validation.option(Validation.f64)),
+        Validation.Field(
+          "dimensions",
+          "dimensions",
+          Validation.tuple[js.Tuple3[Short, Short, Short]](Validation.u16, Validation.u16, Validation.u16)
+        ),
+        Validation.Field("initial", "initial", Validation.char),
+        Validation.Field("offset", "offset", Validation.s8)
+      )
+  }
+
+  sealed trait Change extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Change {
+    def added(value: Item) = new Change {
+      type Type = Item
+      val tag: String = "added"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def removed(value: WitList[Sku]) = new Change {
+      type Type = WitList[Sku]
+      val tag: String = "removed"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val cleared = new Change {
+      type Type = Nothing
+      val tag: String = "cleared"
+    }
+
+    def ok(value: Long) = new Change {
+      type Type = Long
+      val tag: String = "ok"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `Change`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Added(value: Item) extends Case
+      final case class Removed(value: WitList[Sku]) extends Case
+      case object Cleared extends Case
+      final case class Ok(value: Long) extends Case
+    }
+
+    /** The pattern matching and structural methods of `Change` */
+    implicit final class Ops(self: Change) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          added: Item => A,
+          removed: WitList[Sku] => A,
+          cleared: => A,
+          ok: Long => A
+      ): A = self.tag match {
+        case "added" => added(self.`val`.asInstanceOf[Item])
+        case "removed" => removed(self.`val`.asInstanceOf[WitList[Sku]])
+        case "cleared" => cleared
+        case "ok" => ok(self.`val`.asInstanceOf[Long])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Change")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case =
+        fold(Case.Added(_), Case.Removed(_), Case.Cleared, Case.Ok(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Change): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Change` */
+      def show: String = "Change." + Structural.show(self)
+    }
+
+    implicit val validator: Validation.Validator[Change] =
+      Validation.variant[Change](
+        Validation.Case("added", Some(Item.validator)),
+        Validation.Case("removed", Some(Validation.list(Sku.validator))),
+        Validation.Case("cleared", None),
+        Validation.Case("ok", Some(Validation.s64))
+      )
+  }
+
+  sealed trait Stocked extends js.Object {
+    val item: Item
+    val warehouse: Warehouse
+  }
+
+  object Stocked {
+    def apply(item: Item, warehouse: Warehouse): Stocked = {
+      val item0: Item = item
+      val warehouse0: Warehouse = warehouse
+
+      new Stocked {
+        val item: Item = item0
+        val warehouse: Warehouse = warehouse0
+      }
+    }
+
+    /** The structural methods of `Stocked`, which leave its JS representation untouched */
+    implicit final class Ops(self: Stocked) extends AnyVal {
+      /** A new `Stocked` with the given fields replaced */
+      def copy(
+          item: Item = self.item,
+          warehouse: Warehouse = self.warehouse
+      ): Stocked = Stocked(item, warehouse)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Stocked): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Stocked` */
+      def show: String = "Stocked" + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def apply(change: Change): Outcome
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:inventory/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("apply")
+    override def apply(change: Change): Outcome =
+      Validation.results("apply", Outcome.validator)(registered.apply(change))
+  }
+
+  @js.native
+  @JSImport("golem:inventory/warehouses", "Warehouse")
+  class Warehouse(name: String) extends js.Object {
+    def capacity(): Integer = js.native
+  }
+
+  /** The validators of the JS representation of the WIT types.
+    *
+    * A validator returns the mismatches between a value and its WIT type, each of which
+    * starts with the WIT path of the mismatching value, such as `user.addresses[2].zip`.
+    * Record fields are selected by their WIT name, list items by their index in brackets,
+    * tuple items by their position, and variant payloads by the WIT name of their case.
+    * Unsigned integers are held in the Scala type of the same width as their two's
+    * complement, as the generators, WAVE and circe codecs do, so their validators accept the
+    * numbers in the unsigned range or in the range of their two's complement, both of which
+    * lower to the same WIT value. 64-bit integers are either `Long`s or `BigInt`s in that
+    * range. Results only check `ok` values, since errors are thrown rather than represented.
+    */
+  object Validation {
+    /** A check of the JS representation of a value of type `A`, returning the mismatches
+      * found under the WIT path `path`
+      */
+    trait Validator[A] {
+      def apply(value: Any, path: String): List[String]
+    }
+
+    object Validator {
+      /** A validator running `check` on the value and its WIT path */
+      def apply[A](check: (Any, String) => List[String]): Validator[A] = new Validator[A] {
+        def apply(value: Any, path: String): List[String] = check(value, path)
+      }
+    }
+
+    /** The error thrown when values crossing the component boundary do not fit their
+      * WIT types
+      */
+    final case class ValidationError(
+        context: String,
+        mismatches: List[String]
+    ) extends IllegalArgumentException(s"$context: ${mismatches.mkString("; ")}")
+
+    /** A record field, with its JS name, its WIT name and the validator of its value */
+    final case class Field(
+        name: String,
+        label: String,
+        validator: Validator[_]
+    )
+
+    /** A variant case, with its WIT name and the validator of its payload */
+    final case class Case(label: String, payload: Option[Validator[_]])
+
+    /** A flag, with its JS name and its WIT name */
+    final case class Flag(name: String, label: String)
+
+    /** A short description of a value in the mismatches */
+    private def describe(value: Any): String = value match {
+      case null => "null"
+      case text: String => "\"" + text + "\""
+      case _ if js.isUndefined(value) => "undefined"
+      case _ if js.Array.isArray(value) => "an array"
+      case _ if js.typeOf(value) == "object" => "an object"
+      case _ => value.toString
+    }
+
+    /** A validator checking `valid` on values, which are described as `expected` in the
+      * mismatches
+      */
+    private def expect[A](
+        expected: String
+    )(
+        valid: Any => Boolean
+    ): Validator[A] = Validator { (value, path) =>
+      if (valid(value))
+        Nil
+      else
+        List(s"$path: expected $expected, got ${describe(value)}")
+    }
+
+    /** Whether a value is a JS object that is not an array */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" && value != null && !js.Array.isArray(value)
+
+    /** Throws the mismatches, if any, as a `ValidationError` */
+    private def fail(context: String, mismatches: List[String]): Unit =
+      if (mismatches.nonEmpty) throw new ValidationError(context, mismatches)
+
+    /** Checks the arguments of `function`, given with their WIT name and their
+      * validator
+      */
+    def arguments(
+        function: String,
+        arguments: (String, Validator[_], Any)*
+    ): Unit = fail(
+      s"Invalid arguments of $function",
+      arguments.toList.flatMap { case (name, validator, value) =>
+        validator(value, name)
+      }
+    )
+
+    /** Checks the results of `function`, which are returned if they fit their WIT
+      * type
+      */
+    def results[A](function: String, validator: Validator[A])(value: A): A = {
+      fail(s"Invalid results of $function", validator(value, "result"))
+      value
+    }
+
+    /** The validator of `bool` */
+    val bool: Validator[Boolean] =
+      expect("a bool")(value => js.typeOf(value) == "boolean")
+
+    /** The validator of `s8` */
+    val s8: Validator[Byte] = expect("an integer between -128 and 127")(value =>
+      js.typeOf(value) == "number" &&
+        value.asInstanceOf[Double].isWhole &&
+        value.asInstanceOf[Double] >= -128d &&
+        value.asInstanceOf[Double] <= 127d
+    )
+
+    /** The validator of `u8` */
+    val u8: Validator[Byte] = expect("an integer between -128 and 255")(value =>
+      js.typeOf(value) == "number" &&
+        value.asInstanceOf[Double].isWhole &&
+        value.asInstanceOf[Double] >= -128d &&
+        value.asInstanceOf[Double] <= 255d
+    )
+
+    /** The validator of `s16` */
+    val s16: Validator[Short] =
+      expect("an integer between -32768 and 32767")(value =>
+        js.typeOf(value) == "number" &&
+          value.asInstanceOf[Double].isWhole &&
+          value.asInstanceOf[Double] >= -32768d &&
+          value.asInstanceOf[Double] <= 32767d
+      )
+
+    /** The validator of `u16` */
+    val u16: Validator[Short] =
+      expect("an integer between -32768 and 65535")(value =>
+        js.typeOf(value) == "number" &&
+          value.asInstanceOf[Double].isWhole &&
+          value.asInstanceOf[Double] >= -32768d &&
+          value.asInstanceOf[Double] <= 65535d
+      )
+
+    /** The validator of `s32` */
+    val s32: Validator[Integer] =
+      expect("an integer between -2147483648 and 2147483647")(value =>
+        js.typeOf(value) == "number" &&
+          value.asInstanceOf[Double].isWhole &&
+          value.asInstanceOf[Double] >= -2147483648d &&
+          value.asInstanceOf[Double] <= 2147483647d
+      )
+
+    /** The validator of `u32` */
+    val u32: Validator[Integer] =
+      expect("an integer between -2147483648 and 4294967295")(value =>
+        js.typeOf(value) == "number" &&
+          value.asInstanceOf[Double].isWhole &&
+          value.asInstanceOf[Double] >= -2147483648d &&
+          value.asInstanceOf[Double] <= 4294967295d
+      )
+
+    /** The validator of `s64` */
+    val s64: Validator[Long] =
+      expect("a Long or a BigInt between -9223372036854775808 and 9223372036854775807")(value =>
+        value.isInstanceOf[Long] ||
+          js.typeOf(value) == "bigint" &&
+            value.asInstanceOf[js.BigInt] >= js.BigInt("-9223372036854775808") &&
+            value.asInstanceOf[js.BigInt] <= js.BigInt("9223372036854775807")
+      )
+
+    /** The validator of `u64` */
+    val u64: Validator[Long] =
+      expect("a Long or a BigInt between -9223372036854775808 and 18446744073709551615")(value =>
+        value.isInstanceOf[Long] ||
+          js.typeOf(value) == "bigint" &&
+            value.asInstanceOf[js.BigInt] >= js.BigInt("-9223372036854775808") &&
+            value.asInstanceOf[js.BigInt] <= js.BigInt("18446744073709551615")
+      )
+
+    /** The validator of `f32` */
+    val f32: Validator[Float] = expect("an f32")(_.isInstanceOf[Float])
+
+    /** The validator of `f64` */
+    val f64: Validator[Double] =
+      expect("an f64")(value => js.typeOf(value) == "number")
+
+    /** The validator of `char` */
+    val char: Validator[Char] =
+      expect("a char that is not a surrogate")(value =>
+        value.isInstanceOf[Char] && !value.asInstanceOf[Char].isSurrogate
+      )
+
+    /** The validator of `string` */
+    val string: Validator[String] =
+      expect("a string")(value => js.typeOf(value) == "string")
+
+    /** The validator of `list<T>`, given the validator of `T` */
+    def list[A](item: Validator[A]): Validator[js.Array[A]] =
+      Validator { (value, path) =>
+        if (js.Array.isArray(value))
+          value.asInstanceOf[js.Array[Any]].toList.zipWithIndex.flatMap { case (element, i) =>
+            item(element, s"$path[$i]")
+          }
+        else
+          expect("a list")(_ => false)(value, path)
+      }
+
+    /** The validator of `option<T>`, given the validator of `T` */
+    def option[A](some: Validator[A]): Validator[js.UndefOr[A]] =
+      Validator { (value, path) =>
+        if (js.isUndefined(value)) Nil else some(value, path)
+      }
+
+    /** The validator of `result<T, E>`, given the optional validators of `T` and `E`, of which
+      * only `ok` values are checked
+      */
+    def result[A, E](
+        ok: Option[Validator[A]],
+        err: Option[Validator[E]]
+    ): Validator[A] =
+      Validator { (value, path) => ok.fold(List.empty[String])(_(value, path)) }
+
+    /** The validator of a tuple, given the validators of its items */
+    def tuple[A](items: Validator[_]*): Validator[A] =
+      Validator { (value, path) =>
+        if (js.Array.isArray(value) &&
+            value.asInstanceOf[js.Array[Any]].length == items.length)
+          items.toList.zip(value.asInstanceOf[js.Array[Any]].toList)
+            .zipWithIndex.flatMap { case ((item, element), i) =>
+              item(element, s"$path.$i")
+            }
+        else
+          expect(s"a tuple of ${items.length} items")(_ => false)(value, path)
+      }
+
+    /** The validator of a record, given its fields */
+    def record[A](fields: Field*): Validator[A] = Validator { (value, path) =>
+      if (isObject(value))
+        fields.toList.flatMap(field =>
+          field.validator(
+            value.asInstanceOf[js.Dynamic].selectDynamic(field.name),
+            s"$path.${field.label}"
+          )
+        )
+      else
+        expect("a record")(_ => false)(value, path)
+    }
+
+    /** The validator of a variant, given its cases */
+    def variant[A](cases: Case*): Validator[A] = Validator { (value, path) =>
+      if (isObject(value)) {
+        val variant = value.asInstanceOf[js.Dynamic]
+
+        cases.find(_.label == variant.tag.asInstanceOf[Any]) match {
+          case Some(Case(label, payload)) =>
+            payload.fold(List.empty[String])(_(variant.`val`, s"$path.$label"))
+          case None => List(s"$path: unknown case ${describe(variant.tag)}")
+        }
+      } else expect("a variant")(_ => false)(value, path)
+    }
+
+    /** The validator of an enum, given the WIT names of its cases */
+    def enumeration[A](cases: String*): Validator[A] =
+      Validator { (value, path) =>
+        if (cases.contains(value))
+          Nil
+        else
+          List(s"$path: unknown case ${describe(value)}")
+      }
+
+    /** The validator of flags, given its flags */
+    def flags[A](flags: Flag*): Validator[A] = Validator { (value, path) =>
+      if (isObject(value))
+        flags.toList.flatMap(flag =>
+          expect("a bool")(set =>
+            js.isUndefined(set) || js.typeOf(set) == "boolean"
+          )(
+            value.asInstanceOf[js.Dynamic].selectDynamic(flag.name),
+            s"$path.${flag.label}"
+          )
+        )
+      else
+        expect("flags")(_ => false)(value, path)
+    }
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -0,0 +1,176 @@
+// Generated by golem-scalajs-wit-bindgen
+package tests
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+/** The ScalaCheck generators of the WIT types.
+  *
+  * The values are built in their JS representation. Unsigned integers cover the range of their WIT
+  * type, wrapped into the Scala type of the same width, chars are the Unicode scalar values a `Char`
+  * holds, mostly ASCII, and strings are made of them. Results only generate `ok` values, since errors
+  * are thrown rather than represented.
+  *
+  * The generators of the generated types are implicit and named after them, such as `arbitraryItem`
+  * for `Item`, so that importing `Arbitraries._` brings them into scope.
+  */
+object Arbitraries {
+  import org.scalacheck.{Arbitrary, Gen}
+
+  /** A record field, with its JS name, its WIT name and the generator of its value */
+  final case class Field(name: String, label: String, arbitrary: Arbitrary[_])
+
+  /** A variant case, with its WIT name and the generator of its payload */
+  final case class Case(label: String, payload: Option[Arbitrary[_]])
+
+  /** A flag, with its JS name and its WIT name */
+  final case class Flag(name: String, label: String)
+
+  private def sequence(arbitraries: Seq[Arbitrary[_]]): Gen[List[Any]] =
+    Gen.sequence[List[Any], Any](arbitraries.map(_.arbitrary.asInstanceOf[Gen[Any]]))
+
+  /** The generator of `bool` */
+  val bool: Arbitrary[Boolean] = Arbitrary(Gen.oneOf(true, false))
+
+  /** The generator of `s8` */
+  val s8: Arbitrary[Byte] =
+    Arbitrary(Gen.chooseNum(Byte.MinValue, Byte.MaxValue))
+
+  /** The generator of `u8` */
+  val u8: Arbitrary[Byte] = Arbitrary(Gen.chooseNum(0, 0xff).map(_.toByte))
+
+  /** The generator of `s16` */
+  val s16: Arbitrary[Short] =
+    Arbitrary(Gen.chooseNum(Short.MinValue, Short.MaxValue))
+
+  /** The generator of `u16` */
+  val u16: Arbitrary[Short] = Arbitrary(Gen.chooseNum(0, 0xffff).map(_.toShort))
+
+  /** The generator of `s32` */
+  val s32: Arbitrary[Integer] =
+    Arbitrary(Gen.chooseNum(Int.MinValue, Int.MaxValue).map(n => Integer.valueOf(n)))
+
+  /** The generator of `u32` */
+  val u32: Arbitrary[Integer] =
+    Arbitrary(Gen.chooseNum(0L, 0xffffffffL).map(n => Integer.valueOf(n.toInt)))
+
+  /** The generator of `s64` */
+  val s64: Arbitrary[Long] =
+    Arbitrary(Gen.chooseNum(Long.MinValue, Long.MaxValue))
+
+  /** The generator of `u64` */
+  val u64: Arbitrary[Long] =
+    Arbitrary(Gen.chooseNum(BigInt(0), BigInt(2).pow(64) - 1).map(_.longValue))
+
+  /** The generator of `f32` */
+  val f32: Arbitrary[Float] = Arbitrary(Arbitrary.arbFloat.arbitrary)
+
+  /** The generator of `f64` */
+  val f64: Arbitrary[Double] = Arbitrary(Arbitrary.arbDouble.arbitrary)
+
+  /** The generator of `char` */
+  val char: Arbitrary[Char] =
+    Arbitrary(Gen.frequency(9 -> Gen.choose('\u0000', '\u007f'), 1 -> Gen.oneOf(Gen.choose('\u0080', '\ud7ff'), Gen.choose('\ue000', '\uffff'))))
+
+  /** The generator of `string` */
+  val string: Arbitrary[String] =
+    Arbitrary(Gen.listOf(char.arbitrary).map(_.mkString))
+
+  /** The generator of `list<T>`, given the generator of `T` */
+  def list[A](item: Arbitrary[A]): Arbitrary[js.Array[A]] =
+    Arbitrary(Gen.listOf(item.arbitrary).map(_.toJSArray))
+
+  /** The generator of `option<T>`, given the generator of `T` */
+  def option[A](some: Arbitrary[A]): Arbitrary[js.UndefOr[A]] =
+    Arbitrary(Gen.option(some.arbitrary).map(_.fold[js.UndefOr[A]](js.undefined)(value => value)))
+
+  /** The generator of `result<T, E>`, given the optional generators of `T` and `E`, of which only `ok` values are generated */
+  def result[A, E](
+      ok: Option[Arbitrary[A]],
+      err: Option[Arbitrary[E]]
+  ): Arbitrary[A] =
+    Arbitrary(ok.fold(Gen.const(().asInstanceOf[A]))(_.arbitrary))
+
+  /** The generator of a tuple, given the generators of its items */
+  def tuple[A](items: Arbitrary[_]*): Arbitrary[A] =
+    Arbitrary(sequence(items).map(values => js.Array(values: _*).asInstanceOf[A]))
+
+  /** The generator of a record, given its fields */
+  def record[A](fields: Field*): Arbitrary[A] =
+    Arbitrary(sequence(fields.map(_.arbitrary)).map(values => js.Dictionary(fields.map(_.name).zip(values): _*).asInstanceOf[A]))
+
+  /** The generator of a variant, given its cases */
+  def variant[A](cases: Case*): Arbitrary[A] =
+    Arbitrary(Gen.oneOf(cases).flatMap(chosen => chosen.payload.fold(Gen.const[Any](js.undefined))(_.arbitrary).map(value => js.Dictionary[Any]("tag" -> chosen.label, "val" -> value).asInstanceOf[A])))
+
+  /** The generator of an enum, given the WIT names of its cases */
+  def enumeration[A](cases: String*): Arbitrary[A] =
+    Arbitrary(Gen.oneOf(cases).map(_.asInstanceOf[A]))
+
+  /** The generator of flags, given its flags */
+  def flags[A](flags: Flag*): Arbitrary[A] =
+    Arbitrary(Gen.listOfN(flags.length, bool.arbitrary).map(set => js.Dictionary(flags.map(_.name).zip(set): _*).asInstanceOf[A]))
+
+  implicit lazy val arbitrarySku: org.scalacheck.Arbitrary[Sku] =
+    Arbitraries.string
+
+  implicit lazy val arbitraryQuantity: org.scalacheck.Arbitrary[Quantity] =
+    Arbitraries.u32
+
+  implicit lazy val arbitraryMeasure: org.scalacheck.Arbitrary[Measure] =
+    Arbitraries.enumeration[Measure]("piece", "kilogram", "wave")
+
+  implicit lazy val arbitraryTags: org.scalacheck.Arbitrary[Tags] =
+    Arbitraries.flags[Tags](
+      Arbitraries.Flag("fragile", "fragile"),
+      Arbitraries.Flag("perishable", "perishable"),
+      Arbitraries.Flag("true", "true")
+    )
+
+  implicit lazy val arbitraryItem: org.scalacheck.Arbitrary[Item] =
+    Arbitraries.record[Item](
+      Arbitraries.Field("sku", "sku", Arbitraries.arbitrarySku),
+      Arbitraries.Field("name", "name", Arbitraries.string),
+      Arbitraries.Field("quantity", "quantity", Arbitraries.arbitraryQuantity),
+      Arbitraries.Field("measure", "measure", Arbitraries.arbitraryMeasure),
+      Arbitraries.Field("tags", "tags", Arbitraries.arbitraryTags),
+      Arbitraries.Field(
+        "weight",
+        "weight",
+        Arbitraries.option(Arbitraries.f64)
+      ),
+      Arbitraries.Field(
+        "dimensions",
+        "dimensions",
+        Arbitraries.tuple[js.Tuple3[Short, Short, Short]](Arbitraries.u16, Arbitraries.u16, Arbitraries.u16)
+      ),
+      Arbitraries.Field("initial", "initial", Arbitraries.char),
+      Arbitraries.Field("offset", "offset", Arbitraries.s8)
+    )
+
+  implicit lazy val arbitraryChange: org.scalacheck.Arbitrary[Change] =
+    Arbitraries.variant[Change](
+      Arbitraries.Case("added", Some(Arbitraries.arbitraryItem)),
+      Arbitraries.Case(
+        "removed",
+        Some(Arbitraries.list(Arbitraries.arbitrarySku))
+      ),
+      Arbitraries.Case("cleared", None),
+      Arbitraries.Case("ok", Some(Arbitraries.s64))
+    )
+
+  implicit lazy val arbitraryOutcome: org.scalacheck.Arbitrary[Outcome] =
+    Arbitraries.result[WitList[Item], String](Some(Arbitraries.list(Arbitraries.arbitraryItem)), Some(Arbitraries.string))
+}
+
+/** The properties checking that the generated values pass the validators of their types */
+object ValidArbitraries extends org.scalacheck.Properties("ValidArbitraries") {
+  property("Sku") = org.scalacheck.Prop.forAll(Arbitraries.arbitrarySku.arbitrary)(value => org.scalacheck.Prop(Sku.validator(value, "value").isEmpty))
+  property("Quantity") = org.scalacheck.Prop.forAll(Arbitraries.arbitraryQuantity.arbitrary)(value => org.scalacheck.Prop(Quantity.validator(value, "value").isEmpty))
+  property("Measure") = org.scalacheck.Prop.forAll(Arbitraries.arbitraryMeasure.arbitrary)(value => org.scalacheck.Prop(Measure.validator(value, "value").isEmpty))
+  property("Tags") = org.scalacheck.Prop.forAll(Arbitraries.arbitraryTags.arbitrary)(value => org.scalacheck.Prop(Tags.validator(value, "value").isEmpty))
+  property("Item") = org.scalacheck.Prop.forAll(Arbitraries.arbitraryItem.arbitrary)(value => org.scalacheck.Prop(Item.validator(value, "value").isEmpty))
+  property("Change") = org.scalacheck.Prop.forAll(Arbitraries.arbitraryChange.arbitrary)(value => org.scalacheck.Prop(Change.validator(value, "value").isEmpty))
+  property("Outcome") = org.scalacheck.Prop.forAll(Arbitraries.arbitraryOutcome.arbitrary)(value => org.scalacheck.Prop(Outcome.validator(value, "value").isEmpty))
+}
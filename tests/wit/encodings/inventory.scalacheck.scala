@@ -0,0 +1,382 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type Sku = String
+  type Quantity = Integer
+  type Outcome = WitResult[WitList[Item], String]
+}
+
+package tests {
+  @js.native
+  sealed trait Measure extends js.Any
+
+  object Measure {
+    val piece: Measure = "piece".asInstanceOf[Measure]
+    val kilogram: Measure = "kilogram".asInstanceOf[Measure]
+    val wave_: Measure = "wave".asInstanceOf[Measure]
+    val values: js.Array[Measure] = js.Array(piece, kilogram, wave_)
+  }
+
+  sealed trait Tags extends js.Object {
+    val fragile: Boolean
+    val perishable: Boolean
+    val `true`: Boolean
+  }
+
+  object Tags {
+    def apply(
+        fragile: Boolean = false,
+        perishable: Boolean = false,
+        `true`: Boolean = false
+    ): Tags = {
+      val fragile0: Boolean = fragile
+      val perishable0: Boolean = perishable
+      val true0: Boolean = `true`
+
+      new Tags {
+        val fragile: Boolean = fragile0
+        val perishable: Boolean = perishable0
+        val `true`: Boolean = true0
+      }
+    }
+
+    /** The structural methods of `Tags`, which leave its JS representation untouched */
+    implicit final class Ops(self: Tags) extends AnyVal {
+      /** A new `Tags` with the given fields replaced */
+      def copy(
+          fragile: Boolean = self.fragile,
+          perishable: Boolean = self.perishable,
+          `true`: Boolean = self.`true`
+      ): Tags = Tags(fragile, perishable, `true`)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Tags): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Tags` */
+      def show: String = "Tags" + Structural.show(self)
+    }
+  }
+
+  sealed trait Item extends js.Object {
+    val sku: Sku
+    val name: String
+    val quantity: Quantity
+    val measure: Measure
+    val tags: Tags
+    val weight: WitOption[Double]
+    val dimensions: js.Tuple3[Short, Short, Short]
+    val initial: Char
+    val offset: Byte
+  }
+
+  object Item {
+    def apply(
+        sku: Sku,
+        name: String,
+        quantity: Quantity,
+        measure: Measure,
+        tags: Tags,
+        weight: WitOption[Double],
+        dimensions: js.Tuple3[Short, Short, Short],
+        initial: Char,
+        offset: Byte
+    ): Item = {
+      val sku0: Sku = sku
+      val name0: String = name
+      val quantity0: Quantity = quantity
+      val measure0: Measure = measure
+      val tags0: Tags = tags
+      val weight0: WitOption[Double] = weight
+      val dimensions0: js.Tuple3[Short, Short, Short] = dimensions
+      val initial0: Char = initial
+      val offset0: Byte = offset
+
+      new Item {
+        val sku: Sku = sku0
+        val name: String = name0
+        val quantity: Quantity = quantity0
+        val measure: Measure = measure0
+        val tags: Tags = tags0
+        val weight: WitOption[Double] = weight0
+        val dimensions: js.Tuple3[Short, Short, Short] = dimensions0
+        val initial: Char = initial0
+        val offset: Byte = offset0
+      }
+    }
+
+    /** The structural methods of `Item`, which leave its JS representation untouched */
+    implicit final class Ops(self: Item) extends AnyVal {
+      /** A new `Item` with the given fields replaced */
+      def copy(
+          sku: Sku = self.sku,
+          name: String = self.name,
+          quantity: Quantity = self.quantity,
+          measure: Measure = self.measure,
+          tags: Tags = self.tags,
+          weight: WitOption[Double] = self.weight,
+          dimensions: js.Tuple3[Short, Short, Short] = self.dimensions,
+          initial: Char = self.initial,
+          offset: Byte = self.offset
+      ): Item =
+        Item(sku, name, quantity, measure, tags, weight, dimensions, initial, offset)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Item): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Item` */
+      def show: String = "Item" + Structural.show(self)
+    }
+  }
+
+  sealed trait Change extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Change {
+    def added(value: Item) = new Change {
+      type Type = Item
+      val tag: String = "added"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def removed(value: WitList[Sku]) = new Change {
+      type Type = WitList[Sku]
+      val tag: String = "removed"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val cleared = new Change {
+      type Type = Nothing
+      val tag: String = "cleared"
+    }
+
+    def ok(value: Long) = new Change {
+      type Type = Long
+      val tag: String = "ok"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `Change`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Added(value: Item) extends Case
+      final case class Removed(value: WitList[Sku]) extends Case
+      case object Cleared extends Case
+      final case class Ok(value: Long) extends Case
+    }
+
+    /** The pattern matching and structural methods of `Change` */
+    implicit final class Ops(self: Change) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          added: Item => A,
+          removed: WitList[Sku] => A,
+          cleared: => A,
+          ok: Long => A
+      ): A = self.tag match {
+        case "added" => added(self.`val`.asInstanceOf[Item])
+        case "removed" => removed(self.`val`.asInstanceOf[WitList[Sku]])
+        case "cleared" => cleared
+        case "ok" => ok(self.`val`.asInstanceOf[Long])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Change")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case =
+        fold(Case.Added(_), Case.Removed(_), Case.Cleared, Case.Ok(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Change): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Change` */
+      def show: String = "Change." + Structural.show(self)
+    }
+  }
+
+  sealed trait Stocked extends js.Object {
+    val item: Item
+    val warehouse: Warehouse
+  }
+
+  object Stocked {
+    def apply(item: Item, warehouse: Warehouse): Stocked = {
+      val item0: Item = item
+      val warehouse0: Warehouse = warehouse
+
+      new Stocked {
+        val item: Item = item0
+        val warehouse: Warehouse = warehouse0
+      }
+    }
+
+    /** The structural methods of `Stocked`, which leave its JS representation untouched */
+    implicit final class Ops(self: Stocked) extends AnyVal {
+      /** A new `Stocked` with the given fields replaced */
+      def copy(
+          item: Item = self.item,
+          warehouse: Warehouse = self.warehouse
+      ): Stocked = Stocked(item, warehouse)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Stocked): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Stocked` */
+      def show: String = "Stocked" + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def apply(change: Change): Outcome
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:inventory/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("apply")
+    override def apply(change: Change): Outcome = registered.apply(change)
+  }
+
+  @js.native
+  @JSImport("golem:inventory/warehouses", "Warehouse")
+  class Warehouse(name: String) extends js.Object {
+    def capacity(): Integer = js.native
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -0,0 +1,822 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type Sku = String
+
+  object Sku {
+    implicit val wave: Wave[Sku] = Wave.string
+  }
+
+  type Quantity = Integer
+
+  object Quantity {
+    implicit val wave: Wave[Quantity] = Wave.u32
+  }
+
+  type Outcome = WitResult[WitList[Item], String]
+
+  object Outcome {
+    implicit val wave: Wave[Outcome] =
+      Wave.result[WitList[Item], String](Some(Wave.list(Item.wave)), Some(Wave.string))
+  }
+}
+
+package tests {
+  @js.native
+  sealed trait Measure extends js.Any
+
+  object Measure {
+    val piece: Measure = "piece".asInstanceOf[Measure]
+    val kilogram: Measure = "kilogram".asInstanceOf[Measure]
+    val wave_: Measure = "wave".asInstanceOf[Measure]
+    val values: js.Array[Measure] = js.Array(piece, kilogram, wave_)
+
+    implicit val wave: Wave[Measure] =
+      Wave.enumeration[Measure]("piece", "kilogram", "wave")
+  }
+
+  sealed trait Tags extends js.Object {
+    val fragile: Boolean
+    val perishable: Boolean
+    val `true`: Boolean
+  }
+
+  object Tags {
+    def apply(
+        fragile: Boolean = false,
+        perishable: Boolean = false,
+        `true`: Boolean = false
+    ): Tags = {
+      val fragile0: Boolean = fragile
+      val perishable0: Boolean = perishable
+      val true0: Boolean = `true`
+
+      new Tags {
+        val fragile: Boolean = fragile0
+        val perishable: Boolean = perishable0
+        val `true`: Boolean = true0
+      }
+    }
+
+    /** The structural methods of `Tags`, which leave its JS representation untouched */
+    implicit final class Ops(self: Tags) extends AnyVal {
+      /** A new `Tags` with the given fields replaced */
+      def copy(
+          fragile: Boolean = self.fragile,
+          perishable: Boolean = self.perishable,
+          `true`: Boolean = self.`true`
+      ): Tags = Tags(fragile, perishable, `true`)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Tags): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Tags` */
+      def show: String = "Tags" + Structural.show(self)
+    }
+
+    implicit val wave: Wave[Tags] = Wave.flags[Tags](
+      Wave.Flag("fragile", "fragile"),
+      Wave.Flag("perishable", "perishable"),
+      Wave.Flag("true", "true")
+    )
+  }
+
+  sealed trait Item extends js.Object {
+    val sku: Sku
+    val name: String
+    val quantity: Quantity
+    val measure: Measure
+    val tags: Tags
+    val weight: WitOption[Double]
+    val dimensions: js.Tuple3[Short, Short, Short]
+    val initial: Char
+    val offset: Byte
+  }
+
+  object Item {
+    def apply(
+        sku: Sku,
+        name: String,
+        quantity: Quantity,
+        measure: Measure,
+        tags: Tags,
+        weight: WitOption[Double],
+        dimensions: js.Tuple3[Short, Short, Short],
+        initial: Char,
+        offset: Byte
+    ): Item = {
+      val sku0: Sku = sku
+      val name0: String = name
+      val quantity0: Quantity = quantity
+      val measure0: Measure = measure
+      val tags0: Tags = tags
+      val weight0: WitOption[Double] = weight
+      val dimensions0: js.Tuple3[Short, Short, Short] = dimensions
+      val initial0: Char = initial
+      val offset0: Byte = offset
+
+      new Item {
+        val sku: Sku = sku0
+        val name: String = name0
+        val quantity: Quantity = quantity0
+        val measure: Measure = measure0
+        val tags: Tags = tags0
+        val weight: WitOption[Double] = weight0
+        val dimensions: js.Tuple3[Short, Short, Short] = dimensions0
+        val initial: Char = initial0
+        val offset: Byte = offset0
+      }
+    }
+
+    /** The structural methods of `Item`, which leave its JS representation untouched */
+    implicit final class Ops(self: Item) extends AnyVal {
+      /** A new `Item` with the given fields replaced */
+      def copy(
+          sku: Sku = self.sku,
+          name: String = self.name,
+          quantity: Quantity = self.quantity,
+          measure: Measure = self.measure,
+          tags: Tags = self.tags,
+          weight: WitOption[Double] = self.weight,
+          dimensions: js.Tuple3[Short, Short, Short] = self.dimensions,
+          initial: Char = self.initial,
+          offset: Byte = self.offset
+      ): Item =
+        Item(sku, name, quantity, measure, tags, weight, dimensions, initial, offset)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Item): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Item` */
+      def show: String = "Item" + Structural.show(self)
+    }
+
+    implicit val wave: Wave[Item] = Wave.record[Item](
+      Wave.Field("sku", "sku", Sku.wave),
+      Wave.Field("name", "name", Wave.string),
+      Wave.Field("quantity", "quantity", Quantity.wave),
+      Wave.Field("measure", "measure", Measure.wave),
+      Wave.Field("tags", "tags", Tags.wave),
+      Wave.Field("weight", "weight", Wave.option(Wave.f64)),
+      Wave.Field(
+        "dimensions",
+        "dimensions",
+        Wave.tuple[js.Tuple3[Short, Short, Short]](Wave.u16, Wave.u16, Wave.u16)
+      ),
+      Wave.Field("initial", "initial", Wave.char),
+      Wave.Field("offset", "offset", Wave.s8)
+    )
+  }
+
+  sealed trait Change extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Change {
+    def added(value: Item) = new Change {
+      type Type = Item
+      val tag: String = "added"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def removed(value: WitList[Sku]) = new Change {
+      type Type = WitList[Sku]
+      val tag: String = "removed"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val cleared = new Change {
+      type Type = Nothing
+      val tag: String = "cleared"
+    }
+
+    def ok(value: Long) = new Change {
+      type Type = Long
+      val tag: String = "ok"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `Change`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Added(value: Item) extends Case
+      final case class Removed(value: WitList[Sku]) extends Case
+      case object Cleared extends Case
+      final case class Ok(value: Long) extends Case
+    }
+
+    /** The pattern matching and structural methods of `Change` */
+    implicit final class Ops(self: Change) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          added: Item => A,
+          removed: WitList[Sku] => A,
+          cleared: => A,
+          ok: Long => A
+      ): A = self.tag match {
+        case "added" => added(self.`val`.asInstanceOf[Item])
+        case "removed" => removed(self.`val`.asInstanceOf[WitList[Sku]])
+        case "cleared" => cleared
+        case "ok" => ok(self.`val`.asInstanceOf[Long])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Change")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case =
+        fold(Case.Added(_), Case.Removed(_), Case.Cleared, Case.Ok(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Change): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Change` */
+      def show: String = "Change." + Structural.show(self)
+    }
+
+    implicit val wave: Wave[Change] = Wave.variant[Change](
+      Wave.Case("added", Some(Item.wave)),
+      Wave.Case("removed", Some(Wave.list(Sku.wave))),
+      Wave.Case("cleared", None),
+      Wave.Case("ok", Some(Wave.s64))
+    )
+  }
+
+  sealed trait Stocked extends js.Object {
+    val item: Item
+    val warehouse: Warehouse
+  }
+
+  object Stocked {
+    def apply(item: Item, warehouse: Warehouse): Stocked = {
+      val item0: Item = item
+      val warehouse0: Warehouse = warehouse
+
+      new Stocked {
+        val item: Item = item0
+        val warehouse: Warehouse = warehouse0
+      }
+    }
+
+    /** The structural methods of `Stocked`, which leave its JS representation untouched */
+    implicit final class Ops(self: Stocked) extends AnyVal {
+      /** A new `Stocked` with the given fields replaced */
+      def copy(
+          item: Item = self.item,
+          warehouse: Warehouse = self.warehouse
+      ): Stocked = Stocked(item, warehouse)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Stocked): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Stocked` */
+      def show: String = "Stocked" + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def apply(change: Change): Outcome
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:inventory/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("apply")
+    override def apply(change: Change): Outcome = registered.apply(change)
+  }
+
+  @js.native
+  @JSImport("golem:inventory/warehouses", "Warehouse")
+  class Warehouse(name: String) extends js.Object {
+    def capacity(): Integer = js.native
+  }
+
+  /** A printer and parser of the values of a WIT type in the WebAssembly Value Encoding (WAVE) */
+  trait Wave[A] {
+    /** Writes the WAVE of a value */
+    def write(value: A, out: StringBuilder): Unit
+
+    /** Reads a value from its WAVE */
+    def read(in: Wave.Reader): A
+
+    /** Prints a value in WAVE */
+    def print(value: A): String = {
+      val out = new StringBuilder
+      write(value, out)
+      out.toString
+    }
+
+    /** Parses a value from its WAVE, failing with a `Wave.ParseError` */
+    def parse(text: String): A = {
+      val in = new Wave.Reader(text)
+      val value = read(in)
+      in.end()
+      value
+    }
+  }
+
+  object Wave {
+    /** A WAVE that could not be parsed, with the offset at which parsing failed */
+    final case class ParseError(
+        message: String,
+        offset: Int
+    ) extends Exception(s"$message at offset $offset")
+
+    /** A record field, with its JS name, its WIT name and the codec of its value */
+    final case class Field(name: String, label: String, codec: Wave[_])
+
+    /** A variant case, with its WIT name and the codec of its payload */
+    final case class Case(label: String, payload: Option[Wave[_]])
+
+    /** A flag, with its JS name and its WIT name */
+    final case class Flag(name: String, label: String)
+
+    /** Reads the tokens of a WAVE, failing with a `ParseError` */
+    final class Reader(text: String) {
+      private var offset: Int = 0
+
+      /** Fails at the current offset */
+      def fail(message: String): Nothing = throw ParseError(message, offset)
+
+      private def skip(): Unit =
+        while (offset < text.length && text.charAt(offset).isWhitespace) offset += 1
+
+      /** Whether the next char after whitespace is `c` */
+      def peek(c: Char): Boolean = {
+        skip()
+        offset < text.length && text.charAt(offset) == c
+      }
+
+      /** Reads the next char if it is `c` */
+      def accept(c: Char): Boolean = peek(c) && { offset += 1; true }
+
+      /** Reads the next char, failing unless it is `c` */
+      def expect(c: Char): Unit = if (!accept(c)) fail(s"expected '$c'")
+
+      /** Reads a number or a label, such as `-1.5`, `nan` or `%true` */
+      def token(): String = {
+        skip()
+        val start = offset
+        while (offset < text.length && (text.charAt(offset).isLetterOrDigit || "-+.%".contains(text.charAt(offset)))) offset += 1
+        if (offset == start) fail("expected a value")
+        text.substring(start, offset)
+      }
+
+      /** Reads a label, without the `%` of the labels spelled like keywords */
+      def label(): String = token().stripPrefix("%")
+
+      /** Reads a string or a char delimited by `quote`, unescaping it */
+      def quoted(quote: Char): String = {
+        expect(quote)
+        val out = new StringBuilder
+        while (offset < text.length && text.charAt(offset) != quote) out.append(char())
+        expect(quote)
+        out.toString
+      }
+
+      private def char(): String = {
+        val c = text.charAt(offset)
+        offset += 1
+        if (c == '\\' && offset < text.length) escape() else c.toString
+      }
+
+      private def escape(): String = {
+        val c = text.charAt(offset)
+        offset += 1
+
+        c match {
+          case 't' => "\t"
+          case 'n' => "\n"
+          case 'r' => "\r"
+          case 'u' => unicode()
+          case _ => c.toString
+        }
+      }
+
+      private def unicode(): String = {
+        val end = text.indexOf('}', offset)
+        if (!accept('{') || end < 0) fail("invalid unicode escape")
+        val code = java.lang.Integer.parseInt(text.substring(offset, end), 16)
+        offset = end + 1
+        new String(Character.toChars(code))
+      }
+
+      /** Reads comma-separated items up to `close`, the opening delimiter being already read */
+      def items[A](close: Char)(item: => A): List[A] = {
+        val values = List.newBuilder[A]
+        while (!accept(close)) { values += item; if (!peek(close)) expect(',') }
+        values.result()
+      }
+
+      /** Reads comma-separated items between delimiters */
+      def sequence[A](open: Char, close: Char)(item: => A): List[A] = {
+        expect(open)
+        items(close)(item)
+      }
+
+      /** Reads the payload of a case, between parentheses */
+      def payload[A](codec: Wave[A]): A = {
+        expect('(')
+        val value = codec.read(this)
+        expect(')')
+        value
+      }
+
+      /** Fails unless the whole text was read */
+      def end(): Unit = {
+        skip()
+        if (offset < text.length) fail("unexpected input")
+      }
+    }
+
+    private val keywords: Set[String] =
+      Set("true", "false", "some", "none", "ok", "err", "inf", "nan")
+
+    private def writeLabel(label: String, out: StringBuilder): Unit =
+      out.append(if (keywords(label)) s"%$label" else label)
+
+    private def writeQuoted(
+        text: String,
+        quote: Char,
+        out: StringBuilder
+    ): Unit = {
+      out.append(quote)
+      text.foreach(c => out.append(escape(c, quote)))
+      out.append(quote)
+    }
+
+    private def escape(c: Char, quote: Char): String = c match {
+      case '\t' => "\\t"
+      case '\n' => "\\n"
+      case '\r' => "\\r"
+      case _ if c == '\\' || c == quote => s"\\$c"
+      case _ => c.toString
+    }
+
+    private def sequence[A](
+        items: Seq[A],
+        open: Char,
+        close: Char,
+        out: StringBuilder
+    )(
+        item: A => Unit
+    ): Unit = {
+      out.append(open)
+      items.zipWithIndex.foreach { case (value, i) => if (i > 0) out.append(", "); item(value) }
+      out.append(close)
+    }
+
+    private def writePayload[A](
+        codec: Wave[A],
+        value: A,
+        out: StringBuilder
+    ): Unit = {
+      out.append('(')
+      codec.write(value, out)
+      out.append(')')
+    }
+
+    private def primitive[A](
+        toToken: A => String,
+        fromToken: String => A
+    ): Wave[A] = new Wave[A] {
+      def write(value: A, out: StringBuilder): Unit = out.append(toToken(value))
+
+      def read(in: Reader): A = {
+        val token = in.token()
+        try fromToken(token) catch { case _: IllegalArgumentException => in.fail(s"invalid value '$token'") }
+      }
+    }
+
+    private def unsigned(token: String, bits: Int): Int = {
+      if (!token.forall(_.isDigit) || token.toInt >= 1 << bits) throw new NumberFormatException(token)
+      token.toInt
+    }
+
+    private def number(value: Double, text: => String): String =
+      if (value.isNaN) "nan" else if (value.isInfinite) (if (value > 0) "inf" else "-inf") else text
+
+    private def float(token: String): Double = token match {
+      case "nan" => Double.NaN
+      case "inf" => Double.PositiveInfinity
+      case "-inf" => Double.NegativeInfinity
+      case _ => token.toDouble
+    }
+
+    /** The codec of `bool` */
+    val bool: Wave[Boolean] =
+      primitive[Boolean](_.toString, token => if (token == "true") true else if (token == "false") false else throw new IllegalArgumentException(token))
+
+    /** The codec of `s8` */
+    val s8: Wave[Byte] = primitive[Byte](_.toString, _.toByte)
+
+    /** The codec of `u8` */
+    val u8: Wave[Byte] =
+      primitive[Byte](value => (value & 0xff).toString, unsigned(_, 8).toByte)
+
+    /** The codec of `s16` */
+    val s16: Wave[Short] = primitive[Short](_.toString, _.toShort)
+
+    /** The codec of `u16` */
+    val u16: Wave[Short] =
+      primitive[Short](value => (value & 0xffff).toString, unsigned(_, 16).toShort)
+
+    /** The codec of `s32` */
+    val s32: Wave[Integer] =
+      primitive[Integer](_.toString, token => token.toInt)
+
+    /** The codec of `u32` */
+    val u32: Wave[Integer] =
+      primitive[Integer](value => java.lang.Integer.toUnsignedString(value), token => java.lang.Integer.parseUnsignedInt(token))
+
+    /** The codec of `s64` */
+    val s64: Wave[Long] = primitive[Long](_.toString, _.toLong)
+
+    /** The codec of `u64` */
+    val u64: Wave[Long] =
+      primitive[Long](java.lang.Long.toUnsignedString(_), java.lang.Long.parseUnsignedLong(_))
+
+    /** The codec of `f32` */
+    val f32: Wave[Float] =
+      primitive[Float](value => number(value.toDouble, value.toString), float(_).toFloat)
+
+    /** The codec of `f64` */
+    val f64: Wave[Double] =
+      primitive[Double](value => number(value, value.toString), float(_))
+
+    /** The codec of `char` */
+    val char: Wave[Char] = new Wave[Char] {
+      def write(value: Char, out: StringBuilder): Unit =
+        writeQuoted(value.toString, '\'', out)
+
+      def read(in: Reader): Char = {
+        val text = in.quoted('\'')
+        if (text.length != 1) in.fail("expected a single char")
+        text.charAt(0)
+      }
+    }
+
+    /** The codec of `string` */
+    val string: Wave[String] = new Wave[String] {
+      def write(value: String, out: StringBuilder): Unit =
+        writeQuoted(value, '"', out)
+
+      def read(in: Reader): String = in.quoted('"')
+    }
+
+    /** The codec of `list<T>`, given the codec of `T` */
+    def list[A](item: Wave[A]): Wave[js.Array[A]] = new Wave[js.Array[A]] {
+      def write(value: js.Array[A], out: StringBuilder): Unit =
+        sequence(value.toList, '[', ']', out)(item.write(_, out))
+
+      def read(in: Reader): js.Array[A] =
+        in.sequence('[', ']')(item.read(in)).toJSArray
+    }
+
+    /** The codec of `option<T>`, record fields of which may be left out */
+    private final class OptionWave[A](
+        some: Wave[A]
+    ) extends Wave[js.UndefOr[A]] {
+      def write(value: js.UndefOr[A], out: StringBuilder): Unit =
+        value.fold[Unit](out.append("none"))(present => { out.append("some"); writePayload(some, present, out) })
+
+      def read(in: Reader): js.UndefOr[A] = in.label() match {
+        case "none" => js.undefined
+        case "some" => in.payload(some)
+        case other => in.fail(s"expected some or none, got $other")
+      }
+    }
+
+    /** The codec of `option<T>`, given the codec of `T` */
+    def option[A](some: Wave[A]): Wave[js.UndefOr[A]] = new OptionWave(some)
+
+    /** The codec of `result<T, E>`, given the optional codecs of `T` and `E`, errors being thrown as with their JS representation */
+    def result[A, E](ok: Option[Wave[A]], err: Option[Wave[E]]): Wave[A] = new Wave[A] {
+      def write(value: A, out: StringBuilder): Unit = {
+        out.append("ok")
+        ok.foreach(writePayload(_, value, out))
+      }
+
+      def read(in: Reader): A = in.label() match {
+        case "ok" => ok.fold(().asInstanceOf[A])(in.payload(_))
+        case "err" =>
+          throw js.JavaScriptException(err.fold[Any](js.undefined)(in.payload(_)))
+        case other => in.fail(s"expected ok or err, got $other")
+      }
+    }
+
+    /** The codec of a tuple, given the codecs of its items */
+    def tuple[A](items: Wave[_]*): Wave[A] = new Wave[A] {
+      def write(value: A, out: StringBuilder): Unit =
+        sequence(items.zip(value.asInstanceOf[js.Array[Any]].toList), '(', ')', out) { case (item, element) => item.asInstanceOf[Wave[Any]].write(element, out) }
+
+      def read(in: Reader): A = {
+        in.expect('(')
+
+        val values =
+          items.zipWithIndex.map { case (item, i) => if (i > 0) in.expect(','); item.asInstanceOf[Wave[Any]].read(in) }
+
+        in.accept(',')
+        in.expect(')')
+        js.Array(values: _*).asInstanceOf[A]
+      }
+    }
+
+    /** The codec of a record, given its fields */
+    def record[A](fields: Field*): Wave[A] = new Wave[A] {
+      def write(value: A, out: StringBuilder): Unit = {
+        val members = value.asInstanceOf[js.Dynamic]
+        if (fields.isEmpty) out.append("{:}") else sequence(fields, '{', '}', out) { field => writeLabel(field.label, out); out.append(": "); field.codec.asInstanceOf[Wave[Any]].write(members.selectDynamic(field.name), out) }
+      }
+
+      def read(in: Reader): A = {
+        val members = js.Dictionary.empty[Any]
+        in.expect('{')
+        if (in.accept(':')) in.expect('}') else in.items('}') { val label = in.label(); in.expect(':'); val field = fields.find(_.label == label).getOrElse(in.fail(s"unknown field $label")); members(field.name) = field.codec.read(in) }
+        fields.find(field => !members.contains(field.name) && !field.codec.isInstanceOf[OptionWave[_]]).foreach(field => in.fail(s"missing field ${field.label}"))
+        members.asInstanceOf[A]
+      }
+    }
+
+    /** The codec of a variant, given its cases */
+    def variant[A](cases: Case*): Wave[A] = new Wave[A] {
+      def write(value: A, out: StringBuilder): Unit = {
+        val variant = value.asInstanceOf[js.Dynamic]
+        val tag = variant.tag.asInstanceOf[String]
+        writeLabel(tag, out)
+        cases.find(_.label == tag).flatMap(_.payload).foreach(payload => writePayload(payload.asInstanceOf[Wave[Any]], variant.`val`, out))
+      }
+
+      def read(in: Reader): A = {
+        val tag = in.label()
+
+        val payload =
+          cases.find(_.label == tag).getOrElse(in.fail(s"unknown case $tag")).payload
+
+        js.Dictionary[Any]("tag" -> tag, "val" -> payload.fold[Any](js.undefined)(in.payload(_))).asInstanceOf[A]
+      }
+    }
+
+    /** The codec of an enum, given the WIT names of its cases */
+    def enumeration[A](cases: String*): Wave[A] = new Wave[A] {
+      def write(value: A, out: StringBuilder): Unit =
+        writeLabel(value.asInstanceOf[String], out)
+
+      def read(in: Reader): A = {
+        val label = in.label()
+        if (!cases.contains(label)) in.fail(s"unknown case $label")
+        label.asInstanceOf[A]
+      }
+    }
+
+    /** The codec of flags, given its flags */
+    def flags[A](flags: Flag*): Wave[A] = new Wave[A] {
+      def write(value: A, out: StringBuilder): Unit = {
+        val members = value.asInstanceOf[js.Dictionary[Boolean]]
+        sequence(flags.filter(flag => members.getOrElse(flag.name, false)), '{', '}', out)(flag => writeLabel(flag.label, out))
+      }
+
+      def read(in: Reader): A = {
+        val set = in.sequence('{', '}')(in.label())
+        set.find(label => !flags.exists(_.label == label)).foreach(label => in.fail(s"unknown flag $label"))
+        js.Dictionary(flags.map(flag => flag.name -> set.contains(flag.label)): _*).asInstanceOf[A]
+      }
+    }
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
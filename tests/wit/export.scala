@@ -0,0 +1,76 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
+
+package tests {
+  trait Api {
+    def getItemCount(): Integer
+    def `type`(implementation: String): String
+    def register(registered: String): Unit
+  }
+
+  /** Exports the implementation of `Api` registered with `register1` as `golem:shop/api@1.2.0` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation1: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register1(api: Api): Unit = implementation1 = Some(api)
+
+    private def registered1: Api = implementation1 match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("getItemCount")
+    override def getItemCount(): Integer = registered1.getItemCount()
+
+    @JSExport("type")
+    override def `type`(implementation: String): String =
+      registered1.`type`(implementation)
+
+    @JSExport("register")
+    override def register(registered: String): Unit =
+      registered1.register(registered)
+  }
+
+  @js.native
+  @JSImport("golem:shop/internal@1.2.0", JSImport.Namespace)
+  object Internal extends js.Object {
+    def ping(): Unit = js.native
+  }
+}
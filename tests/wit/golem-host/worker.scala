@@ -0,0 +1,688 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  /** An instant in time, in nanoseconds. An instant is relative to an
+    * unspecified initial value, and can only be compared to instances from
+    * the same monotonic-clock.
+    */
+  type Instant = Long
+
+  /** A duration of time, in nanoseconds. */
+  type Duration = Long
+
+  /** An index into the persistent log storing all performed operations of a worker */
+  type OplogIndex = Long
+
+  /** Represents a Golem component's version */
+  type ComponentVersion = Long
+}
+
+package tests {
+  trait Api {
+    def run(policy: RetryPolicy): Long
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:worker/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("run")
+    override def run(policy: RetryPolicy): Long = registered.run(policy)
+  }
+
+  /** `pollable` represents a single I/O event which may be ready, or not. */
+  @js.native
+  @JSImport("wasi:io/poll@0.2.0", "Pollable")
+  class Pollable extends js.Object {
+    /** Return the readiness of a pollable. This function never blocks.
+      *
+      * Returns `true` when the pollable is ready, and `false` otherwise.
+      *
+      * @return `bool`
+      */
+    def ready(): Boolean = js.native
+
+    /** `block` returns immediately if the pollable is ready, and otherwise
+      * blocks until ready.
+      *
+      * This function is equivalent to calling `poll.poll` on a list
+      * containing only this pollable.
+      */
+    def block(): Unit = js.native
+  }
+
+  /** A poll API intended to let users wait for I/O events on multiple handles
+    * at once.
+    */
+  @js.native
+  @JSImport("wasi:io/poll@0.2.0", JSImport.Namespace)
+  object Poll extends js.Object {
+    /** Poll for completion on a set of pollables.
+      *
+      * This function takes a list of pollables, which identify I/O sources of
+      * interest, and waits until one or more of the events is ready for I/O.
+      *
+      * The result `list<u32>` contains one or more indices of handles in the
+      * argument list that is ready for I/O.
+      *
+      * @param in `list<borrow<pollable>>`
+      * @return `list<u32>`
+      */
+    def poll(in: WitList[Pollable]): WitList[Integer] = js.native
+  }
+
+  /** WASI Monotonic Clock is a clock API intended to let users measure elapsed
+    * time.
+    *
+    * A monotonic clock is a clock which has an unspecified initial value, and
+    * successive reads of the clock will produce non-decreasing values.
+    */
+  @js.native
+  @JSImport("wasi:clocks/monotonic-clock@0.2.0", JSImport.Namespace)
+  object MonotonicClock extends js.Object {
+    /** Read the current value of the clock.
+      *
+      * The clock is monotonic, therefore calling this function repeatedly will
+      * produce a sequence of non-decreasing values.
+      *
+      * @return `instant`
+      */
+    def now(): Instant = js.native
+
+    /** Query the resolution of the clock. Returns the duration of time
+      * corresponding to a clock tick.
+      *
+      * @return `duration`
+      */
+    def resolution(): Duration = js.native
+
+    /** Create a `pollable` which will resolve once the specified instant
+      * has occurred.
+      *
+      * @param when `instant`
+      * @return `own<pollable>`
+      */
+    def subscribeInstant(when: Instant): Pollable = js.native
+
+    /** Create a `pollable` that will resolve after the specified duration has
+      * elapsed from the time this function is invoked.
+      *
+      * @param when `duration`
+      * @return `own<pollable>`
+      */
+    def subscribeDuration(when: Duration): Pollable = js.native
+  }
+
+  /** UUID */
+  sealed trait Uuid extends js.Object {
+    val highBits: Long
+    val lowBits: Long
+  }
+
+  object Uuid {
+    def apply(highBits: Long, lowBits: Long): Uuid = {
+      val highBits0: Long = highBits
+      val lowBits0: Long = lowBits
+
+      new Uuid {
+        val highBits: Long = highBits0
+        val lowBits: Long = lowBits0
+      }
+    }
+
+    /** The structural methods of `Uuid`, which leave its JS representation untouched */
+    implicit final class Ops(self: Uuid) extends AnyVal {
+      /** A new `Uuid` with the given fields replaced */
+      def copy(
+          highBits: Long = self.highBits,
+          lowBits: Long = self.lowBits
+      ): Uuid = Uuid(highBits, lowBits)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Uuid): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Uuid` */
+      def show: String = "Uuid" + Structural.show(self)
+    }
+  }
+
+  /** Represents a Golem component */
+  sealed trait ComponentId extends js.Object {
+    val uuid: Uuid
+  }
+
+  object ComponentId {
+    def apply(uuid: Uuid): ComponentId = {
+      val uuid0: Uuid = uuid
+
+      new ComponentId {
+        val uuid: Uuid = uuid0
+      }
+    }
+
+    /** The structural methods of `ComponentId`, which leave its JS representation untouched */
+    implicit final class Ops(self: ComponentId) extends AnyVal {
+      /** A new `ComponentId` with the given fields replaced */
+      def copy(uuid: Uuid = self.uuid): ComponentId = ComponentId(uuid)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: ComponentId): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `ComponentId` */
+      def show: String = "ComponentId" + Structural.show(self)
+    }
+  }
+
+  /** Represents a Golem worker */
+  sealed trait WorkerId extends js.Object {
+    val componentId: ComponentId
+    val workerName: String
+  }
+
+  object WorkerId {
+    def apply(componentId: ComponentId, workerName: String): WorkerId = {
+      val componentId0: ComponentId = componentId
+      val workerName0: String = workerName
+
+      new WorkerId {
+        val componentId: ComponentId = componentId0
+        val workerName: String = workerName0
+      }
+    }
+
+    /** The structural methods of `WorkerId`, which leave its JS representation untouched */
+    implicit final class Ops(self: WorkerId) extends AnyVal {
+      /** A new `WorkerId` with the given fields replaced */
+      def copy(
+          componentId: ComponentId = self.componentId,
+          workerName: String = self.workerName
+      ): WorkerId = WorkerId(componentId, workerName)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: WorkerId): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `WorkerId` */
+      def show: String = "WorkerId" + Structural.show(self)
+    }
+  }
+
+  /** A promise ID is a value that can be passed to an external Golem API to complete that promise
+    * from an arbitrary external source, while Golem workers can await for this completion.
+    */
+  sealed trait PromiseId extends js.Object {
+    val workerId: WorkerId
+    val oplogIdx: OplogIndex
+  }
+
+  object PromiseId {
+    def apply(workerId: WorkerId, oplogIdx: OplogIndex): PromiseId = {
+      val workerId0: WorkerId = workerId
+      val oplogIdx0: OplogIndex = oplogIdx
+
+      new PromiseId {
+        val workerId: WorkerId = workerId0
+        val oplogIdx: OplogIndex = oplogIdx0
+      }
+    }
+
+    /** The structural methods of `PromiseId`, which leave its JS representation untouched */
+    implicit final class Ops(self: PromiseId) extends AnyVal {
+      /** A new `PromiseId` with the given fields replaced */
+      def copy(
+          workerId: WorkerId = self.workerId,
+          oplogIdx: OplogIndex = self.oplogIdx
+      ): PromiseId = PromiseId(workerId, oplogIdx)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: PromiseId): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `PromiseId` */
+      def show: String = "PromiseId" + Structural.show(self)
+    }
+  }
+
+  /** Configures how the executor retries failures */
+  sealed trait RetryPolicy extends js.Object {
+    /** The maximum number of retries before the worker becomes permanently failed */
+    val maxAttempts: Integer
+
+    /** The minimum delay between retries (applied to the first retry) */
+    val minDelay: Duration
+
+    /** The maximum delay between retries */
+    val maxDelay: Duration
+
+    /** Multiplier applied to the delay on each retry to implement exponential backoff */
+    val multiplier: Double
+
+    /** The maximum amount of jitter to add to the delay */
+    val maxJitterFactor: WitOption[Double]
+  }
+
+  object RetryPolicy {
+    def apply(
+        maxAttempts: Integer,
+        minDelay: Duration,
+        maxDelay: Duration,
+        multiplier: Double,
+        maxJitterFactor: WitOption[Double]
+    ): RetryPolicy = {
+      val maxAttempts0: Integer = maxAttempts
+      val minDelay0: Duration = minDelay
+      val maxDelay0: Duration = maxDelay
+      val multiplier0: Double = multiplier
+      val maxJitterFactor0: WitOption[Double] = maxJitterFactor
+
+      new RetryPolicy {
+        val maxAttempts: Integer = maxAttempts0
+        val minDelay: Duration = minDelay0
+        val maxDelay: Duration = maxDelay0
+        val multiplier: Double = multiplier0
+        val maxJitterFactor: WitOption[Double] = maxJitterFactor0
+      }
+    }
+
+    /** The structural methods of `RetryPolicy`, which leave its JS representation untouched */
+    implicit final class Ops(self: RetryPolicy) extends AnyVal {
+      /** A new `RetryPolicy` with the given fields replaced */
+      def copy(
+          maxAttempts: Integer = self.maxAttempts,
+          minDelay: Duration = self.minDelay,
+          maxDelay: Duration = self.maxDelay,
+          multiplier: Double = self.multiplier,
+          maxJitterFactor: WitOption[Double] = self.maxJitterFactor
+      ): RetryPolicy =
+        RetryPolicy(maxAttempts, minDelay, maxDelay, multiplier, maxJitterFactor)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: RetryPolicy): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `RetryPolicy` */
+      def show: String = "RetryPolicy" + Structural.show(self)
+    }
+  }
+
+  /** Configurable persistence level for workers */
+  sealed trait PersistenceLevel extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object PersistenceLevel {
+    val persistNothing = new PersistenceLevel {
+      type Type = Nothing
+      val tag: String = "persist-nothing"
+    }
+
+    val persistRemoteSideEffects = new PersistenceLevel {
+      type Type = Nothing
+      val tag: String = "persist-remote-side-effects"
+    }
+
+    val smart = new PersistenceLevel {
+      type Type = Nothing
+      val tag: String = "smart"
+    }
+
+    /** A case of `PersistenceLevel`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object PersistNothing extends Case
+      case object PersistRemoteSideEffects extends Case
+      case object Smart extends Case
+    }
+
+    /** The pattern matching and structural methods of `PersistenceLevel` */
+    implicit final class Ops(self: PersistenceLevel) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          persistNothing: => A,
+          persistRemoteSideEffects: => A,
+          smart: => A
+      ): A = self.tag match {
+        case "persist-nothing" => persistNothing
+        case "persist-remote-side-effects" => persistRemoteSideEffects
+        case "smart" => smart
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of PersistenceLevel")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case =
+        fold(Case.PersistNothing, Case.PersistRemoteSideEffects, Case.Smart)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: PersistenceLevel): Boolean =
+        Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `PersistenceLevel` */
+      def show: String = "PersistenceLevel." + Structural.show(self)
+    }
+  }
+
+  /** Describes how to update a worker to a different component version */
+  @js.native
+  sealed trait UpdateMode extends js.Any
+
+  object UpdateMode {
+    /** Automatic update tries to recover the worker using the new component version
+      * and may fail if there is a divergence.
+      */
+    val automatic: UpdateMode = "automatic".asInstanceOf[UpdateMode]
+
+    /** Manual, snapshot-based update uses a user-defined implementation of the `save-snapshot` interface
+      * to store the worker's state, and a user-defined implementation of the `load-snapshot` interface to
+      * load it into the new version.
+      */
+    val snapshotBased: UpdateMode = "snapshot-based".asInstanceOf[UpdateMode]
+
+    val values: js.Array[UpdateMode] = js.Array(automatic, snapshotBased)
+  }
+
+  /** The Golem host API of Golem 1.0, available to every worker */
+  @js.native
+  @JSImport("golem:api/host@0.2.0", JSImport.Namespace)
+  object Host extends js.Object {
+    /** Create a new promise
+      *
+      * @return `promise-id`
+      */
+    def createPromise(): PromiseId = js.native
+
+    /** Suspends execution until the given promise gets completed, and returns the payload passed to
+      * the promise completion.
+      *
+      * @param promiseId `promise-id`
+      * @return `list<u8>`
+      */
+    def awaitPromise(promiseId: PromiseId): WitList[Byte] = js.native
+
+    /** Completes the given promise with the given payload. Returns true if the promise was completed, false
+      * if the promise was already completed. The payload is passed to the worker that is awaiting the promise.
+      *
+      * @param promiseId `promise-id`
+      * @param data `list<u8>`
+      * @return `bool`
+      */
+    def completePromise(promiseId: PromiseId, data: WitList[Byte]): Boolean =
+      js.native
+
+    /** Deletes the given promise
+      *
+      * @param promiseId `promise-id`
+      */
+    def deletePromise(promiseId: PromiseId): Unit = js.native
+
+    /** Returns the current position in the persistent op log
+      *
+      * @return `oplog-index`
+      */
+    def getOplogIndex(): OplogIndex = js.native
+
+    /** Makes the current worker travel back in time and continue execution from the given position in the persistent
+      * op log.
+      *
+      * @param oplogIdx `oplog-index`
+      */
+    def setOplogIndex(oplogIdx: OplogIndex): Unit = js.native
+
+    /** Blocks the execution until the oplog has been written to at least the specified number of replicas,
+      * or the maximum number of replicas if the requested number is higher.
+      *
+      * @param replicas `u8`
+      */
+    def oplogCommit(replicas: Byte): Unit = js.native
+
+    /** Marks the beginning of an atomic operation.
+      * In case of a failure within the region selected by `mark-begin-operation` and `mark-end-operation`
+      * the whole region will be reexecuted on retry.
+      * The end of the region is when `mark-end-operation` is called with the returned oplog-index.
+      *
+      * @return `oplog-index`
+      */
+    def markBeginOperation(): OplogIndex = js.native
+
+    /** Commits this atomic operation. After `mark-end-operation` is called for a given index, further calls
+      * with the same parameter will do nothing.
+      *
+      * @param begin `oplog-index`
+      */
+    def markEndOperation(begin: OplogIndex): Unit = js.native
+
+    /** Gets the current retry policy associated with the worker
+      *
+      * @return `retry-policy`
+      */
+    def getRetryPolicy(): RetryPolicy = js.native
+
+    /** Overrides the current retry policy associated with the worker. Following this call, `get-retry-policy` will return the
+      * new retry policy.
+      *
+      * @param newRetryPolicy `retry-policy`
+      */
+    def setRetryPolicy(newRetryPolicy: RetryPolicy): Unit = js.native
+
+    /** Gets the worker's current persistence level.
+      *
+      * @return `persistence-level`
+      */
+    def getOplogPersistenceLevel(): PersistenceLevel = js.native
+
+    /** Sets the worker's current persistence level. This can increase the performance of execution in cases where durable
+      * execution is not required.
+      *
+      * @param newPersistenceLevel `persistence-level`
+      */
+    def setOplogPersistenceLevel(newPersistenceLevel: PersistenceLevel): Unit =
+      js.native
+
+    /** Gets the current idempotence mode. See `set-idempotence-mode` for details.
+      *
+      * @return `bool`
+      */
+    def getIdempotenceMode(): Boolean = js.native
+
+    /** Sets the current idempotence mode. The default is true.
+      * True means side-effects are treated idempotent and Golem guarantees at-least-once semantics.
+      * In case of false the executor provides at-most-once semantics, failing the worker in case it is
+      * not known if the side effect was already executed.
+      *
+      * @param idempotent `bool`
+      */
+    def setIdempotenceMode(idempotent: Boolean): Unit = js.native
+
+    /** Generates an idempotency key. This operation will never be replayed —
+      * i.e. not only is this key generated, but it is persisted and committed, such that the key can be used in third-party systems (e.g. payment processing)
+      * to introduce idempotence.
+      *
+      * @return `uuid`
+      */
+    def generateIdempotencyKey(): Uuid = js.native
+
+    /** Initiates an update attempt for the given worker. The function returns immediately once the request has been processed,
+      * not waiting for the worker to get updated.
+      *
+      * @param workerId `worker-id`
+      * @param targetVersion `component-version`
+      * @param mode `update-mode`
+      */
+    def updateWorker(
+        workerId: WorkerId,
+        targetVersion: ComponentVersion,
+        mode: UpdateMode
+    ): Unit = js.native
+  }
+
+  /** Idiomatic wrappers around the host API of Golem 1.0 */
+  object Golem {
+    /** Runs `body` with the given persistence level, restoring the previous one afterwards */
+    def withPersistenceLevel[A](level: PersistenceLevel)(body: => A): A = {
+      val previous = Host.getOplogPersistenceLevel()
+      Host.setOplogPersistenceLevel(level)
+      try body finally Host.setOplogPersistenceLevel(previous)
+    }
+
+    /** Runs `body` with side effects treated as idempotent or not, restoring the previous mode afterwards */
+    def withIdempotenceMode[A](idempotent: Boolean)(body: => A): A = {
+      val previous = Host.getIdempotenceMode()
+      Host.setIdempotenceMode(idempotent)
+      try body finally Host.setIdempotenceMode(previous)
+    }
+
+    /** Runs `body` with the given retry policy, restoring the previous one afterwards */
+    def withRetryPolicy[A](policy: RetryPolicy)(body: => A): A = {
+      val previous = Host.getRetryPolicy()
+      Host.setRetryPolicy(policy)
+      try body finally Host.setRetryPolicy(previous)
+    }
+
+    /** Runs `body` as an atomic operation, which is retried as a whole on failure */
+    def atomically[A](body: => A): A = {
+      val begin: OplogIndex = Host.markBeginOperation()
+      val result = body
+      Host.markEndOperation(begin)
+      result
+    }
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
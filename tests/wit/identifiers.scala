@@ -0,0 +1,322 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
+
+package tests {
+  sealed trait Reserved extends js.Object {
+    val `type`: Integer
+    val `val`: String
+    val `object`: Boolean
+    val `match`: Byte
+    val `yield`: Long
+  }
+
+  object Reserved {
+    def apply(
+        `type`: Integer,
+        `val`: String,
+        `object`: Boolean,
+        `match`: Byte,
+        `yield`: Long
+    ): Reserved = {
+      val type0: Integer = `type`
+      val val0: String = `val`
+      val object0: Boolean = `object`
+      val match0: Byte = `match`
+      val yield0: Long = `yield`
+
+      new Reserved {
+        val `type`: Integer = type0
+        val `val`: String = val0
+        val `object`: Boolean = object0
+        val `match`: Byte = match0
+        val `yield`: Long = yield0
+      }
+    }
+
+    /** The structural methods of `Reserved`, which leave its JS representation untouched */
+    implicit final class Ops(self: Reserved) extends AnyVal {
+      /** A new `Reserved` with the given fields replaced */
+      def copy(
+          `type`: Integer = self.`type`,
+          `val`: String = self.`val`,
+          `object`: Boolean = self.`object`,
+          `match`: Byte = self.`match`,
+          `yield`: Long = self.`yield`
+      ): Reserved = Reserved(`type`, `val`, `object`, `match`, `yield`)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Reserved): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Reserved` */
+      def show: String = "Reserved" + Structural.show(self)
+    }
+  }
+
+  sealed trait WitResult_ extends js.Object {
+    val value: Integer
+  }
+
+  object WitResult_ {
+    def apply(value: Integer): WitResult_ = {
+      val value0: Integer = value
+
+      new WitResult_ {
+        val value: Integer = value0
+      }
+    }
+
+    /** The structural methods of `WitResult_`, which leave its JS representation untouched */
+    implicit final class Ops(self: WitResult_) extends AnyVal {
+      /** A new `WitResult_` with the given fields replaced */
+      def copy(value: Integer = self.value): WitResult_ = WitResult_(value)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: WitResult_): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `WitResult_` */
+      def show: String = "WitResult_" + Structural.show(self)
+    }
+  }
+
+  sealed trait Shadowing extends js.Object {
+    val a: Integer
+    val a0: Integer
+    val a1: String
+  }
+
+  object Shadowing {
+    def apply(a: Integer, a0: Integer, a1: String): Shadowing = {
+      val a01: Integer = a
+      val a00: Integer = a0
+      val a10: String = a1
+
+      new Shadowing {
+        val a: Integer = a01
+        val a0: Integer = a00
+        val a1: String = a10
+      }
+    }
+
+    /** The structural methods of `Shadowing`, which leave its JS representation untouched */
+    implicit final class Ops(self: Shadowing) extends AnyVal {
+      /** A new `Shadowing` with the given fields replaced */
+      def copy(
+          a: Integer = self.a,
+          a0: Integer = self.a0,
+          a1: String = self.a1
+      ): Shadowing = Shadowing(a, a0, a1)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Shadowing): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Shadowing` */
+      def show: String = "Shadowing" + Structural.show(self)
+    }
+  }
+
+  sealed trait KeywordCases extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object KeywordCases {
+    def tag_(value1: Integer) = new KeywordCases {
+      type Type = Integer
+      val tag: String = "tag"
+      override val `val`: js.UndefOr[Type] = value1
+    }
+
+    val val_ = new KeywordCases {
+      type Type = Nothing
+      val tag: String = "val"
+    }
+
+    def `type`(value1: String) = new KeywordCases {
+      type Type = String
+      val tag: String = "type"
+      override val `val`: js.UndefOr[Type] = value1
+    }
+
+    val multiWord = new KeywordCases {
+      type Type = Nothing
+      val tag: String = "multi-word"
+    }
+
+    def value(value1: Integer) = new KeywordCases {
+      type Type = Integer
+      val tag: String = "value"
+      override val `val`: js.UndefOr[Type] = value1
+    }
+
+    /** A case of `KeywordCases`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Tag(value: Integer) extends Case
+      case object Val extends Case
+      final case class Type(value: String) extends Case
+      case object MultiWord extends Case
+      final case class Value(value: Integer) extends Case
+    }
+
+    /** The pattern matching and structural methods of `KeywordCases` */
+    implicit final class Ops(self: KeywordCases) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          tag_: Integer => A,
+          val_: => A,
+          `type`: String => A,
+          multiWord: => A,
+          value: Integer => A
+      ): A = self.tag match {
+        case "tag" => tag_(self.`val`.asInstanceOf[Integer])
+        case "val" => val_
+        case "type" => `type`(self.`val`.asInstanceOf[String])
+        case "multi-word" => multiWord
+        case "value" => value(self.`val`.asInstanceOf[Integer])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of KeywordCases")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case =
+        fold(Case.Tag(_), Case.Val, Case.Type(_), Case.MultiWord, Case.Value(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: KeywordCases): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `KeywordCases` */
+      def show: String = "KeywordCases." + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def `new`(`type`: Integer, `val`: String): Reserved
+    def `yield`(`this`: KeywordCases): WitResult_
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
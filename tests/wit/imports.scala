@@ -0,0 +1,259 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
+
+package tests {
+  trait Api {
+    def reserve(sku: String, amount: Integer): Boolean
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:inventory/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("reserve")
+    override def reserve(sku: String, amount: Integer): Boolean =
+      registered.reserve(sku, amount)
+  }
+
+  /** The stock level of an item */
+  sealed trait Level extends js.Object {
+    val sku: String
+    val available: Integer
+  }
+
+  object Level {
+    def apply(sku: String, available: Integer): Level = {
+      val sku0: String = sku
+      val available0: Integer = available
+
+      new Level {
+        val sku: String = sku0
+        val available: Integer = available0
+      }
+    }
+
+    /** The structural methods of `Level`, which leave its JS representation untouched */
+    implicit final class Ops(self: Level) extends AnyVal {
+      /** A new `Level` with the given fields replaced */
+      def copy(
+          sku: String = self.sku,
+          available: Integer = self.available
+      ): Level = Level(sku, available)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Level): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Level` */
+      def show: String = "Level" + Structural.show(self)
+    }
+  }
+
+  @js.native
+  sealed trait Warehouse extends js.Any
+
+  object Warehouse {
+    val north: Warehouse = "north".asInstanceOf[Warehouse]
+    val south: Warehouse = "south".asInstanceOf[Warehouse]
+    val values: js.Array[Warehouse] = js.Array(north, south)
+  }
+
+  /** The results of `shelf.bounds` */
+  sealed trait ShelfBoundsResult extends js.Object {
+    val lowest: Integer
+    val highest: Integer
+  }
+
+  object ShelfBoundsResult {
+    def apply(lowest: Integer, highest: Integer): ShelfBoundsResult = {
+      val lowest0: Integer = lowest
+      val highest0: Integer = highest
+
+      new ShelfBoundsResult {
+        val lowest: Integer = lowest0
+        val highest: Integer = highest0
+      }
+    }
+
+    /** The structural methods of `ShelfBoundsResult`, which leave its JS representation untouched */
+    implicit final class Ops(self: ShelfBoundsResult) extends AnyVal {
+      /** A new `ShelfBoundsResult` with the given fields replaced */
+      def copy(
+          lowest: Integer = self.lowest,
+          highest: Integer = self.highest
+      ): ShelfBoundsResult = ShelfBoundsResult(lowest, highest)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: ShelfBoundsResult): Boolean =
+        Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `ShelfBoundsResult` */
+      def show: String = "ShelfBoundsResult" + Structural.show(self)
+    }
+  }
+
+  @js.native
+  @JSImport("golem:inventory/stock", "Shelf")
+  class Shelf(warehouse: Warehouse) extends js.Object {
+    /** The lowest and highest stock levels on the shelf
+      *
+      * @return `(lowest: u32, highest: u32)`
+      */
+    def bounds(): ShelfBoundsResult = js.native
+  }
+
+  /** Another component keeping track of stock levels */
+  @js.native
+  @JSImport("golem:inventory/stock", JSImport.Namespace)
+  object Stock extends js.Object {
+    /** Returns the stock level of an item in a warehouse
+      *
+      * @param sku `string`
+      * @param warehouse `warehouse`
+      * @return `level`
+      */
+    def getLevel(sku: String, warehouse: Warehouse): Level = js.native
+
+    def restock(level: Level): Unit = js.native
+
+    /** The stock levels of the given items, in any warehouse unless one is given
+      *
+      * @param skus `list<string>`
+      * @param warehouse `option<warehouse>`
+      * @return `result<list<level>, string>`
+      */
+    def getLevels(
+        skus: WitList[String],
+        warehouse: WitOption[Warehouse]
+    ): WitResult[WitList[Level], String] = js.native
+
+    def getLabel(sku: String): WitOption[WitList[Byte]] = js.native
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
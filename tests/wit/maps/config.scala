@@ -0,0 +1,297 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  /** The labels of a service, edited in place */
+  type Labels = WitList[js.Tuple2[String, String]]
+}
+
+package tests {
+  sealed trait Limit extends js.Object {
+    val cpu: Integer
+    val memory: Long
+  }
+
+  object Limit {
+    def apply(cpu: Integer, memory: Long): Limit = {
+      val cpu0: Integer = cpu
+      val memory0: Long = memory
+
+      new Limit {
+        val cpu: Integer = cpu0
+        val memory: Long = memory0
+      }
+    }
+
+    /** The structural methods of `Limit`, which leave its JS representation untouched */
+    implicit final class Ops(self: Limit) extends AnyVal {
+      /** A new `Limit` with the given fields replaced */
+      def copy(cpu: Integer = self.cpu, memory: Long = self.memory): Limit =
+        Limit(cpu, memory)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Limit): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Limit` */
+      def show: String = "Limit" + Structural.show(self)
+    }
+  }
+
+  sealed trait Service extends js.Object {
+    val name: String
+    val labels: Labels
+    val limits: WitList[js.Tuple2[String, Limit]]
+    val ports: WitList[js.Tuple2[Short, String]]
+  }
+
+  object Service {
+    def apply(
+        name: String,
+        labels: Labels,
+        limits: WitList[js.Tuple2[String, Limit]],
+        ports: WitList[js.Tuple2[Short, String]]
+    ): Service = {
+      val name0: String = name
+      val labels0: Labels = labels
+      val limits0: WitList[js.Tuple2[String, Limit]] = limits
+      val ports0: WitList[js.Tuple2[Short, String]] = ports
+
+      new Service {
+        val name: String = name0
+        val labels: Labels = labels0
+        val limits: WitList[js.Tuple2[String, Limit]] = limits0
+        val ports: WitList[js.Tuple2[Short, String]] = ports0
+      }
+    }
+
+    /** The structural methods of `Service`, which leave its JS representation untouched */
+    implicit final class Ops(self: Service) extends AnyVal {
+      /** A new `Service` with the given fields replaced */
+      def copy(
+          name: String = self.name,
+          labels: Labels = self.labels,
+          limits: WitList[js.Tuple2[String, Limit]] = self.limits,
+          ports: WitList[js.Tuple2[Short, String]] = self.ports
+      ): Service = Service(name, labels, limits, ports)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Service): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Service` */
+      def show: String = "Service" + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    /** The settings of the services, by environment and name
+      *
+      * @return `list<tuple<string, list<tuple<string, option<s32>>>>>`
+      */
+    def getSettings(): WitList[js.Tuple2[String, WitList[js.Tuple2[String, WitOption[Integer]]]]]
+
+    def deploy(
+        services: WitList[js.Tuple2[String, Service]],
+        env: WitList[js.Tuple2[String, String]]
+    ): WitResult[Labels, String]
+  }
+
+  /** The idiomatic counterparts of the types of `Api`, in which the implementation registered with `ApiExport` is written */
+  object Idiomatic {
+    /** The labels of a service, edited in place */
+    type Labels = js.Dictionary[String]
+
+    object Labels {
+      /** Converts `Labels` to its JS representation */
+      def toJS(value: Labels): _root_.tests.Labels =
+        value.toList.map(entry => js.Tuple2(entry._1, entry._2)).toJSArray
+
+      /** Converts the JS representation of `Labels` */
+      def fromJS(value: _root_.tests.Labels): Labels =
+        js.Dictionary(value.toList.map(entry => entry._1 -> entry._2): _*)
+    }
+
+    final case class Limit(cpu: Int, memory: Long)
+
+    object Limit {
+      /** Converts `Limit` to its JS representation */
+      def toJS(value: Limit): _root_.tests.Limit =
+        _root_.tests.Limit(Int.box(value.cpu), value.memory)
+
+      /** Converts the JS representation of `Limit` */
+      def fromJS(value: _root_.tests.Limit): Limit =
+        Limit(Int.unbox(value.cpu), value.memory)
+    }
+
+    final case class Service(
+        name: String,
+        labels: Labels,
+        limits: Map[String, Limit],
+        ports: List[(Short, String)]
+    )
+
+    object Service {
+      /** Converts `Service` to its JS representation */
+      def toJS(value: Service): _root_.tests.Service =
+        _root_.tests.Service(value.name, Labels.toJS(value.labels), value.limits.toList.map(entry => js.Tuple2(entry._1, Limit.toJS(entry._2))).toJSArray, value.ports.map(item => { val tuple1 = item; js.Tuple2(tuple1._1, tuple1._2) }).toJSArray)
+
+      /** Converts the JS representation of `Service` */
+      def fromJS(value: _root_.tests.Service): Service =
+        Service(value.name, Labels.fromJS(value.labels), value.limits.toList.map(entry => entry._1 -> Limit.fromJS(entry._2)).toMap, value.ports.toList.map(item => { val tuple1 = item; (tuple1._1, tuple1._2) }))
+    }
+
+    trait Api {
+      /** The settings of the services, by environment and name
+        *
+        * @return `list<tuple<string, list<tuple<string, option<s32>>>>>`
+        */
+      def getSettings(): Map[String, Map[String, Option[Int]]]
+
+      def deploy(
+          services: Map[String, Service],
+          env: Map[String, String]
+      ): Either[String, Labels]
+    }
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:config/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Idiomatic.Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Idiomatic.Api): Unit = implementation = Some(api)
+
+    private def registered: Idiomatic.Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("getSettings")
+    override def getSettings(): WitList[js.Tuple2[String, WitList[js.Tuple2[String, WitOption[Integer]]]]] =
+      registered.getSettings().toList.map(entry => js.Tuple2(entry._1, entry._2.toList.map(entry1 => js.Tuple2(entry1._1, entry1._2.map(item2 => Int.box(item2)).orUndefined)).toJSArray)).toJSArray
+
+    @JSExport("deploy")
+    override def deploy(
+        services: WitList[js.Tuple2[String, Service]],
+        env: WitList[js.Tuple2[String, String]]
+    ): WitResult[Labels, String] =
+      registered.deploy(services.toList.map(entry => entry._1 -> Idiomatic.Service.fromJS(entry._2)).toMap, env.toList.map(entry => entry._1 -> entry._2).toMap) match { case Right(ok) => Idiomatic.Labels.toJS(ok); case Left(err) => throw js.JavaScriptException(err) }
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
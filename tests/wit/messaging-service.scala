@@ -1,95 +1,304 @@
+// Generated by golem-scalajs-wit-bindgen
 
-                // Generated by golem-scalajs-wit-bindgen
-                package tests
-
-                import scala.scalajs.js
-                import scala.scalajs.js.JSConverters._
-
-                
-                sealed trait Message extends js.Object {
-                    val messageId: Integer
-val userId: Integer
-val content: String
-val channel: MessageChannel
-val status: MessageStatus
-                }
-                object Message {
-                    def apply(messageId: Integer, userId: Integer, content: String, channel: MessageChannel, status: MessageStatus): Message = {
-                        val messageId0: Integer = messageId
-val userId0: Integer = userId
-val content0: String = content
-val channel0: MessageChannel = channel
-val status0: MessageStatus = status
-
-                        new Message {
-                            val messageId: Integer = messageId0
-val userId: Integer = userId0
-val content: String = content0
-val channel: MessageChannel = channel0
-val status: MessageStatus = status0
-                        }
-                    }
-                }
-            
-
-                
-                sealed trait MessageResult extends js.Object { self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }
-
-                object MessageResult {
-                    
-                            def success(value: Message) = new MessageResult {
-                                type Type = Message
-                                
-                                val tag: String = "success"
-                                override val `val`: js.UndefOr[Type] = value
-                            }
-                        
-
-                            def failure(value: MessageError) = new MessageResult {
-                                type Type = MessageError
-                                
-                                val tag: String = "failure"
-                                override val `val`: js.UndefOr[Type] = value
-                            }
-                        
-                }
-            
-
-                trait Api {
-                    type WitResult[+Ok, +Err] = Ok
-                    object WitResult {
-                        def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
-
-                        def err[Err](value: Err): WitResult[Nothing, Err] = throw js.JavaScriptException(value)
-
-                        val unit: WitResult[Unit, Nothing] = ()
-                    }
-
-                    type WitOption[+A] = js.UndefOr[A]
-                    object WitOption {
-                        def some[A](value: A): WitOption[A] = value
-
-                        val none: WitOption[Nothing] = js.undefined
-
-                        def fromOption[A](option: Option[A]) =
-                        option match {
-                            case Some(value) => value.asInstanceOf[js.UndefOr[A]]
-                            case None        => js.undefined
-                        }
-                    }
-
-                    type WitList[A] = js.Array[A]
-                    object WitList {
-                        def fromList[A](list: List[A]): WitList[A] = list.toJSArray
-                    }
-                    
-                    def sendMessage(userId: Integer, message: String, channel: MessageChannel): MessageResult
-def getMessageStatus(messageId: Integer): MessageStatus
-def listUserMessages(userId: Integer): WitList[Message]
-                }
-            
\ No newline at end of file
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
+
+package tests {
+  sealed trait Message extends js.Object {
+    val messageId: Integer
+    val userId: Integer
+    val content: String
+    val channel: MessageChannel
+    val status: MessageStatus
+  }
+
+  object Message {
+    def apply(
+        messageId: Integer,
+        userId: Integer,
+        content: String,
+        channel: MessageChannel,
+        status: MessageStatus
+    ): Message = {
+      val messageId0: Integer = messageId
+      val userId0: Integer = userId
+      val content0: String = content
+      val channel0: MessageChannel = channel
+      val status0: MessageStatus = status
+
+      new Message {
+        val messageId: Integer = messageId0
+        val userId: Integer = userId0
+        val content: String = content0
+        val channel: MessageChannel = channel0
+        val status: MessageStatus = status0
+      }
+    }
+
+    /** The structural methods of `Message`, which leave its JS representation untouched */
+    implicit final class Ops(self: Message) extends AnyVal {
+      /** A new `Message` with the given fields replaced */
+      def copy(
+          messageId: Integer = self.messageId,
+          userId: Integer = self.userId,
+          content: String = self.content,
+          channel: MessageChannel = self.channel,
+          status: MessageStatus = self.status
+      ): Message = Message(messageId, userId, content, channel, status)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Message): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Message` */
+      def show: String = "Message" + Structural.show(self)
+    }
+  }
+
+  sealed trait MessageResult extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object MessageResult {
+    def success(value: Message) = new MessageResult {
+      type Type = Message
+      val tag: String = "success"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def failure(value: MessageError) = new MessageResult {
+      type Type = MessageError
+      val tag: String = "failure"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `MessageResult`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Success(value: Message) extends Case
+      final case class Failure(value: MessageError) extends Case
+    }
+
+    /** The pattern matching and structural methods of `MessageResult` */
+    implicit final class Ops(self: MessageResult) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](success: Message => A, failure: MessageError => A): A = self.tag match {
+        case "success" => success(self.`val`.asInstanceOf[Message])
+        case "failure" => failure(self.`val`.asInstanceOf[MessageError])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of MessageResult")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Success(_), Case.Failure(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: MessageResult): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `MessageResult` */
+      def show: String = "MessageResult." + Structural.show(self)
+    }
+  }
+
+  @js.native
+  sealed trait MessageStatus extends js.Any
+
+  object MessageStatus {
+    val pending: MessageStatus = "pending".asInstanceOf[MessageStatus]
+    val sent: MessageStatus = "sent".asInstanceOf[MessageStatus]
+    val delivered: MessageStatus = "delivered".asInstanceOf[MessageStatus]
+    val failed: MessageStatus = "failed".asInstanceOf[MessageStatus]
+
+    val values: js.Array[MessageStatus] =
+      js.Array(pending, sent, delivered, failed)
+  }
+
+  @js.native
+  sealed trait MessageChannel extends js.Any
+
+  object MessageChannel {
+    val email: MessageChannel = "email".asInstanceOf[MessageChannel]
+    val sms: MessageChannel = "sms".asInstanceOf[MessageChannel]
+
+    val pushNotification: MessageChannel =
+      "push-notification".asInstanceOf[MessageChannel]
+
+    val values: js.Array[MessageChannel] =
+      js.Array(email, sms, pushNotification)
+  }
+
+  @js.native
+  sealed trait MessageError extends js.Any
+
+  object MessageError {
+    val invalidChannel: MessageError =
+      "invalid-channel".asInstanceOf[MessageError]
+
+    val userNotFound: MessageError = "user-not-found".asInstanceOf[MessageError]
+
+    val sendingFailed: MessageError =
+      "sending-failed".asInstanceOf[MessageError]
+
+    val values: js.Array[MessageError] =
+      js.Array(invalidChannel, userNotFound, sendingFailed)
+  }
+
+  trait Api {
+    def sendMessage(
+        userId: Integer,
+        message: String,
+        channel: MessageChannel
+    ): MessageResult
+
+    def getMessageStatus(messageId: Integer): MessageStatus
+    def listUserMessages(userId: Integer): WitList[Message]
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `pack:name/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("sendMessage")
+    override def sendMessage(
+        userId: Integer,
+        message: String,
+        channel: MessageChannel
+    ): MessageResult = registered.sendMessage(userId, message, channel)
+
+    @JSExport("getMessageStatus")
+    override def getMessageStatus(messageId: Integer): MessageStatus =
+      registered.getMessageStatus(messageId)
+
+    @JSExport("listUserMessages")
+    override def listUserMessages(userId: Integer): WitList[Message] =
+      registered.listUserMessages(userId)
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
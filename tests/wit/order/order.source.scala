@@ -0,0 +1,338 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type OrderId = String
+}
+
+package tests {
+  /** Unlike record tracking, an order is placed by the customer */
+  sealed trait Order extends js.Object {
+    val id: OrderId
+    val status: OrderStatus
+    val lines: WitList[OrderLine]
+    val options: OrderOptions
+  }
+
+  object Order {
+    def apply(
+        id: OrderId,
+        status: OrderStatus,
+        lines: WitList[OrderLine],
+        options: OrderOptions
+    ): Order = {
+      val id0: OrderId = id
+      val status0: OrderStatus = status
+      val lines0: WitList[OrderLine] = lines
+      val options0: OrderOptions = options
+
+      new Order {
+        val id: OrderId = id0
+        val status: OrderStatus = status0
+        val lines: WitList[OrderLine] = lines0
+        val options: OrderOptions = options0
+      }
+    }
+
+    /** The structural methods of `Order`, which leave its JS representation untouched */
+    implicit final class Ops(self: Order) extends AnyVal {
+      /** A new `Order` with the given fields replaced */
+      def copy(
+          id: OrderId = self.id,
+          status: OrderStatus = self.status,
+          lines: WitList[OrderLine] = self.lines,
+          options: OrderOptions = self.options
+      ): Order = Order(id, status, lines, options)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Order): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Order` */
+      def show: String = "Order" + Structural.show(self)
+    }
+  }
+
+  sealed trait OrderStatus extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object OrderStatus {
+    val pending = new OrderStatus {
+      type Type = Nothing
+      val tag: String = "pending"
+    }
+
+    def shipped(value: Tracking) = new OrderStatus {
+      type Type = Tracking
+      val tag: String = "shipped"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `OrderStatus`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object Pending extends Case
+      final case class Shipped(value: Tracking) extends Case
+    }
+
+    /** The pattern matching and structural methods of `OrderStatus` */
+    implicit final class Ops(self: OrderStatus) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](pending: => A, shipped: Tracking => A): A = self.tag match {
+        case "pending" => pending
+        case "shipped" => shipped(self.`val`.asInstanceOf[Tracking])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of OrderStatus")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Pending, Case.Shipped(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: OrderStatus): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `OrderStatus` */
+      def show: String = "OrderStatus." + Structural.show(self)
+    }
+  }
+
+  @js.native
+  sealed trait Carrier extends js.Any
+
+  object Carrier {
+    val ups: Carrier = "ups".asInstanceOf[Carrier]
+    val fedEx: Carrier = "fed-ex".asInstanceOf[Carrier]
+    val values: js.Array[Carrier] = js.Array(ups, fedEx)
+  }
+
+  sealed trait OrderLine extends js.Object {
+    val sku: String
+    val quantity: Integer
+  }
+
+  object OrderLine {
+    def apply(sku: String, quantity: Integer): OrderLine = {
+      val sku0: String = sku
+      val quantity0: Integer = quantity
+
+      new OrderLine {
+        val sku: String = sku0
+        val quantity: Integer = quantity0
+      }
+    }
+
+    /** The structural methods of `OrderLine`, which leave its JS representation untouched */
+    implicit final class Ops(self: OrderLine) extends AnyVal {
+      /** A new `OrderLine` with the given fields replaced */
+      def copy(
+          sku: String = self.sku,
+          quantity: Integer = self.quantity
+      ): OrderLine = OrderLine(sku, quantity)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: OrderLine): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `OrderLine` */
+      def show: String = "OrderLine" + Structural.show(self)
+    }
+  }
+
+  sealed trait OrderOptions extends js.Object {
+    val giftWrap: Boolean
+    val express: Boolean
+  }
+
+  object OrderOptions {
+    def apply(
+        giftWrap: Boolean = false,
+        express: Boolean = false
+    ): OrderOptions = {
+      val giftWrap0: Boolean = giftWrap
+      val express0: Boolean = express
+
+      new OrderOptions {
+        val giftWrap: Boolean = giftWrap0
+        val express: Boolean = express0
+      }
+    }
+
+    /** The structural methods of `OrderOptions`, which leave its JS representation untouched */
+    implicit final class Ops(self: OrderOptions) extends AnyVal {
+      /** A new `OrderOptions` with the given fields replaced */
+      def copy(
+          giftWrap: Boolean = self.giftWrap,
+          express: Boolean = self.express
+      ): OrderOptions = OrderOptions(giftWrap, express)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: OrderOptions): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `OrderOptions` */
+      def show: String = "OrderOptions" + Structural.show(self)
+    }
+  }
+
+  sealed trait Tracking extends js.Object {
+    val carrier: Carrier
+    val code: String
+  }
+
+  object Tracking {
+    def apply(carrier: Carrier, code: String): Tracking = {
+      val carrier0: Carrier = carrier
+      val code0: String = code
+
+      new Tracking {
+        val carrier: Carrier = carrier0
+        val code: String = code0
+      }
+    }
+
+    /** The structural methods of `Tracking`, which leave its JS representation untouched */
+    implicit final class Ops(self: Tracking) extends AnyVal {
+      /** A new `Tracking` with the given fields replaced */
+      def copy(
+          carrier: Carrier = self.carrier,
+          code: String = self.code
+      ): Tracking = Tracking(carrier, code)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Tracking): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Tracking` */
+      def show: String = "Tracking" + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def placeOrder(order: Order): WitResult[OrderId, String]
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -1,43 +1,72 @@
+// Generated by golem-scalajs-wit-bindgen
 
-                // Generated by golem-scalajs-wit-bindgen
-                package tests
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
 
-                import scala.scalajs.js
-                import scala.scalajs.js.JSConverters._
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
 
-                
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
 
-                
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
 
-                trait Api {
-                    type WitResult[+Ok, +Err] = Ok
-                    object WitResult {
-                        def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+    val unit: WitResult[Unit, Nothing] = ()
+  }
 
-                        def err[Err](value: Err): WitResult[Nothing, Err] = throw js.JavaScriptException(value)
+  type WitOption[+A] = js.UndefOr[A]
 
-                        val unit: WitResult[Unit, Nothing] = ()
-                    }
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
 
-                    type WitOption[+A] = js.UndefOr[A]
-                    object WitOption {
-                        def some[A](value: A): WitOption[A] = value
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
 
-                        val none: WitOption[Nothing] = js.undefined
+  type WitList[A] = js.Array[A]
 
-                        def fromOption[A](option: Option[A]) =
-                        option match {
-                            case Some(value) => value.asInstanceOf[js.UndefOr[A]]
-                            case None        => js.undefined
-                        }
-                    }
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
 
-                    type WitList[A] = js.Array[A]
-                    object WitList {
-                        def fromList[A](list: List[A]): WitList[A] = list.toJSArray
-                    }
-                    
-                    def getRandomBytes(len: Long): WitList[Byte]
-def getRandomU64(): Long
-                }
-            
\ No newline at end of file
+package tests {
+  /** WASI Random is a random data API.
+    *
+    * It is intended to be portable at least between Unix-family platforms and
+    * Windows.
+    */
+  trait Api {
+    /** Return `len` cryptographically-secure random or pseudo-random bytes.
+      *
+      * This function must produce data at least as cryptographically secure and
+      * fast as an adequately seeded cryptographically-secure pseudo-random
+      * number generator (CSPRNG). It must not block, from the perspective of
+      * the calling program, under any circumstances, including on the first
+      * request and on requests for numbers of bytes. The returned data must
+      * always be unpredictable.
+      *
+      * This function must always return fresh data. Deterministic environments
+      * must omit this function, rather than implementing it with deterministic
+      * data.
+      *
+      * @param len `u64`
+      * @return `list<u8>`
+      */
+    def getRandomBytes(len: Long): WitList[Byte]
+
+    /** Return a cryptographically-secure random or pseudo-random `u64` value.
+      *
+      * This function returns the same type of data as `get-random-bytes`,
+      * represented as a `u64`.
+      *
+      * @return `u64`
+      */
+    def getRandomU64(): Long
+  }
+}
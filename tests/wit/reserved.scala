@@ -0,0 +1,381 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type ApiImpl_ = WitList[ApiExport_]
+}
+
+package tests {
+  sealed trait ApiExport_ extends js.Object {
+    val id: Integer
+  }
+
+  object ApiExport_ {
+    def apply(id: Integer): ApiExport_ = {
+      val id0: Integer = id
+
+      new ApiExport_ {
+        val id: Integer = id0
+      }
+    }
+
+    /** The structural methods of `ApiExport_`, which leave its JS representation untouched */
+    implicit final class Ops(self: ApiExport_) extends AnyVal {
+      /** A new `ApiExport_` with the given fields replaced */
+      def copy(id: Integer = self.id): ApiExport_ = ApiExport_(id)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: ApiExport_): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `ApiExport_` */
+      def show: String = "ApiExport_" + Structural.show(self)
+    }
+  }
+
+  sealed trait Golem_ extends js.Object {
+    val id: Integer
+  }
+
+  object Golem_ {
+    def apply(id: Integer): Golem_ = {
+      val id0: Integer = id
+
+      new Golem_ {
+        val id: Integer = id0
+      }
+    }
+
+    /** The structural methods of `Golem_`, which leave its JS representation untouched */
+    implicit final class Ops(self: Golem_) extends AnyVal {
+      /** A new `Golem_` with the given fields replaced */
+      def copy(id: Integer = self.id): Golem_ = Golem_(id)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Golem_): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Golem_` */
+      def show: String = "Golem_" + Structural.show(self)
+    }
+  }
+
+  @js.native
+  sealed trait Host_ extends js.Any
+
+  object Host_ {
+    val local: Host_ = "local".asInstanceOf[Host_]
+    val remote: Host_ = "remote".asInstanceOf[Host_]
+    val values: js.Array[Host_] = js.Array(local, remote)
+  }
+
+  sealed trait ApiRestClient_ extends js.Object {
+    val url: String
+  }
+
+  object ApiRestClient_ {
+    def apply(url: String): ApiRestClient_ = {
+      val url0: String = url
+
+      new ApiRestClient_ {
+        val url: String = url0
+      }
+    }
+
+    /** The structural methods of `ApiRestClient_`, which leave its JS representation untouched */
+    implicit final class Ops(self: ApiRestClient_) extends AnyVal {
+      /** A new `ApiRestClient_` with the given fields replaced */
+      def copy(url: String = self.url): ApiRestClient_ = ApiRestClient_(url)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: ApiRestClient_): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `ApiRestClient_` */
+      def show: String = "ApiRestClient_" + Structural.show(self)
+    }
+  }
+
+  sealed trait StubApi_ extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object StubApi_ {
+    val empty = new StubApi_ {
+      type Type = Nothing
+      val tag: String = "empty"
+    }
+
+    def fixed(value: Integer) = new StubApi_ {
+      type Type = Integer
+      val tag: String = "fixed"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `StubApi_`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object Empty extends Case
+      final case class Fixed(value: Integer) extends Case
+    }
+
+    /** The pattern matching and structural methods of `StubApi_` */
+    implicit final class Ops(self: StubApi_) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](empty: => A, fixed: Integer => A): A = self.tag match {
+        case "empty" => empty
+        case "fixed" => fixed(self.`val`.asInstanceOf[Integer])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of StubApi_")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Empty, Case.Fixed(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: StubApi_): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `StubApi_` */
+      def show: String = "StubApi_." + Structural.show(self)
+    }
+  }
+
+  sealed trait MockApi_ extends js.Object {
+    val recording: Boolean
+    val strict: Boolean
+  }
+
+  object MockApi_ {
+    def apply(recording: Boolean = false, strict: Boolean = false): MockApi_ = {
+      val recording0: Boolean = recording
+      val strict0: Boolean = strict
+
+      new MockApi_ {
+        val recording: Boolean = recording0
+        val strict: Boolean = strict0
+      }
+    }
+
+    /** The structural methods of `MockApi_`, which leave its JS representation untouched */
+    implicit final class Ops(self: MockApi_) extends AnyVal {
+      /** A new `MockApi_` with the given fields replaced */
+      def copy(
+          recording: Boolean = self.recording,
+          strict: Boolean = self.strict
+      ): MockApi_ = MockApi_(recording, strict)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: MockApi_): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `MockApi_` */
+      def show: String = "MockApi_" + Structural.show(self)
+    }
+  }
+
+  sealed trait Main_ extends js.Object {
+    val args: WitList[String]
+  }
+
+  object Main_ {
+    def apply(args: WitList[String]): Main_ = {
+      val args0: WitList[String] = args
+
+      new Main_ {
+        val args: WitList[String] = args0
+      }
+    }
+
+    /** The structural methods of `Main_`, which leave its JS representation untouched */
+    implicit final class Ops(self: Main_) extends AnyVal {
+      /** A new `Main_` with the given fields replaced */
+      def copy(args: WitList[String] = self.args): Main_ = Main_(args)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Main_): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Main_` */
+      def show: String = "Main_" + Structural.show(self)
+    }
+  }
+
+  sealed trait ValidArbitraries_ extends js.Object {
+    val seed: Long
+  }
+
+  object ValidArbitraries_ {
+    def apply(seed: Long): ValidArbitraries_ = {
+      val seed0: Long = seed
+
+      new ValidArbitraries_ {
+        val seed: Long = seed0
+      }
+    }
+
+    /** The structural methods of `ValidArbitraries_`, which leave its JS representation untouched */
+    implicit final class Ops(self: ValidArbitraries_) extends AnyVal {
+      /** A new `ValidArbitraries_` with the given fields replaced */
+      def copy(seed: Long = self.seed): ValidArbitraries_ =
+        ValidArbitraries_(seed)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: ValidArbitraries_): Boolean =
+        Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `ValidArbitraries_` */
+      def show: String = "ValidArbitraries_" + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def get(id: Integer): ApiExport_
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:reserved/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("get")
+    override def get(id: Integer): ApiExport_ = registered.get(id)
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
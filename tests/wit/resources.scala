@@ -1,42 +1,40 @@
+// Generated by golem-scalajs-wit-bindgen
 
-                // Generated by golem-scalajs-wit-bindgen
-                package tests
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
 
-                import scala.scalajs.js
-                import scala.scalajs.js.JSConverters._
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
 
-                
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
 
-                
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
 
-                trait Api {
-                    type WitResult[+Ok, +Err] = Ok
-                    object WitResult {
-                        def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+    val unit: WitResult[Unit, Nothing] = ()
+  }
 
-                        def err[Err](value: Err): WitResult[Nothing, Err] = throw js.JavaScriptException(value)
+  type WitOption[+A] = js.UndefOr[A]
 
-                        val unit: WitResult[Unit, Nothing] = ()
-                    }
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
 
-                    type WitOption[+A] = js.UndefOr[A]
-                    object WitOption {
-                        def some[A](value: A): WitOption[A] = value
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
 
-                        val none: WitOption[Nothing] = js.undefined
+  type WitList[A] = js.Array[A]
 
-                        def fromOption[A](option: Option[A]) =
-                        option match {
-                            case Some(value) => value.asInstanceOf[js.UndefOr[A]]
-                            case None        => js.undefined
-                        }
-                    }
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
 
-                    type WitList[A] = js.Array[A]
-                    object WitList {
-                        def fromList[A](list: List[A]): WitList[A] = list.toJSArray
-                    }
-                    
-                    
-                }
-            
\ No newline at end of file
+package tests {
+  trait Api
+}
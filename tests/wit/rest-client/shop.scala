@@ -0,0 +1,865 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.concurrent.{ExecutionContext, Future}
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type Items = WitList[Item]
+}
+
+package tests {
+  /** An item in the cart */
+  sealed trait Item extends js.Object {
+    val productId: String
+    val quantity: Integer
+    val price: WitOption[Double]
+  }
+
+  object Item {
+    def apply(
+        productId: String,
+        quantity: Integer,
+        price: WitOption[Double]
+    ): Item = {
+      val productId0: String = productId
+      val quantity0: Integer = quantity
+      val price0: WitOption[Double] = price
+
+      new Item {
+        val productId: String = productId0
+        val quantity: Integer = quantity0
+        val price: WitOption[Double] = price0
+      }
+    }
+
+    /** The structural methods of `Item`, which leave its JS representation untouched */
+    implicit final class Ops(self: Item) extends AnyVal {
+      /** A new `Item` with the given fields replaced */
+      def copy(
+          productId: String = self.productId,
+          quantity: Integer = self.quantity,
+          price: WitOption[Double] = self.price
+      ): Item = Item(productId, quantity, price)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Item): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Item` */
+      def show: String = "Item" + Structural.show(self)
+    }
+  }
+
+  @js.native
+  sealed trait Status extends js.Any
+
+  object Status {
+    val open: Status = "open".asInstanceOf[Status]
+    val checkedOut: Status = "checked-out".asInstanceOf[Status]
+    val values: js.Array[Status] = js.Array(open, checkedOut)
+  }
+
+  sealed trait Discounts extends js.Object {
+    val loyalty: Boolean
+    val seasonal: Boolean
+  }
+
+  object Discounts {
+    def apply(
+        loyalty: Boolean = false,
+        seasonal: Boolean = false
+    ): Discounts = {
+      val loyalty0: Boolean = loyalty
+      val seasonal0: Boolean = seasonal
+
+      new Discounts {
+        val loyalty: Boolean = loyalty0
+        val seasonal: Boolean = seasonal0
+      }
+    }
+
+    /** The structural methods of `Discounts`, which leave its JS representation untouched */
+    implicit final class Ops(self: Discounts) extends AnyVal {
+      /** A new `Discounts` with the given fields replaced */
+      def copy(
+          loyalty: Boolean = self.loyalty,
+          seasonal: Boolean = self.seasonal
+      ): Discounts = Discounts(loyalty, seasonal)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Discounts): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Discounts` */
+      def show: String = "Discounts" + Structural.show(self)
+    }
+  }
+
+  sealed trait Event extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Event {
+    def added(value: Item) = new Event {
+      type Type = Item
+      val tag: String = "added"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def removed(value: js.Tuple2[String, Integer]) = new Event {
+      type Type = js.Tuple2[String, Integer]
+      val tag: String = "removed"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val cleared = new Event {
+      type Type = Nothing
+      val tag: String = "cleared"
+    }
+
+    /** A case of `Event`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Added(value: Item) extends Case
+      final case class Removed(value: js.Tuple2[String, Integer]) extends Case
+      case object Cleared extends Case
+    }
+
+    /** The pattern matching and structural methods of `Event` */
+    implicit final class Ops(self: Event) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          added: Item => A,
+          removed: js.Tuple2[String, Integer] => A,
+          cleared: => A
+      ): A = self.tag match {
+        case "added" => added(self.`val`.asInstanceOf[Item])
+        case "removed" =>
+          removed(self.`val`.asInstanceOf[js.Tuple2[String, Integer]])
+        case "cleared" => cleared
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Event")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Added(_), Case.Removed(_), Case.Cleared)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Event): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Event` */
+      def show: String = "Event." + Structural.show(self)
+    }
+  }
+
+  /** The limits of a customer, up to 255 carts of 65535 items, 4294967295 cents per order,
+    * 18446744073709551615 loyalty points and a balance down to -9223372036854775808 cents
+    */
+  sealed trait Limits extends js.Object {
+    val carts: Byte
+    val items: Short
+    val cents: Integer
+    val points: Long
+    val balance: Long
+  }
+
+  object Limits {
+    def apply(
+        carts: Byte,
+        items: Short,
+        cents: Integer,
+        points: Long,
+        balance: Long
+    ): Limits = {
+      val carts0: Byte = carts
+      val items0: Short = items
+      val cents0: Integer = cents
+      val points0: Long = points
+      val balance0: Long = balance
+
+      new Limits {
+        val carts: Byte = carts0
+        val items: Short = items0
+        val cents: Integer = cents0
+        val points: Long = points0
+        val balance: Long = balance0
+      }
+    }
+
+    /** The structural methods of `Limits`, which leave its JS representation untouched */
+    implicit final class Ops(self: Limits) extends AnyVal {
+      /** A new `Limits` with the given fields replaced */
+      def copy(
+          carts: Byte = self.carts,
+          items: Short = self.items,
+          cents: Integer = self.cents,
+          points: Long = self.points,
+          balance: Long = self.balance
+      ): Limits = Limits(carts, items, cents, points, balance)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Limits): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Limits` */
+      def show: String = "Limits" + Structural.show(self)
+    }
+  }
+
+  /** The results of `totals` */
+  sealed trait TotalsResult extends js.Object {
+    val count: Integer
+    val amount: Double
+  }
+
+  object TotalsResult {
+    def apply(count: Integer, amount: Double): TotalsResult = {
+      val count0: Integer = count
+      val amount0: Double = amount
+
+      new TotalsResult {
+        val count: Integer = count0
+        val amount: Double = amount0
+      }
+    }
+
+    /** The structural methods of `TotalsResult`, which leave its JS representation untouched */
+    implicit final class Ops(self: TotalsResult) extends AnyVal {
+      /** A new `TotalsResult` with the given fields replaced */
+      def copy(
+          count: Integer = self.count,
+          amount: Double = self.amount
+      ): TotalsResult = TotalsResult(count, amount)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: TotalsResult): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `TotalsResult` */
+      def show: String = "TotalsResult" + Structural.show(self)
+    }
+  }
+
+  /** The cart of a customer
+    *
+    * Invokes `golem:shop/api@1.0.0` on a worker through the Golem REST API
+    *
+    * @param baseUrl the URL of the Golem REST API, such as `http://localhost:9881`
+    * @param componentId the ID of the component of the worker
+    * @param workerName the name of the worker
+    * @param token the token authenticating the requests, if any
+    */
+  class ApiRestClient(
+      baseUrl: String,
+      componentId: String,
+      workerName: String,
+      token: Option[String] = None
+  ) {
+    private val worker: String =
+      s"$baseUrl/v1/components/$componentId/workers/$workerName"
+
+    private def invoke[A](
+        signature: ApiRestClient.Signature,
+        params: Any*
+    )(
+        results: js.Array[Any] => A
+    ): Future[A] =
+      ApiRestClient.invoke(worker, token, signature, params)(results)
+
+    /** Adds an item to the cart
+      *
+      * @param item `item`
+      * @return the future of `WitResult[Unit, String]`
+      */
+    def addItem(item: Item): Future[WitResult[Unit, String]] =
+      invoke(ApiRestClient.addItem, item)(values =>
+        values(0).asInstanceOf[WitResult[Unit, String]]
+      )
+
+    /** The items in the cart
+      *
+      * @return the future of `Items`
+      */
+    def getItems(): Future[Items] =
+      invoke(ApiRestClient.getItems)(values => values(0).asInstanceOf[Items])
+
+    def getStatus(): Future[Status] =
+      invoke(ApiRestClient.getStatus)(values => values(0).asInstanceOf[Status])
+
+    def apply(discounts: Discounts, code: Char): Future[Long] =
+      invoke(ApiRestClient.apply1, discounts, code)(values =>
+        values(0).asInstanceOf[Long]
+      )
+
+    def history(since: WitOption[Long]): Future[WitList[Event]] =
+      invoke(ApiRestClient.history, since)(values =>
+        values(0).asInstanceOf[WitList[Event]]
+      )
+
+    def totals(): Future[TotalsResult] = invoke(ApiRestClient.totals)(values =>
+      TotalsResult(
+        values(0).asInstanceOf[Integer],
+        values(1).asInstanceOf[Double]
+      )
+    )
+
+    def clear(): Future[Unit] = invoke(ApiRestClient.clear)(_ => ())
+
+    def setLimits(limits: Limits): Future[Limits] =
+      invoke(ApiRestClient.setLimits, limits)(values =>
+        values(0).asInstanceOf[Limits]
+      )
+  }
+
+  object ApiRestClient {
+    /** A WIT type in the typed JSON value format of Golem, converting values from and to
+      * their JS representation
+      */
+    trait Codec {
+      /** The analysed type, which is how Golem describes WIT types */
+      def typ: js.Any
+
+      /** Converts a value from its JS representation to JSON */
+      def encode(value: Any): js.Any
+
+      /** Converts a value from JSON to its JS representation */
+      def decode(json: js.Any): Any
+
+      /** Converts a value to a typed JSON value */
+      def typed(value: Any): js.Any =
+        js.Dictionary[js.Any]("typ" -> typ, "value" -> encode(value))
+    }
+
+    /** An error status returned by the Golem REST API, with the body of the response */
+    final case class GolemError(
+        status: Int,
+        body: String
+    ) extends Exception(s"Golem responded with status $status: $body")
+
+    /** The qualified name of a function, with the codecs of its params and results */
+    final case class Signature(
+        function: String,
+        params: Seq[Codec],
+        results: Seq[Codec]
+    )
+
+    private implicit val executionContext: ExecutionContext =
+      scala.scalajs.concurrent.JSExecutionContext.queue
+
+    private def analysed(name: String, fields: (String, js.Any)*): js.Any = {
+      val typ = js.Dictionary[js.Any](fields: _*)
+      typ("type") = name
+      typ
+    }
+
+    private def primitive(name: String): Codec = new Codec {
+      val typ: js.Any = analysed(name)
+      def encode(value: Any): js.Any = value.asInstanceOf[js.Any]
+      def decode(json: js.Any): Any = json
+    }
+
+    val bool: Codec = primitive("Bool")
+    val s8: Codec = primitive("S8")
+    val s16: Codec = primitive("S16")
+    val s32: Codec = primitive("S32")
+    val f32: Codec = primitive("F32")
+    val f64: Codec = primitive("F64")
+    val str: Codec = primitive("Str")
+
+    val u8: Codec = new Codec {
+      val typ: js.Any = analysed("U8")
+      def encode(value: Any): js.Any = value.asInstanceOf[Byte] & 0xff
+      def decode(json: js.Any): Any = json.asInstanceOf[Int].toByte
+    }
+
+    val u16: Codec = new Codec {
+      val typ: js.Any = analysed("U16")
+      def encode(value: Any): js.Any = value.asInstanceOf[Short] & 0xffff
+      def decode(json: js.Any): Any = json.asInstanceOf[Int].toShort
+    }
+
+    val u32: Codec = new Codec {
+      val typ: js.Any = analysed("U32")
+
+      def encode(value: Any): js.Any =
+        Integer.toUnsignedLong(value.asInstanceOf[Int]).toDouble
+
+      def decode(json: js.Any): Any = json.asInstanceOf[Double].toLong.toInt
+    }
+
+    val s64: Codec = new Codec {
+      val typ: js.Any = analysed("S64")
+
+      def encode(value: Any): js.Any =
+        js.BigInt(value.asInstanceOf[Long].toString)
+
+      def decode(json: js.Any): Any =
+        java.lang.Long.parseLong(js.Dynamic.global.BigInt(json).toString)
+    }
+
+    val u64: Codec = new Codec {
+      val typ: js.Any = analysed("U64")
+
+      def encode(value: Any): js.Any =
+        js.BigInt(java.lang.Long.toUnsignedString(value.asInstanceOf[Long]))
+
+      def decode(json: js.Any): Any =
+        java.lang.Long.parseUnsignedLong(js.Dynamic.global.BigInt(json).toString)
+    }
+
+    val chr: Codec = new Codec {
+      val typ: js.Any = analysed("Chr")
+      def encode(value: Any): js.Any = value.asInstanceOf[Char].toString
+      def decode(json: js.Any): Any = json.asInstanceOf[String].charAt(0)
+    }
+
+    private def typeOf(codec: Option[Codec]): js.Any = codec.map(_.typ).orNull
+
+    /** The codec of `list<T>`, given the codec of `T` */
+    def list(inner: Codec): Codec = new Codec {
+      val typ: js.Any = analysed("List", "inner" -> inner.typ)
+
+      def encode(value: Any): js.Any =
+        value.asInstanceOf[js.Array[Any]].map(inner.encode)
+
+      def decode(json: js.Any): Any =
+        json.asInstanceOf[js.Array[js.Any]].map(inner.decode)
+    }
+
+    /** The codec of `option<T>`, given the codec of `T` */
+    def option(inner: Codec): Codec = new Codec {
+      val typ: js.Any = analysed("Option", "inner" -> inner.typ)
+
+      def encode(value: Any): js.Any =
+        if (js.isUndefined(value)) null else inner.encode(value)
+
+      def decode(json: js.Any): Any =
+        if (json == null || js.isUndefined(json))
+          js.undefined
+        else
+          inner.decode(json)
+    }
+
+    /** The codec of `result<T, E>`, given the codecs of `T` and `E`, errors being thrown
+      * as with their JS representation
+      */
+    def result(ok: Option[Codec], err: Option[Codec]): Codec = new Codec {
+      val typ: js.Any =
+        analysed("Result", "ok" -> typeOf(ok), "err" -> typeOf(err))
+
+      def encode(value: Any): js.Any =
+        js.Dictionary[js.Any]("ok" -> ok.fold[js.Any](null)(_.encode(value)))
+
+      def decode(json: js.Any): Any = {
+        val cases = json.asInstanceOf[js.Dictionary[js.Any]]
+
+        cases.get("ok") match {
+          case Some(json) => ok.fold[Any](())(_.decode(json))
+          case None =>
+            throw js.JavaScriptException(err.fold[Any](())(_.decode(cases("err"))))
+        }
+      }
+    }
+
+    /** The codec of a tuple, given the codecs of its items */
+    def tuple(items: Codec*): Codec = new Codec {
+      val typ: js.Any =
+        analysed("Tuple", "items" -> js.Array(items.map(_.typ): _*))
+
+      def encode(value: Any): js.Any = {
+        val values = value.asInstanceOf[js.Array[Any]]
+
+        js.Array(
+          items.zipWithIndex.map { case (item, i) =>
+            item.encode(values(i))
+          }: _*
+        )
+      }
+
+      def decode(json: js.Any): Any = {
+        val values = json.asInstanceOf[js.Array[js.Any]]
+
+        js.Array(
+          items.zipWithIndex.map { case (item, i) =>
+            item.decode(values(i))
+          }: _*
+        )
+      }
+    }
+
+    /** The codec of a record, given the WIT name, the JS name and the codec of each field */
+    def record(fields: (String, String, Codec)*): Codec = new Codec {
+      val typ: js.Any = analysed(
+        "Record",
+        "fields" -> js.Array(
+          fields.map { case (name, _, field) =>
+            js.Dictionary[js.Any]("name" -> name, "typ" -> field.typ)
+          }: _*
+        )
+      )
+
+      def encode(value: Any): js.Any = {
+        val members = value.asInstanceOf[js.Dictionary[Any]]
+
+        js.Dictionary[js.Any](
+          fields.map { case (name, jsName, field) =>
+            name -> field.encode(members.getOrElse(jsName, js.undefined))
+          }: _*
+        )
+      }
+
+      def decode(json: js.Any): Any = {
+        val members = json.asInstanceOf[js.Dictionary[js.Any]]
+
+        js.Dictionary[Any](
+          fields.map { case (name, jsName, field) =>
+            jsName -> field.decode(members.getOrElse(name, null))
+          }: _*
+        )
+      }
+    }
+
+    /** The codec of a variant, given the name and the codec of the payload of each case */
+    def variant(cases: (String, Option[Codec])*): Codec = new Codec {
+      val typ: js.Any = analysed(
+        "Variant",
+        "cases" -> js.Array(
+          cases.map { case (name, payload) =>
+            js.Dictionary[js.Any]("name" -> name, "typ" -> typeOf(payload))
+          }: _*
+        )
+      )
+
+      def encode(value: Any): js.Any = {
+        val variant = value.asInstanceOf[js.Dynamic]
+        val tag = variant.tag.asInstanceOf[String]
+
+        js.Dictionary[js.Any](
+          tag -> payload(tag).fold[js.Any](null)(_.encode(variant.`val`))
+        )
+      }
+
+      def decode(json: js.Any): Any = {
+        val (tag, value) = json.asInstanceOf[js.Dictionary[js.Any]].head
+
+        js.Dictionary[Any](
+          "tag" -> tag,
+          "val" -> payload(tag).fold[Any](js.undefined)(_.decode(value))
+        )
+      }
+
+      private def payload(tag: String): Option[Codec] =
+        cases.find(_._1 == tag).flatMap(_._2)
+    }
+
+    /** The codec of an enum, given the name of each case */
+    def enumeration(cases: String*): Codec = new Codec {
+      val typ: js.Any = analysed("Enum", "cases" -> js.Array(cases: _*))
+      def encode(value: Any): js.Any = value.asInstanceOf[js.Any]
+      def decode(json: js.Any): Any = json
+    }
+
+    /** The codec of flags, given the WIT name and the JS name of each flag */
+    def flags(names: (String, String)*): Codec = new Codec {
+      val typ: js.Any =
+        analysed("Flags", "names" -> js.Array(names.map(_._1): _*))
+
+      def encode(value: Any): js.Any = {
+        val flags = value.asInstanceOf[js.Dictionary[Boolean]]
+
+        js.Array(
+          names.collect { case (name, jsName) if flags.getOrElse(jsName, false) =>
+            name
+          }: _*
+        )
+      }
+
+      def decode(json: js.Any): Any = {
+        val set = json.asInstanceOf[js.Array[String]]
+
+        js.Dictionary(
+          names.map { case (name, jsName) => jsName -> set.contains(name) }: _*
+        )
+      }
+    }
+
+    /** The prefix marking the digits of a `BigInt` while it is converted to JSON */
+    private val bigIntMarker = "\u0000bigint:"
+
+    /** Converts a value to JSON, writing the `BigInt`s of 64-bit integers as exact numbers */
+    private def stringify(value: js.Any): String = {
+      val marked = js.JSON.stringify(
+        value,
+        (_: String, value: js.Any) =>
+          if (js.typeOf(value) == "bigint")
+            (bigIntMarker + value.toString): js.Any
+          else
+            value
+      )
+
+      val marker =
+        java.util.regex.Pattern.quote(js.JSON.stringify(bigIntMarker).init)
+
+      marked.replaceAll(marker + "(-?[0-9]+)\"", "$1")
+    }
+
+    /** Reads an integer that does not fit a double as an exact `BigInt`, where the JS
+      * engine gives its source
+      */
+    private def exact(
+        key: String,
+        value: js.Any,
+        context: js.UndefOr[js.Dynamic]
+    ): js.Any = {
+      val source =
+        context.flatMap(_.source.asInstanceOf[js.UndefOr[String]])
+          .filter(_.matches("-?[0-9]+"))
+
+      val unsafe =
+        js.typeOf(value) == "number" &&
+          !js.Dynamic.global.Number.isSafeInteger(value).asInstanceOf[Boolean]
+
+      if (unsafe && source.isDefined) js.Dynamic.global.BigInt(source.get) else value
+    }
+
+    /** Parses JSON, reading the integers that do not fit a double with `exact` */
+    private def parse(text: String): js.Dynamic =
+      js.Dynamic.global.JSON.parse(text, js.Any.fromFunction3(exact))
+
+    /** Invokes a function on a worker and awaits its results, which are converted by `results` */
+    private def invoke[A](
+        worker: String,
+        token: Option[String],
+        signature: Signature,
+        params: Seq[Any]
+    )(
+        results: js.Array[Any] => A
+    ): Future[A] = {
+      val function = js.URIUtils.encodeURIComponent(signature.function)
+
+      val typed =
+        signature.params.zip(params).map { case (codec, value) => codec.typed(value) }
+
+      val headers = js.Dictionary("Content-Type" -> "application/json")
+      token.foreach(token => headers("Authorization") = s"Bearer $token")
+
+      val request = js.Dictionary[js.Any](
+        "method" -> "POST",
+        "headers" -> headers,
+        "body" -> stringify(js.Dictionary[js.Any]("params" -> js.Array(typed: _*)))
+      )
+
+      val response = js.Dynamic.global.fetch(
+        s"$worker/invoke-and-await?function=$function",
+        request
+      ).asInstanceOf[js.Promise[js.Dynamic]]
+
+      response.toFuture.flatMap(response =>
+        response.text().asInstanceOf[js.Promise[String]].toFuture.map(body =>
+          results(decode(signature, response.status.asInstanceOf[Int], body))
+        )
+      )
+    }
+
+    /** Decodes the results of an invocation, failing with a `GolemError` on error statuses */
+    private def decode(
+        signature: Signature,
+        status: Int,
+        body: String
+    ): js.Array[Any] = {
+      if (status < 200 || status >= 300) throw GolemError(status, body)
+      val values = parse(body).result.value.asInstanceOf[js.Array[js.Any]]
+
+      js.Array(
+        signature.results.zipWithIndex.map { case (codec, i) =>
+          codec.decode(values(i))
+        }: _*
+      )
+    }
+
+    lazy val itemCodec: Codec = record(
+      ("product-id", "productId", str),
+      ("quantity", "quantity", u32),
+      ("price", "price", option(f64))
+    )
+
+    lazy val itemsCodec: Codec = list(itemCodec)
+    lazy val statusCodec: Codec = enumeration("open", "checked-out")
+
+    lazy val discountsCodec: Codec =
+      flags(("loyalty", "loyalty"), ("seasonal", "seasonal"))
+
+    lazy val eventCodec: Codec = variant(
+      ("added", Some(itemCodec)),
+      ("removed", Some(tuple(str, u32))),
+      ("cleared", None)
+    )
+
+    lazy val limitsCodec: Codec = record(
+      ("carts", "carts", u8),
+      ("items", "items", u16),
+      ("cents", "cents", u32),
+      ("points", "points", u64),
+      ("balance", "balance", s64)
+    )
+
+    val addItem: Signature = Signature(
+      "golem:shop/api@1.0.0.{add-item}",
+      Seq(itemCodec),
+      Seq(result(None, Some(str)))
+    )
+
+    val getItems: Signature =
+      Signature("golem:shop/api@1.0.0.{get-items}", Seq(), Seq(itemsCodec))
+
+    val getStatus: Signature =
+      Signature("golem:shop/api@1.0.0.{get-status}", Seq(), Seq(statusCodec))
+
+    val apply1: Signature = Signature(
+      "golem:shop/api@1.0.0.{apply}",
+      Seq(discountsCodec, chr),
+      Seq(u64)
+    )
+
+    val history: Signature = Signature(
+      "golem:shop/api@1.0.0.{history}",
+      Seq(option(u64)),
+      Seq(list(eventCodec))
+    )
+
+    val totals: Signature =
+      Signature("golem:shop/api@1.0.0.{totals}", Seq(), Seq(u32, f64))
+
+    val clear: Signature =
+      Signature("golem:shop/api@1.0.0.{clear}", Seq(), Seq())
+
+    val setLimits: Signature = Signature(
+      "golem:shop/api@1.0.0.{set-limits}",
+      Seq(limitsCodec),
+      Seq(limitsCodec)
+    )
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -0,0 +1,305 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
+
+package tests {
+  /** An item in the cart */
+  sealed trait Item extends js.Object {
+    val productId: String
+    val quantity: Integer
+  }
+
+  object Item {
+    def apply(productId: String, quantity: Integer): Item = {
+      val productId0: String = productId
+      val quantity0: Integer = quantity
+
+      new Item {
+        val productId: String = productId0
+        val quantity: Integer = quantity0
+      }
+    }
+
+    /** The structural methods of `Item`, which leave its JS representation untouched */
+    implicit final class Ops(self: Item) extends AnyVal {
+      /** A new `Item` with the given fields replaced */
+      def copy(
+          productId: String = self.productId,
+          quantity: Integer = self.quantity
+      ): Item = Item(productId, quantity)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Item): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Item` */
+      def show: String = "Item" + Structural.show(self)
+    }
+  }
+
+  /** The cart of a customer */
+  @js.native
+  @JSImport("golem:shop-stub/stub-shop@1.0.0", "Api")
+  class Api(location: Uri) extends js.Object {
+    /** Adds an item to the cart
+      *
+      * @param item `item`
+      * @return `result<_, string>`
+      */
+    def blockingAddItem(item: Item): WitResult[Unit, String] = js.native
+
+    /** Adds an item to the cart
+      *
+      * @param item `item`
+      * @return the pending call, which gets `WitResult[Unit, String]`
+      */
+    def addItem(item: Item): FutureAddItemResult = js.native
+
+    /** The items in the cart
+      *
+      * @return `list<item>`
+      */
+    def blockingGetItems(): WitList[Item] = js.native
+
+    /** The items in the cart
+      *
+      * @return the pending call, which gets `WitList[Item]`
+      */
+    def getItems(): FutureGetItemsResult = js.native
+
+    def blockingClear(): Unit = js.native
+    def clear(): FutureClearResult = js.native
+    def blockingClone(target: String): Unit = js.native
+
+    @JSName("clone")
+    def clone_(target: String): FutureCloneResult = js.native
+  }
+
+  /** A pending call of `add-item` on a remote worker */
+  @js.native
+  @JSImport("golem:shop-stub/stub-shop@1.0.0", "FutureAddItemResult")
+  class FutureAddItemResult extends js.Object {
+    /** Creates a pollable that is ready once the call has completed */
+    def subscribe(): Pollable = js.native
+
+    /** The results of the call, once it has completed */
+    def get(): WitOption[WitResult[Unit, String]] = js.native
+  }
+
+  /** A pending call of `get-items` on a remote worker */
+  @js.native
+  @JSImport("golem:shop-stub/stub-shop@1.0.0", "FutureGetItemsResult")
+  class FutureGetItemsResult extends js.Object {
+    /** Creates a pollable that is ready once the call has completed */
+    def subscribe(): Pollable = js.native
+
+    /** The results of the call, once it has completed */
+    def get(): WitOption[WitList[Item]] = js.native
+  }
+
+  /** A pending call of `clear` on a remote worker */
+  @js.native
+  @JSImport("golem:shop-stub/stub-shop@1.0.0", "FutureClearResult")
+  class FutureClearResult extends js.Object {
+    /** Creates a pollable that is ready once the call has completed */
+    def subscribe(): Pollable = js.native
+
+    /** The results of the call, once it has completed */
+    def get(): WitOption[Unit] = js.native
+  }
+
+  /** A pending call of `clone` on a remote worker */
+  @js.native
+  @JSImport("golem:shop-stub/stub-shop@1.0.0", "FutureCloneResult")
+  class FutureCloneResult extends js.Object {
+    /** Creates a pollable that is ready once the call has completed */
+    def subscribe(): Pollable = js.native
+
+    /** The results of the call, once it has completed */
+    def get(): WitOption[Unit] = js.native
+  }
+
+  /** `pollable` represents a single I/O event which may be ready, or not. */
+  @js.native
+  @JSImport("wasi:io/poll@0.2.0", "Pollable")
+  class Pollable extends js.Object {
+    /** Return the readiness of a pollable. This function never blocks.
+      *
+      * Returns `true` when the pollable is ready, and `false` otherwise.
+      *
+      * @return `bool`
+      */
+    def ready(): Boolean = js.native
+
+    /** `block` returns immediately if the pollable is ready, and otherwise
+      * blocks until ready.
+      *
+      * This function is equivalent to calling `poll.poll` on a list
+      * containing only this pollable.
+      */
+    def block(): Unit = js.native
+  }
+
+  /** A poll API intended to let users wait for I/O events on multiple handles
+    * at once.
+    */
+  @js.native
+  @JSImport("wasi:io/poll@0.2.0", JSImport.Namespace)
+  object Poll extends js.Object {
+    /** Poll for completion on a set of pollables.
+      *
+      * This function takes a list of pollables, which identify I/O sources of
+      * interest, and waits until one or more of the events is ready for I/O.
+      *
+      * The result `list<u32>` contains one or more indices of handles in the
+      * argument list that is ready for I/O.
+      *
+      * @param in `list<borrow<pollable>>`
+      * @return `list<u32>`
+      */
+    def poll(in: WitList[Pollable]): WitList[Integer] = js.native
+  }
+
+  /** The URI of a worker, such as `urn:worker:<component-id>/<worker-name>` */
+  sealed trait Uri extends js.Object {
+    val value: String
+  }
+
+  object Uri {
+    def apply(value: String): Uri = {
+      val value0: String = value
+
+      new Uri {
+        val value: String = value0
+      }
+    }
+
+    /** The structural methods of `Uri`, which leave its JS representation untouched */
+    implicit final class Ops(self: Uri) extends AnyVal {
+      /** A new `Uri` with the given fields replaced */
+      def copy(value: String = self.value): Uri = Uri(value)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Uri): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Uri` */
+      def show: String = "Uri" + Structural.show(self)
+    }
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
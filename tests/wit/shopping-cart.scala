@@ -1,110 +1,299 @@
+// Generated by golem-scalajs-wit-bindgen
 
-                // Generated by golem-scalajs-wit-bindgen
-                package tests
-
-                import scala.scalajs.js
-                import scala.scalajs.js.JSConverters._
-
-                
-                sealed trait ProductItem extends js.Object {
-                    val productId: String
-val name: String
-val price: Float
-val quantity: Integer
-                }
-                object ProductItem {
-                    def apply(productId: String, name: String, price: Float, quantity: Integer): ProductItem = {
-                        val productId0: String = productId
-val name0: String = name
-val price0: Float = price
-val quantity0: Integer = quantity
-
-                        new ProductItem {
-                            val productId: String = productId0
-val name: String = name0
-val price: Float = price0
-val quantity: Integer = quantity0
-                        }
-                    }
-                }
-            
-
-                sealed trait OrderConfirmation extends js.Object {
-                    val orderId: String
-                }
-                object OrderConfirmation {
-                    def apply(orderId: String): OrderConfirmation = {
-                        val orderId0: String = orderId
-
-                        new OrderConfirmation {
-                            val orderId: String = orderId0
-                        }
-                    }
-                }
-            
-
-                
-                sealed trait CheckoutResult extends js.Object { self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }
-
-                object CheckoutResult {
-                    
-                            def error(value: String) = new CheckoutResult {
-                                type Type = String
-                                
-                                val tag: String = "error"
-                                override val `val`: js.UndefOr[Type] = value
-                            }
-                        
-
-                            def success(value: OrderConfirmation) = new CheckoutResult {
-                                type Type = OrderConfirmation
-                                
-                                val tag: String = "success"
-                                override val `val`: js.UndefOr[Type] = value
-                            }
-                        
-                }
-            
-
-                trait Api {
-                    type WitResult[+Ok, +Err] = Ok
-                    object WitResult {
-                        def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
-
-                        def err[Err](value: Err): WitResult[Nothing, Err] = throw js.JavaScriptException(value)
-
-                        val unit: WitResult[Unit, Nothing] = ()
-                    }
-
-                    type WitOption[+A] = js.UndefOr[A]
-                    object WitOption {
-                        def some[A](value: A): WitOption[A] = value
-
-                        val none: WitOption[Nothing] = js.undefined
-
-                        def fromOption[A](option: Option[A]) =
-                        option match {
-                            case Some(value) => value.asInstanceOf[js.UndefOr[A]]
-                            case None        => js.undefined
-                        }
-                    }
-
-                    type WitList[A] = js.Array[A]
-                    object WitList {
-                        def fromList[A](list: List[A]): WitList[A] = list.toJSArray
-                    }
-                    
-                    def initializeCart(userId: String): WitResult[String, String]
-def addItem(item: ProductItem): Unit
-def removeItem(productId: String): Unit
-def updateItemQuantity(productId: String, quantity: Integer): Unit
-def checkout(): CheckoutResult
-def getCartContents(): WitList[ProductItem]
-def getFirstItem(): WitOption[ProductItem]
-                }
-            
\ No newline at end of file
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
+
+package tests {
+  sealed trait ProductItem extends js.Object {
+    val productId: String
+    val name: String
+    val price: Float
+    val quantity: Integer
+  }
+
+  object ProductItem {
+    def apply(
+        productId: String,
+        name: String,
+        price: Float,
+        quantity: Integer
+    ): ProductItem = {
+      val productId0: String = productId
+      val name0: String = name
+      val price0: Float = price
+      val quantity0: Integer = quantity
+
+      new ProductItem {
+        val productId: String = productId0
+        val name: String = name0
+        val price: Float = price0
+        val quantity: Integer = quantity0
+      }
+    }
+
+    /** The structural methods of `ProductItem`, which leave its JS representation untouched */
+    implicit final class Ops(self: ProductItem) extends AnyVal {
+      /** A new `ProductItem` with the given fields replaced */
+      def copy(
+          productId: String = self.productId,
+          name: String = self.name,
+          price: Float = self.price,
+          quantity: Integer = self.quantity
+      ): ProductItem = ProductItem(productId, name, price, quantity)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: ProductItem): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `ProductItem` */
+      def show: String = "ProductItem" + Structural.show(self)
+    }
+  }
+
+  sealed trait OrderConfirmation extends js.Object {
+    val orderId: String
+  }
+
+  object OrderConfirmation {
+    def apply(orderId: String): OrderConfirmation = {
+      val orderId0: String = orderId
+
+      new OrderConfirmation {
+        val orderId: String = orderId0
+      }
+    }
+
+    /** The structural methods of `OrderConfirmation`, which leave its JS representation untouched */
+    implicit final class Ops(self: OrderConfirmation) extends AnyVal {
+      /** A new `OrderConfirmation` with the given fields replaced */
+      def copy(orderId: String = self.orderId): OrderConfirmation =
+        OrderConfirmation(orderId)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: OrderConfirmation): Boolean =
+        Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `OrderConfirmation` */
+      def show: String = "OrderConfirmation" + Structural.show(self)
+    }
+  }
+
+  sealed trait CheckoutResult extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object CheckoutResult {
+    def error(value: String) = new CheckoutResult {
+      type Type = String
+      val tag: String = "error"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def success(value: OrderConfirmation) = new CheckoutResult {
+      type Type = OrderConfirmation
+      val tag: String = "success"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `CheckoutResult`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Error(value: String) extends Case
+      final case class Success(value: OrderConfirmation) extends Case
+    }
+
+    /** The pattern matching and structural methods of `CheckoutResult` */
+    implicit final class Ops(self: CheckoutResult) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](error: String => A, success: OrderConfirmation => A): A = self.tag match {
+        case "error" => error(self.`val`.asInstanceOf[String])
+        case "success" => success(self.`val`.asInstanceOf[OrderConfirmation])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of CheckoutResult")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Error(_), Case.Success(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: CheckoutResult): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `CheckoutResult` */
+      def show: String = "CheckoutResult." + Structural.show(self)
+    }
+  }
+
+  trait Api {
+    def initializeCart(userId: String): WitResult[String, String]
+    def addItem(item: ProductItem): Unit
+    def removeItem(productId: String): Unit
+    def updateItemQuantity(productId: String, quantity: Integer): Unit
+    def checkout(): CheckoutResult
+    def getCartContents(): WitList[ProductItem]
+    def getFirstItem(): WitOption[ProductItem]
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `pack:name/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("initializeCart")
+    override def initializeCart(userId: String): WitResult[String, String] =
+      registered.initializeCart(userId)
+
+    @JSExport("addItem")
+    override def addItem(item: ProductItem): Unit = registered.addItem(item)
+
+    @JSExport("removeItem")
+    override def removeItem(productId: String): Unit =
+      registered.removeItem(productId)
+
+    @JSExport("updateItemQuantity")
+    override def updateItemQuantity(
+        productId: String,
+        quantity: Integer
+    ): Unit = registered.updateItemQuantity(productId, quantity)
+
+    @JSExport("checkout")
+    override def checkout(): CheckoutResult = registered.checkout()
+
+    @JSExport("getCartContents")
+    override def getCartContents(): WitList[ProductItem] =
+      registered.getCartContents()
+
+    @JSExport("getFirstItem")
+    override def getFirstItem(): WitOption[ProductItem] =
+      registered.getFirstItem()
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -1,271 +1,711 @@
-
-                // Generated by golem-scalajs-wit-bindgen
-                package tests
-
-                import scala.scalajs.js
-                import scala.scalajs.js.JSConverters._
-
-                
-                sealed trait T20 extends js.Object {
-                    
-                }
-                object T20 {
-                    def apply(): T20 = {
-                        
-
-                        new T20 {
-                            
-                        }
-                    }
-                }
-            
-
-                sealed trait T21 extends js.Object {
-                    val a: Integer
-                }
-                object T21 {
-                    def apply(a: Integer): T21 = {
-                        val a0: Integer = a
-
-                        new T21 {
-                            val a: Integer = a0
-                        }
-                    }
-                }
-            
-
-                sealed trait T22 extends js.Object {
-                    val a: Integer
-                }
-                object T22 {
-                    def apply(a: Integer): T22 = {
-                        val a0: Integer = a
-
-                        new T22 {
-                            val a: Integer = a0
-                        }
-                    }
-                }
-            
-
-                sealed trait T23 extends js.Object {
-                    val a: Integer
-val b: Long
-                }
-                object T23 {
-                    def apply(a: Integer, b: Long): T23 = {
-                        val a0: Integer = a
-val b0: Long = b
-
-                        new T23 {
-                            val a: Integer = a0
-val b: Long = b0
-                        }
-                    }
-                }
-            
-
-                sealed trait T24 extends js.Object {
-                    val a: Integer
-val b: Long
-                }
-                object T24 {
-                    def apply(a: Integer, b: Long): T24 = {
-                        val a0: Integer = a
-val b0: Long = b
-
-                        new T24 {
-                            val a: Integer = a0
-val b: Long = b0
-                        }
-                    }
-                }
-            
-
-                sealed trait T25 extends js.Object {
-                    val x: Integer
-                }
-                object T25 {
-                    def apply(x: Integer): T25 = {
-                        val x0: Integer = x
-
-                        new T25 {
-                            val x: Integer = x0
-                        }
-                    }
-                }
-            
-
-                sealed trait Record extends js.Object {
-                    
-                }
-                object Record {
-                    def apply(): Record = {
-                        
-
-                        new Record {
-                            
-                        }
-                    }
-                }
-            
-
-                
-                sealed trait T33 extends js.Object { self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }
-
-                object T33 {
-                    
-                            val a = new T33 {
-                                type Type = Nothing
-                                
-                                val tag: String = "a"
-                                
-                            }
-                        
-                }
-            
-
-                sealed trait T34 extends js.Object { self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }
-
-                object T34 {
-                    
-                            val a = new T34 {
-                                type Type = Nothing
-                                
-                                val tag: String = "a"
-                                
-                            }
-                        
-
-                            val b = new T34 {
-                                type Type = Nothing
-                                
-                                val tag: String = "b"
-                                
-                            }
-                        
-                }
-            
-
-                sealed trait T35 extends js.Object { self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }
-
-                object T35 {
-                    
-                            val a = new T35 {
-                                type Type = Nothing
-                                
-                                val tag: String = "a"
-                                
-                            }
-                        
-
-                            val b = new T35 {
-                                type Type = Nothing
-                                
-                                val tag: String = "b"
-                                
-                            }
-                        
-                }
-            
-
-                sealed trait T36 extends js.Object { self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }
-
-                object T36 {
-                    
-                            val a = new T36 {
-                                type Type = Nothing
-                                
-                                val tag: String = "a"
-                                
-                            }
-                        
-
-                            def b(value: Integer) = new T36 {
-                                type Type = Integer
-                                
-                                val tag: String = "b"
-                                override val `val`: js.UndefOr[Type] = value
-                            }
-                        
-                }
-            
-
-                sealed trait T37 extends js.Object { self =>
-                    type Type
-
-                    val tag: String
-                    val `val`: js.UndefOr[Type]
-                }
-
-                object T37 {
-                    
-                            val a = new T37 {
-                                type Type = Nothing
-                                
-                                val tag: String = "a"
-                                
-                            }
-                        
-
-                            def b(value: WitOption[Integer]) = new T37 {
-                                type Type = WitOption[Integer]
-                                
-                                val tag: String = "b"
-                                override val `val`: js.UndefOr[Type] = value
-                            }
-                        
-                }
-            
-
-                trait Api {
-                    type WitResult[+Ok, +Err] = Ok
-                    object WitResult {
-                        def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
-
-                        def err[Err](value: Err): WitResult[Nothing, Err] = throw js.JavaScriptException(value)
-
-                        val unit: WitResult[Unit, Nothing] = ()
-                    }
-
-                    type WitOption[+A] = js.UndefOr[A]
-                    object WitOption {
-                        def some[A](value: A): WitOption[A] = value
-
-                        val none: WitOption[Nothing] = js.undefined
-
-                        def fromOption[A](option: Option[A]) =
-                        option match {
-                            case Some(value) => value.asInstanceOf[js.UndefOr[A]]
-                            case None        => js.undefined
-                        }
-                    }
-
-                    type WitList[A] = js.Array[A]
-                    object WitList {
-                        def fromList[A](list: List[A]): WitList[A] = list.toJSArray
-                    }
-                    
-                    
-                }
-            
\ No newline at end of file
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  type T1 = Byte
+  type T2 = Short
+  type T3 = Integer
+  type T4 = Long
+  type T5 = Byte
+  type T6 = Short
+  type T7 = Integer
+  type T8 = Long
+  type T9A = Float
+  type T9B = Float
+  type T10A = Double
+  type T10B = Double
+  type T11 = Char
+  type T12 = WitList[Char]
+  type T13 = String
+  type T14 = WitOption[Integer]
+  type T15 = WitResult[Integer, Integer]
+  type T16 = WitResult[Unit, Integer]
+  type T17 = WitResult[Integer, Unit]
+  type T18 = WitResult[Unit, Unit]
+  type T29 = js.Tuple2[Integer, Long]
+  type T43 = Boolean
+  type T44 = String
+  type T45 = WitList[WitList[WitList[T32]]]
+  type T46 = T44
+  type T47 = T44
+
+  /** type order doesn't matter */
+  type Foo = Bar
+
+  type Bar = Integer
+}
+
+package tests {
+  sealed trait T20 extends js.Object
+
+  object T20 {
+    def apply(): T20 = {
+      new T20 {}
+    }
+
+    /** The structural methods of `T20`, which leave its JS representation untouched */
+    implicit final class Ops(self: T20) extends AnyVal {
+      /** A new `T20` with the given fields replaced */
+      def copy(): T20 = T20()
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T20): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T20` */
+      def show: String = "T20" + Structural.show(self)
+    }
+  }
+
+  sealed trait T21 extends js.Object {
+    val a: Integer
+  }
+
+  object T21 {
+    def apply(a: Integer): T21 = {
+      val a0: Integer = a
+
+      new T21 {
+        val a: Integer = a0
+      }
+    }
+
+    /** The structural methods of `T21`, which leave its JS representation untouched */
+    implicit final class Ops(self: T21) extends AnyVal {
+      /** A new `T21` with the given fields replaced */
+      def copy(a: Integer = self.a): T21 = T21(a)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T21): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T21` */
+      def show: String = "T21" + Structural.show(self)
+    }
+  }
+
+  sealed trait T22 extends js.Object {
+    val a: Integer
+  }
+
+  object T22 {
+    def apply(a: Integer): T22 = {
+      val a0: Integer = a
+
+      new T22 {
+        val a: Integer = a0
+      }
+    }
+
+    /** The structural methods of `T22`, which leave its JS representation untouched */
+    implicit final class Ops(self: T22) extends AnyVal {
+      /** A new `T22` with the given fields replaced */
+      def copy(a: Integer = self.a): T22 = T22(a)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T22): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T22` */
+      def show: String = "T22" + Structural.show(self)
+    }
+  }
+
+  sealed trait T23 extends js.Object {
+    val a: Integer
+    val b: Long
+  }
+
+  object T23 {
+    def apply(a: Integer, b: Long): T23 = {
+      val a0: Integer = a
+      val b0: Long = b
+
+      new T23 {
+        val a: Integer = a0
+        val b: Long = b0
+      }
+    }
+
+    /** The structural methods of `T23`, which leave its JS representation untouched */
+    implicit final class Ops(self: T23) extends AnyVal {
+      /** A new `T23` with the given fields replaced */
+      def copy(a: Integer = self.a, b: Long = self.b): T23 = T23(a, b)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T23): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T23` */
+      def show: String = "T23" + Structural.show(self)
+    }
+  }
+
+  sealed trait T24 extends js.Object {
+    val a: Integer
+    val b: Long
+  }
+
+  object T24 {
+    def apply(a: Integer, b: Long): T24 = {
+      val a0: Integer = a
+      val b0: Long = b
+
+      new T24 {
+        val a: Integer = a0
+        val b: Long = b0
+      }
+    }
+
+    /** The structural methods of `T24`, which leave its JS representation untouched */
+    implicit final class Ops(self: T24) extends AnyVal {
+      /** A new `T24` with the given fields replaced */
+      def copy(a: Integer = self.a, b: Long = self.b): T24 = T24(a, b)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T24): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T24` */
+      def show: String = "T24" + Structural.show(self)
+    }
+  }
+
+  sealed trait T25 extends js.Object {
+    val x: Integer
+  }
+
+  object T25 {
+    def apply(x: Integer): T25 = {
+      val x0: Integer = x
+
+      new T25 {
+        val x: Integer = x0
+      }
+    }
+
+    /** The structural methods of `T25`, which leave its JS representation untouched */
+    implicit final class Ops(self: T25) extends AnyVal {
+      /** A new `T25` with the given fields replaced */
+      def copy(x: Integer = self.x): T25 = T25(x)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T25): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T25` */
+      def show: String = "T25" + Structural.show(self)
+    }
+  }
+
+  sealed trait Record extends js.Object
+
+  object Record {
+    def apply(): Record = {
+      new Record {}
+    }
+
+    /** The structural methods of `Record`, which leave its JS representation untouched */
+    implicit final class Ops(self: Record) extends AnyVal {
+      /** A new `Record` with the given fields replaced */
+      def copy(): Record = Record()
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Record): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Record` */
+      def show: String = "Record" + Structural.show(self)
+    }
+  }
+
+  sealed trait T30 extends js.Object
+
+  object T30 {
+    def apply(): T30 = {
+      new T30 {}
+    }
+
+    /** The structural methods of `T30`, which leave its JS representation untouched */
+    implicit final class Ops(self: T30) extends AnyVal {
+      /** A new `T30` with the given fields replaced */
+      def copy(): T30 = T30()
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T30): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T30` */
+      def show: String = "T30" + Structural.show(self)
+    }
+  }
+
+  sealed trait T31 extends js.Object {
+    val a: Boolean
+    val b: Boolean
+    val c: Boolean
+  }
+
+  object T31 {
+    def apply(
+        a: Boolean = false,
+        b: Boolean = false,
+        c: Boolean = false
+    ): T31 = {
+      val a0: Boolean = a
+      val b0: Boolean = b
+      val c0: Boolean = c
+
+      new T31 {
+        val a: Boolean = a0
+        val b: Boolean = b0
+        val c: Boolean = c0
+      }
+    }
+
+    /** The structural methods of `T31`, which leave its JS representation untouched */
+    implicit final class Ops(self: T31) extends AnyVal {
+      /** A new `T31` with the given fields replaced */
+      def copy(
+          a: Boolean = self.a,
+          b: Boolean = self.b,
+          c: Boolean = self.c
+      ): T31 = T31(a, b, c)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T31): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T31` */
+      def show: String = "T31" + Structural.show(self)
+    }
+  }
+
+  sealed trait T32 extends js.Object {
+    val a: Boolean
+    val b: Boolean
+    val c: Boolean
+  }
+
+  object T32 {
+    def apply(
+        a: Boolean = false,
+        b: Boolean = false,
+        c: Boolean = false
+    ): T32 = {
+      val a0: Boolean = a
+      val b0: Boolean = b
+      val c0: Boolean = c
+
+      new T32 {
+        val a: Boolean = a0
+        val b: Boolean = b0
+        val c: Boolean = c0
+      }
+    }
+
+    /** The structural methods of `T32`, which leave its JS representation untouched */
+    implicit final class Ops(self: T32) extends AnyVal {
+      /** A new `T32` with the given fields replaced */
+      def copy(
+          a: Boolean = self.a,
+          b: Boolean = self.b,
+          c: Boolean = self.c
+      ): T32 = T32(a, b, c)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T32): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T32` */
+      def show: String = "T32" + Structural.show(self)
+    }
+  }
+
+  sealed trait T33 extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object T33 {
+    val a = new T33 {
+      type Type = Nothing
+      val tag: String = "a"
+    }
+
+    /** A case of `T33`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object A extends Case
+    }
+
+    /** The pattern matching and structural methods of `T33` */
+    implicit final class Ops(self: T33) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](a: => A): A = self.tag match {
+        case "a" => a
+        case tag => throw new IllegalStateException(s"Unknown case $tag of T33")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.A)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T33): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T33` */
+      def show: String = "T33." + Structural.show(self)
+    }
+  }
+
+  sealed trait T34 extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object T34 {
+    val a = new T34 {
+      type Type = Nothing
+      val tag: String = "a"
+    }
+
+    val b = new T34 {
+      type Type = Nothing
+      val tag: String = "b"
+    }
+
+    /** A case of `T34`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object A extends Case
+      case object B extends Case
+    }
+
+    /** The pattern matching and structural methods of `T34` */
+    implicit final class Ops(self: T34) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](a: => A, b: => A): A = self.tag match {
+        case "a" => a
+        case "b" => b
+        case tag => throw new IllegalStateException(s"Unknown case $tag of T34")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.A, Case.B)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T34): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T34` */
+      def show: String = "T34." + Structural.show(self)
+    }
+  }
+
+  sealed trait T35 extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object T35 {
+    val a = new T35 {
+      type Type = Nothing
+      val tag: String = "a"
+    }
+
+    val b = new T35 {
+      type Type = Nothing
+      val tag: String = "b"
+    }
+
+    /** A case of `T35`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object A extends Case
+      case object B extends Case
+    }
+
+    /** The pattern matching and structural methods of `T35` */
+    implicit final class Ops(self: T35) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](a: => A, b: => A): A = self.tag match {
+        case "a" => a
+        case "b" => b
+        case tag => throw new IllegalStateException(s"Unknown case $tag of T35")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.A, Case.B)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T35): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T35` */
+      def show: String = "T35." + Structural.show(self)
+    }
+  }
+
+  sealed trait T36 extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object T36 {
+    val a = new T36 {
+      type Type = Nothing
+      val tag: String = "a"
+    }
+
+    def b(value: Integer) = new T36 {
+      type Type = Integer
+      val tag: String = "b"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `T36`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object A extends Case
+      final case class B(value: Integer) extends Case
+    }
+
+    /** The pattern matching and structural methods of `T36` */
+    implicit final class Ops(self: T36) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](a: => A, b: Integer => A): A = self.tag match {
+        case "a" => a
+        case "b" => b(self.`val`.asInstanceOf[Integer])
+        case tag => throw new IllegalStateException(s"Unknown case $tag of T36")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.A, Case.B(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T36): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T36` */
+      def show: String = "T36." + Structural.show(self)
+    }
+  }
+
+  sealed trait T37 extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object T37 {
+    val a = new T37 {
+      type Type = Nothing
+      val tag: String = "a"
+    }
+
+    def b(value: WitOption[Integer]) = new T37 {
+      type Type = WitOption[Integer]
+      val tag: String = "b"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `T37`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object A extends Case
+      final case class B(value: WitOption[Integer]) extends Case
+    }
+
+    /** The pattern matching and structural methods of `T37` */
+    implicit final class Ops(self: T37) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](a: => A, b: WitOption[Integer] => A): A = self.tag match {
+        case "a" => a
+        case "b" => b(self.`val`.asInstanceOf[WitOption[Integer]])
+        case tag => throw new IllegalStateException(s"Unknown case $tag of T37")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.A, Case.B(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: T37): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `T37` */
+      def show: String = "T37." + Structural.show(self)
+    }
+  }
+
+  @js.native
+  sealed trait T41 extends js.Any
+
+  object T41 {
+    val a: T41 = "a".asInstanceOf[T41]
+    val b: T41 = "b".asInstanceOf[T41]
+    val c: T41 = "c".asInstanceOf[T41]
+    val values: js.Array[T41] = js.Array(a, b, c)
+  }
+
+  @js.native
+  sealed trait T42 extends js.Any
+
+  object T42 {
+    val a: T42 = "a".asInstanceOf[T42]
+    val b: T42 = "b".asInstanceOf[T42]
+    val c: T42 = "c".asInstanceOf[T42]
+    val values: js.Array[T42] = js.Array(a, b, c)
+  }
+
+  trait Api
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
@@ -0,0 +1,806 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+}
+
+package tests {
+  sealed trait Address extends js.Object {
+    val street: String
+    val zip: String
+  }
+
+  object Address {
+    def apply(street: String, zip: String): Address = {
+      val street0: String = street
+      val zip0: String = zip
+
+      new Address {
+        val street: String = street0
+        val zip: String = zip0
+      }
+    }
+
+    /** The structural methods of `Address`, which leave its JS representation untouched */
+    implicit final class Ops(self: Address) extends AnyVal {
+      /** A new `Address` with the given fields replaced */
+      def copy(street: String = self.street, zip: String = self.zip): Address =
+        Address(street, zip)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Address): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Address` */
+      def show: String = "Address" + Structural.show(self)
+    }
+
+    implicit val validator: Validation.Validator[Address] =
+      Validation.record[Address](
+        Validation.Field("street", "street", Validation.string),
+        Validation.Field("zip", "zip", Validation.string)
+      )
+  }
+
+  @js.native
+  sealed trait Role extends js.Any
+
+  object Role {
+    val admin: Role = "admin".asInstanceOf[Role]
+    val member: Role = "member".asInstanceOf[Role]
+    val values: js.Array[Role] = js.Array(admin, member)
+
+    implicit val validator: Validation.Validator[Role] =
+      Validation.enumeration[Role]("admin", "member")
+  }
+
+  sealed trait Permissions extends js.Object {
+    val read: Boolean
+    val write: Boolean
+  }
+
+  object Permissions {
+    def apply(read: Boolean = false, write: Boolean = false): Permissions = {
+      val read0: Boolean = read
+      val write0: Boolean = write
+
+      new Permissions {
+        val read: Boolean = read0
+        val write: Boolean = write0
+      }
+    }
+
+    /** The structural methods of `Permissions`, which leave its JS representation untouched */
+    implicit final class Ops(self: Permissions) extends AnyVal {
+      /** A new `Permissions` with the given fields replaced */
+      def copy(
+          read: Boolean = self.read,
+          write: Boolean = self.write
+      ): Permissions = Permissions(read, write)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Permissions): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Permissions` */
+      def show: String = "Permissions" + Structural.show(self)
+    }
+
+    implicit val validator: Validation.Validator[Permissions] =
+      Validation.flags[Permissions](
+        Validation.Flag("read", "read"),
+        Validation.Flag("write", "write")
+      )
+  }
+
+  sealed trait Contact extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Contact {
+    def email(value: String) = new Contact {
+      type Type = String
+      val tag: String = "email"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def phone(value: js.Tuple2[Short, Integer]) = new Contact {
+      type Type = js.Tuple2[Short, Integer]
+      val tag: String = "phone"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val none = new Contact {
+      type Type = Nothing
+      val tag: String = "none"
+    }
+
+    /** A case of `Contact`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      final case class Email(value: String) extends Case
+      final case class Phone(value: js.Tuple2[Short, Integer]) extends Case
+      case object None extends Case
+    }
+
+    /** The pattern matching and structural methods of `Contact` */
+    implicit final class Ops(self: Contact) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          email: String => A,
+          phone: js.Tuple2[Short, Integer] => A,
+          none: => A
+      ): A = self.tag match {
+        case "email" => email(self.`val`.asInstanceOf[String])
+        case "phone" =>
+          phone(self.`val`.asInstanceOf[js.Tuple2[Short, Integer]])
+        case "none" => none
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Contact")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Email(_), Case.Phone(_), Case.None)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Contact): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Contact` */
+      def show: String = "Contact." + Structural.show(self)
+    }
+
+    implicit val validator: Validation.Validator[Contact] =
+      Validation.variant[Contact](
+        Validation.Case("email", Some(Validation.string)),
+        Validation.Case(
+          "phone",
+          Some(
+            Validation.tuple[js.Tuple2[Short, Integer]](Validation.u16, Validation.u32)
+          )
+        ),
+        Validation.Case("none", None)
+      )
+  }
+
+  sealed trait User extends js.Object {
+    val name: String
+    val age: Byte
+    val addresses: WitList[Address]
+    val role: Role
+    val permissions: Permissions
+    val contact: Contact
+    val nickname: WitOption[String]
+  }
+
+  object User {
+    def apply(
+        name: String,
+        age: Byte,
+        addresses: WitList[Address],
+        role: Role,
+        permissions: Permissions,
+        contact: Contact,
+        nickname: WitOption[String]
+    ): User = {
+      val name0: String = name
+      val age0: Byte = age
+      val addresses0: WitList[Address] = addresses
+      val role0: Role = role
+      val permissions0: Permissions = permissions
+      val contact0: Contact = contact
+      val nickname0: WitOption[String] = nickname
+
+      new User {
+        val name: String = name0
+        val age: Byte = age0
+        val addresses: WitList[Address] = addresses0
+        val role: Role = role0
+        val permissions: Permissions = permissions0
+        val contact: Contact = contact0
+        val nickname: WitOption[String] = nickname0
+      }
+    }
+
+    /** The structural methods of `User`, which leave its JS representation untouched */
+    implicit final class Ops(self: User) extends AnyVal {
+      /** A new `User` with the given fields replaced */
+      def copy(
+          name: String = self.name,
+          age: Byte = self.age,
+          addresses: WitList[Address] = self.addresses,
+          role: Role = self.role,
+          permissions: Permissions = self.permissions,
+          contact: Contact = self.contact,
+          nickname: WitOption[String] = self.nickname
+      ): User = User(name, age, addresses, role, permissions, contact, nickname)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: User): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `User` */
+      def show: String = "User" + Structural.show(self)
+    }
+
+    implicit val validator: Validation.Validator[User] =
+      Validation.record[User](
+        Validation.Field("name", "name", Validation.string),
+        Validation.Field("age", "age", Validation.u8),
+        Validation.Field(
+          "addresses",
+          "addresses",
+          Validation.list(Address.validator)
+        ),
+        Validation.Field("role", "role", Role.validator),
+        Validation.Field("permissions", "permissions", Permissions.validator),
+        Validation.Field("contact", "contact", Contact.validator),
+        Validation.Field(
+          "nickname",
+          "nickname",
+          Validation.option(Validation.string)
+        )
+      )
+  }
+
+  /** The results of `bounds` */
+  sealed trait BoundsResult extends js.Object {
+    val lowest: Integer
+    val highest: Integer
+  }
+
+  object BoundsResult {
+    def apply(lowest: Integer, highest: Integer): BoundsResult = {
+      val lowest0: Integer = lowest
+      val highest0: Integer = highest
+
+      new BoundsResult {
+        val lowest: Integer = lowest0
+        val highest: Integer = highest0
+      }
+    }
+
+    /** The structural methods of `BoundsResult`, which leave its JS representation untouched */
+    implicit final class Ops(self: BoundsResult) extends AnyVal {
+      /** A new `BoundsResult` with the given fields replaced */
+      def copy(
+          lowest: Integer = self.lowest,
+          highest: Integer = self.highest
+      ): BoundsResult = BoundsResult(lowest, highest)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: BoundsResult): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `BoundsResult` */
+      def show: String = "BoundsResult" + Structural.show(self)
+    }
+
+    implicit val validator: Validation.Validator[BoundsResult] =
+      Validation.record[BoundsResult](
+        Validation.Field("lowest", "lowest", Validation.s32),
+        Validation.Field("highest", "highest", Validation.s32)
+      )
+  }
+
+  trait Api {
+    def getUser(id: Long): WitOption[User]
+    def rename(id: Long, name: String): WitResult[User, String]
+    def bounds(): BoundsResult
+    def clear(): Unit
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:users/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("getUser")
+    override def getUser(id: Long): WitOption[User] =
+      Validation.results("get-user", Validation.option(User.validator))(
+        registered.getUser(id)
+      )
+
+    @JSExport("rename")
+    override def rename(id: Long, name: String): WitResult[User, String] =
+      Validation.results(
+        "rename",
+        Validation.result[User, String](Some(User.validator), Some(Validation.string))
+      )(registered.rename(id, name))
+
+    @JSExport("bounds")
+    override def bounds(): BoundsResult =
+      Validation.results("bounds", BoundsResult.validator)(registered.bounds())
+
+    @JSExport("clear")
+    override def clear(): Unit = registered.clear()
+  }
+
+  sealed trait Entry extends js.Object {
+    val key: String
+    val score: Double
+  }
+
+  object Entry {
+    def apply(key: String, score: Double): Entry = {
+      val key0: String = key
+      val score0: Double = score
+
+      new Entry {
+        val key: String = key0
+        val score: Double = score0
+      }
+    }
+
+    /** The structural methods of `Entry`, which leave its JS representation untouched */
+    implicit final class Ops(self: Entry) extends AnyVal {
+      /** A new `Entry` with the given fields replaced */
+      def copy(key: String = self.key, score: Double = self.score): Entry =
+        Entry(key, score)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Entry): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Entry` */
+      def show: String = "Entry" + Structural.show(self)
+    }
+
+    implicit val validator: Validation.Validator[Entry] =
+      Validation.record[Entry](
+        Validation.Field("key", "key", Validation.string),
+        Validation.Field("score", "score", Validation.f64)
+      )
+  }
+
+  @js.native
+  @JSImport("golem:users/directory", "Cursor")
+  class Cursor extends js.Object {
+    def next(): WitOption[Entry] = js.native
+  }
+
+  object Directory {
+    @js.native
+    @JSImport("golem:users/directory", JSImport.Namespace)
+    private object Native extends js.Object {
+      def publish(entry: Entry, tags: WitList[String]): Boolean = js.native
+      def forward(cursor: Cursor, initial: Char): Unit = js.native
+      def archive(user: User): Unit = js.native
+    }
+
+    def publish(entry: Entry, tags: WitList[String]): Boolean = {
+      Validation.arguments(
+        "publish",
+        ("entry", Entry.validator, entry),
+        ("tags", Validation.list(Validation.string), tags)
+      )
+
+      Native.publish(entry, tags)
+    }
+
+    def forward(cursor: Cursor, initial: Char): Unit = {
+      Validation.arguments("forward", ("initial", Validation.char, initial))
+      Native.forward(cursor, initial)
+    }
+
+    def archive(user: User): Unit = {
+      Validation.arguments("archive", ("user", User.validator, user))
+      Native.archive(user)
+    }
+  }
+
+  /** The validators of the JS representation of the WIT types.
+    *
+    * A validator returns the mismatches between a value and its WIT type, each of which
+    * starts with the WIT path of the mismatching value, such as `user.addresses[2].zip`.
+    * Record fields are selected by their WIT name, list items by their index in brackets,
+    * tuple items by their position, and variant payloads by the WIT name of their case.
+    * Unsigned integers are held in the Scala type of the same width as their two's
+    * complement, as the generators, WAVE and circe codecs do, so their validators accept the
+    * numbers in the unsigned range or in the range of their two's complement, both of which
+    * lower to the same WIT value. 64-bit integers are either `Long`s or `BigInt`s in that
+    * range. Results only check `ok` values, since errors are thrown rather than represented.
+    */
+  object Validation {
+    /** A check of the JS representation of a value of type `A`, returning the mismatches
+      * found under the WIT path `path`
+      */
+    trait Validator[A] {
+      def apply(value: Any, path: String): List[String]
+    }
+
+    object Validator {
+      /** A validator running `check` on the value and its WIT path */
+      def apply[A](check: (Any, String) => List[String]): Validator[A] = new Validator[A] {
+        def apply(value: Any, path: String): List[String] = check(value, path)
+      }
+    }
+
+    /** The error thrown when values crossing the component boundary do not fit their
+      * WIT types
+      */
+    final case class ValidationError(
+        context: String,
+        mismatches: List[String]
+    ) extends IllegalArgumentException(s"$context: ${mismatches.mkString("; ")}")
+
+    /** A record field, with its JS name, its WIT name and the validator of its value */
+    final case class Field(
+        name: String,
+        label: String,
+        validator: Validator[_]
+    )
+
+    /** A variant case, with its WIT name and the validator of its payload */
+    final case class Case(label: String, payload: Option[Validator[_]])
+
+    /** A flag, with its JS name and its WIT name */
+    final case class Flag(name: String, label: String)
+
+    /** A short description of a value in the mismatches */
+    private def describe(value: Any): String = value match {
+      case null => "null"
+      case text: String => "\"" + text + "\""
+      case _ if js.isUndefined(value) => "undefined"
+      case _ if js.Array.isArray(value) => "an array"
+      case _ if js.typeOf(value) == "object" => "an object"
+      case _ => value.toString
+    }
+
+    /** A validator checking `valid` on values, which are described as `expected` in the
+      * mismatches
+      */
+    private def expect[A](
+        expected: String
+    )(
+        valid: Any => Boolean
+    ): Validator[A] = Validator { (value, path) =>
+      if (valid(value))
+        Nil
+      else
+        List(s"$path: expected $expected, got ${describe(value)}")
+    }
+
+    /** Whether a value is a JS object that is not an array */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" && value != null && !js.Array.isArray(value)
+
+    /** Throws the mismatches, if any, as a `ValidationError` */
+    private def fail(context: String, mismatches: List[String]): Unit =
+      if (mismatches.nonEmpty) throw new ValidationError(context, mismatches)
+
+    /** Checks the arguments of `function`, given with their WIT name and their
+      * validator
+      */
+    def arguments(
+        function: String,
+        arguments: (String, Validator[_], Any)*
+    ): Unit = fail(
+      s"Invalid arguments of $function",
+      arguments.toList.flatMap { case (name, validator, value) =>
+        validator(value, name)
+      }
+    )
+
+    /** Checks the results of `function`, which are returned if they fit their WIT
+      * type
+      */
+    def results[A](function: String, validator: Validator[A])(value: A): A = {
+      fail(s"Invalid results of $function", validator(value, "result"))
+      value
+    }
+
+    /** The validator of `bool` */
+    val bool: Validator[Boolean] =
+      expect("a bool")(value => js.typeOf(value) == "boolean")
+
+    /** The validator of `s8` */
+    val s8: Validator[Byte] = expect("an integer between -128 and 127")(value =>
+      js.typeOf(value) == "number" &&
+        value.asInstanceOf[Double].isWhole &&
+        value.asInstanceOf[Double] >= -128d &&
+        value.asInstanceOf[Double] <= 127d
+    )
+
+    /** The validator of `u8` */
+    val u8: Validator[Byte] = expect("an integer between -128 and 255")(value =>
+      js.typeOf(value) == "number" &&
+        value.asInstanceOf[Double].isWhole &&
+        value.asInstanceOf[Double] >= -128d &&
+        value.asInstanceOf[Double] <= 255d
+    )
+
+    /** The validator of `s16` */
+    val s16: Validator[Short] =
+      expect("an integer between -32768 and 32767")(value =>
+        js.typeOf(value) == "number" &&
+          value.asInstanceOf[Double].isWhole &&
+          value.asInstanceOf[Double] >= -32768d &&
+          value.asInstanceOf[Double] <= 32767d
+      )
+
+    /** The validator of `u16` */
+    val u16: Validator[Short] =
+      expect("an integer between -32768 and 65535")(value =>
+        js.typeOf(value) == "number" &&
+          value.asInstanceOf[Double].isWhole &&
+          value.asInstanceOf[Double] >= -32768d &&
+          value.asInstanceOf[Double] <= 65535d
+      )
+
+    /** The validator of `s32` */
+    val s32: Validator[Integer] =
+      expect("an integer between -2147483648 and 2147483647")(value =>
+        js.typeOf(value) == "number" &&
+          value.asInstanceOf[Double].isWhole &&
+          value.asInstanceOf[Double] >= -2147483648d &&
+          value.asInstanceOf[Double] <= 2147483647d
+      )
+
+    /** The validator of `u32` */
+    val u32: Validator[Integer] =
+      expect("an integer between -2147483648 and 4294967295")(value =>
+        js.typeOf(value) == "number" &&
+          value.asInstanceOf[Double].isWhole &&
+          value.asInstanceOf[Double] >= -2147483648d &&
+          value.asInstanceOf[Double] <= 4294967295d
+      )
+
+    /** The validator of `s64` */
+    val s64: Validator[Long] =
+      expect("a Long or a BigInt between -9223372036854775808 and 9223372036854775807")(value =>
+        value.isInstanceOf[Long] ||
+          js.typeOf(value) == "bigint" &&
+            value.asInstanceOf[js.BigInt] >= js.BigInt("-9223372036854775808") &&
+            value.asInstanceOf[js.BigInt] <= js.BigInt("9223372036854775807")
+      )
+
+    /** The validator of `u64` */
+    val u64: Validator[Long] =
+      expect("a Long or a BigInt between -9223372036854775808 and 18446744073709551615")(value =>
+        value.isInstanceOf[Long] ||
+          js.typeOf(value) == "bigint" &&
+            value.asInstanceOf[js.BigInt] >= js.BigInt("-9223372036854775808") &&
+            value.asInstanceOf[js.BigInt] <= js.BigInt("18446744073709551615")
+      )
+
+    /** The validator of `f32` */
+    val f32: Validator[Float] = expect("an f32")(_.isInstanceOf[Float])
+
+    /** The validator of `f64` */
+    val f64: Validator[Double] =
+      expect("an f64")(value => js.typeOf(value) == "number")
+
+    /** The validator of `char` */
+    val char: Validator[Char] =
+      expect("a char that is not a surrogate")(value =>
+        value.isInstanceOf[Char] && !value.asInstanceOf[Char].isSurrogate
+      )
+
+    /** The validator of `string` */
+    val string: Validator[String] =
+      expect("a string")(value => js.typeOf(value) == "string")
+
+    /** The validator of `list<T>`, given the validator of `T` */
+    def list[A](item: Validator[A]): Validator[js.Array[A]] =
+      Validator { (value, path) =>
+        if (js.Array.isArray(value))
+          value.asInstanceOf[js.Array[Any]].toList.zipWithIndex.flatMap { case (element, i) =>
+            item(element, s"$path[$i]")
+          }
+        else
+          expect("a list")(_ => false)(value, path)
+      }
+
+    /** The validator of `option<T>`, given the validator of `T` */
+    def option[A](some: Validator[A]): Validator[js.UndefOr[A]] =
+      Validator { (value, path) =>
+        if (js.isUndefined(value)) Nil else some(value, path)
+      }
+
+    /** The validator of `result<T, E>`, given the optional validators of `T` and `E`, of which
+      * only `ok` values are checked
+      */
+    def result[A, E](
+        ok: Option[Validator[A]],
+        err: Option[Validator[E]]
+    ): Validator[A] =
+      Validator { (value, path) => ok.fold(List.empty[String])(_(value, path)) }
+
+    /** The validator of a tuple, given the validators of its items */
+    def tuple[A](items: Validator[_]*): Validator[A] =
+      Validator { (value, path) =>
+        if (js.Array.isArray(value) &&
+            value.asInstanceOf[js.Array[Any]].length == items.length)
+          items.toList.zip(value.asInstanceOf[js.Array[Any]].toList)
+            .zipWithIndex.flatMap { case ((item, element), i) =>
+              item(element, s"$path.$i")
+            }
+        else
+          expect(s"a tuple of ${items.length} items")(_ => false)(value, path)
+      }
+
+    /** The validator of a record, given its fields */
+    def record[A](fields: Field*): Validator[A] = Validator { (value, path) =>
+      if (isObject(value))
+        fields.toList.flatMap(field =>
+          field.validator(
+            value.asInstanceOf[js.Dynamic].selectDynamic(field.name),
+            s"$path.${field.label}"
+          )
+        )
+      else
+        expect("a record")(_ => false)(value, path)
+    }
+
+    /** The validator of a variant, given its cases */
+    def variant[A](cases: Case*): Validator[A] = Validator { (value, path) =>
+      if (isObject(value)) {
+        val variant = value.asInstanceOf[js.Dynamic]
+
+        cases.find(_.label == variant.tag.asInstanceOf[Any]) match {
+          case Some(Case(label, payload)) =>
+            payload.fold(List.empty[String])(_(variant.`val`, s"$path.$label"))
+          case None => List(s"$path: unknown case ${describe(variant.tag)}")
+        }
+      } else expect("a variant")(_ => false)(value, path)
+    }
+
+    /** The validator of an enum, given the WIT names of its cases */
+    def enumeration[A](cases: String*): Validator[A] =
+      Validator { (value, path) =>
+        if (cases.contains(value))
+          Nil
+        else
+          List(s"$path: unknown case ${describe(value)}")
+      }
+
+    /** The validator of flags, given its flags */
+    def flags[A](flags: Flag*): Validator[A] = Validator { (value, path) =>
+      if (isObject(value))
+        flags.toList.flatMap(flag =>
+          expect("a bool")(set =>
+            js.isUndefined(set) || js.typeOf(set) == "boolean"
+          )(
+            value.asInstanceOf[js.Dynamic].selectDynamic(flag.name),
+            s"$path.${flag.label}"
+          )
+        )
+      else
+        expect("flags")(_ => false)(value, path)
+    }
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}
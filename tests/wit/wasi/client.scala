@@ -0,0 +1,1802 @@
+// Generated by golem-scalajs-wit-bindgen
+
+import scala.scalajs.js
+import scala.scalajs.js.JSConverters._
+import scala.scalajs.js.annotation._
+
+package object tests {
+  type WitResult[+Ok, +Err] = Ok
+
+  object WitResult {
+    def ok[Ok](value: Ok): WitResult[Ok, Nothing] = value
+
+    def err[Err](value: Err): WitResult[Nothing, Err] =
+      throw js.JavaScriptException(value)
+
+    val unit: WitResult[Unit, Nothing] = ()
+  }
+
+  type WitOption[+A] = js.UndefOr[A]
+
+  object WitOption {
+    def some[A](value: A): WitOption[A] = value
+    val none: WitOption[Nothing] = js.undefined
+
+    def fromOption[A](option: Option[A]) = option match {
+      case Some(value) => value.asInstanceOf[js.UndefOr[A]]
+      case None => js.undefined
+    }
+  }
+
+  type WitList[A] = js.Array[A]
+
+  object WitList {
+    def fromList[A](list: List[A]): WitList[A] = list.toJSArray
+  }
+
+  /** An instant in time, in nanoseconds. An instant is relative to an
+    * unspecified initial value, and can only be compared to instances from
+    * the same monotonic-clock.
+    */
+  type Instant = Long
+
+  /** A duration of time, in nanoseconds. */
+  type Duration = Long
+
+  type IoError = Error
+
+  /** Field keys are always strings. */
+  type FieldKey = String
+
+  /** Field values should always be ASCII strings. However, in
+    * reality, HTTP implementations often have to interpret malformed values,
+    * so they are provided as a list of bytes.
+    */
+  type FieldValue = WitList[Byte]
+
+  /** Headers is an alias for Fields. */
+  type Headers = Fields
+
+  /** Trailers is an alias for Fields. */
+  type Trailers = Fields
+
+  /** This type corresponds to the HTTP standard Status Code. */
+  type StatusCode = Short
+}
+
+package tests {
+  trait Api {
+    def fetch(url: String): WitResult[WitList[Byte], String]
+  }
+
+  /** Exports the implementation of `Api` registered with `register` as `golem:client/api` */
+  @JSExportTopLevel("api")
+  object ApiExport extends Api {
+    private var implementation: Option[Api] = None
+
+    /** Registers the implementation the exported functions delegate to */
+    def register(api: Api): Unit = implementation = Some(api)
+
+    private def registered: Api = implementation match {
+      case Some(api) => api
+      case None =>
+        throw new IllegalStateException("No implementation of Api was registered")
+    }
+
+    @JSExport("fetch")
+    override def fetch(url: String): WitResult[WitList[Byte], String] =
+      registered.fetch(url)
+  }
+
+  /** A time and date in seconds plus nanoseconds. */
+  sealed trait Datetime extends js.Object {
+    val seconds: Long
+    val nanoseconds: Integer
+  }
+
+  object Datetime {
+    def apply(seconds: Long, nanoseconds: Integer): Datetime = {
+      val seconds0: Long = seconds
+      val nanoseconds0: Integer = nanoseconds
+
+      new Datetime {
+        val seconds: Long = seconds0
+        val nanoseconds: Integer = nanoseconds0
+      }
+    }
+
+    /** The structural methods of `Datetime`, which leave its JS representation untouched */
+    implicit final class Ops(self: Datetime) extends AnyVal {
+      /** A new `Datetime` with the given fields replaced */
+      def copy(
+          seconds: Long = self.seconds,
+          nanoseconds: Integer = self.nanoseconds
+      ): Datetime = Datetime(seconds, nanoseconds)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Datetime): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Datetime` */
+      def show: String = "Datetime" + Structural.show(self)
+    }
+  }
+
+  /** WASI Wall Clock is a clock API intended to let users query the current
+    * time. The name "wall" makes an analogy to a "clock on the wall", which
+    * is not necessarily monotonic as it may be reset.
+    */
+  @js.native
+  @JSImport("wasi:clocks/wall-clock@0.2.0", JSImport.Namespace)
+  object WallClock extends js.Object {
+    /** Read the current value of the clock.
+      *
+      * This clock is not monotonic, therefore calling this function repeatedly
+      * will not necessarily produce a sequence of non-decreasing values.
+      *
+      * @return `datetime`
+      */
+    def now(): Datetime = js.native
+
+    /** Query the resolution of the clock.
+      *
+      * @return `datetime`
+      */
+    def resolution(): Datetime = js.native
+  }
+
+  /** WASI Random is a random data API. */
+  @js.native
+  @JSImport("wasi:random/random@0.2.0", JSImport.Namespace)
+  object Random extends js.Object {
+    /** Return `len` cryptographically-secure random or pseudo-random bytes.
+      *
+      * @param len `u64`
+      * @return `list<u8>`
+      */
+    def getRandomBytes(len: Long): WitList[Byte] = js.native
+
+    /** Return a cryptographically-secure random or pseudo-random `u64` value.
+      *
+      * @return `u64`
+      */
+    def getRandomU64(): Long = js.native
+  }
+
+  /** The insecure-seed interface for seeding hash-map DoS resistance. */
+  @js.native
+  @JSImport("wasi:random/insecure-seed@0.2.0", JSImport.Namespace)
+  object InsecureSeed extends js.Object {
+    /** Return a 128-bit value that may contain a pseudo-random value.
+      *
+      * @return `tuple<u64, u64>`
+      */
+    def insecureSeed(): js.Tuple2[Long, Long] = js.native
+  }
+
+  /** `pollable` represents a single I/O event which may be ready, or not. */
+  @js.native
+  @JSImport("wasi:io/poll@0.2.0", "Pollable")
+  class Pollable extends js.Object {
+    /** Return the readiness of a pollable. This function never blocks.
+      *
+      * Returns `true` when the pollable is ready, and `false` otherwise.
+      *
+      * @return `bool`
+      */
+    def ready(): Boolean = js.native
+
+    /** `block` returns immediately if the pollable is ready, and otherwise
+      * blocks until ready.
+      *
+      * This function is equivalent to calling `poll.poll` on a list
+      * containing only this pollable.
+      */
+    def block(): Unit = js.native
+  }
+
+  /** A poll API intended to let users wait for I/O events on multiple handles
+    * at once.
+    */
+  @js.native
+  @JSImport("wasi:io/poll@0.2.0", JSImport.Namespace)
+  object Poll extends js.Object {
+    /** Poll for completion on a set of pollables.
+      *
+      * This function takes a list of pollables, which identify I/O sources of
+      * interest, and waits until one or more of the events is ready for I/O.
+      *
+      * The result `list<u32>` contains one or more indices of handles in the
+      * argument list that is ready for I/O.
+      *
+      * @param in `list<borrow<pollable>>`
+      * @return `list<u32>`
+      */
+    def poll(in: WitList[Pollable]): WitList[Integer] = js.native
+  }
+
+  /** WASI Monotonic Clock is a clock API intended to let users measure elapsed
+    * time.
+    *
+    * A monotonic clock is a clock which has an unspecified initial value, and
+    * successive reads of the clock will produce non-decreasing values.
+    */
+  @js.native
+  @JSImport("wasi:clocks/monotonic-clock@0.2.0", JSImport.Namespace)
+  object MonotonicClock extends js.Object {
+    /** Read the current value of the clock.
+      *
+      * The clock is monotonic, therefore calling this function repeatedly will
+      * produce a sequence of non-decreasing values.
+      *
+      * @return `instant`
+      */
+    def now(): Instant = js.native
+
+    /** Query the resolution of the clock. Returns the duration of time
+      * corresponding to a clock tick.
+      *
+      * @return `duration`
+      */
+    def resolution(): Duration = js.native
+
+    /** Create a `pollable` which will resolve once the specified instant
+      * has occurred.
+      *
+      * @param when `instant`
+      * @return `own<pollable>`
+      */
+    def subscribeInstant(when: Instant): Pollable = js.native
+
+    /** Create a `pollable` that will resolve after the specified duration has
+      * elapsed from the time this function is invoked.
+      *
+      * @param when `duration`
+      * @return `own<pollable>`
+      */
+    def subscribeDuration(when: Duration): Pollable = js.native
+  }
+
+  /** A resource which represents some error information.
+    *
+    * The only method provided by this resource is `to-debug-string`,
+    * which provides some human-readable information about the error.
+    */
+  @js.native
+  @JSImport("wasi:io/error@0.2.0", "Error")
+  class Error extends js.Object {
+    /** Returns a string that is suitable to assist humans in debugging
+      * this error.
+      *
+      * @return `string`
+      */
+    def toDebugString(): String = js.native
+  }
+
+  /** An error for input-stream and output-stream operations. */
+  sealed trait StreamError extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object StreamError {
+    /** The last operation (a write or flush) failed before completion. */
+    def lastOperationFailed(value: Error) = new StreamError {
+      type Type = Error
+      val tag: String = "last-operation-failed"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** The stream is closed: no more input will be accepted by the
+      * stream.
+      */
+    val closed = new StreamError {
+      type Type = Nothing
+      val tag: String = "closed"
+    }
+
+    /** A case of `StreamError`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      /** The last operation (a write or flush) failed before completion. */
+      final case class LastOperationFailed(value: Error) extends Case
+
+      /** The stream is closed: no more input will be accepted by the
+        * stream.
+        */
+      case object Closed extends Case
+    }
+
+    /** The pattern matching and structural methods of `StreamError` */
+    implicit final class Ops(self: StreamError) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](lastOperationFailed: Error => A, closed: => A): A = self.tag match {
+        case "last-operation-failed" =>
+          lastOperationFailed(self.`val`.asInstanceOf[Error])
+        case "closed" => closed
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of StreamError")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.LastOperationFailed(_), Case.Closed)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: StreamError): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `StreamError` */
+      def show: String = "StreamError." + Structural.show(self)
+    }
+  }
+
+  /** An input bytestream. */
+  @js.native
+  @JSImport("wasi:io/streams@0.2.0", "InputStream")
+  class InputStream extends js.Object {
+    /** Perform a non-blocking read from the stream.
+      *
+      * @param len `u64`
+      * @return `result<list<u8>, stream-error>`
+      */
+    def read(len: Long): WitResult[WitList[Byte], StreamError] = js.native
+
+    /** Read bytes from a stream, after blocking until at least one byte can
+      * be read.
+      *
+      * @param len `u64`
+      * @return `result<list<u8>, stream-error>`
+      */
+    def blockingRead(len: Long): WitResult[WitList[Byte], StreamError] =
+      js.native
+
+    /** Skip bytes from a stream. Returns number of bytes skipped.
+      *
+      * @param len `u64`
+      * @return `result<u64, stream-error>`
+      */
+    def skip(len: Long): WitResult[Long, StreamError] = js.native
+
+    /** Skip bytes from a stream, after blocking until at least one byte
+      * can be skipped.
+      *
+      * @param len `u64`
+      * @return `result<u64, stream-error>`
+      */
+    def blockingSkip(len: Long): WitResult[Long, StreamError] = js.native
+
+    /** Create a `pollable` which will resolve once either the specified stream
+      * has bytes available to read or the other end of the stream has been
+      * closed.
+      *
+      * @return `own<pollable>`
+      */
+    def subscribe(): Pollable = js.native
+  }
+
+  /** An output bytestream. */
+  @js.native
+  @JSImport("wasi:io/streams@0.2.0", "OutputStream")
+  class OutputStream extends js.Object {
+    /** Check readiness for writing. This function never blocks.
+      *
+      * Returns the number of bytes permitted for the next call to `write`.
+      *
+      * @return `result<u64, stream-error>`
+      */
+    def checkWrite(): WitResult[Long, StreamError] = js.native
+
+    /** Perform a write. This function never blocks.
+      *
+      * @param contents `list<u8>`
+      * @return `result<_, stream-error>`
+      */
+    def write(contents: WitList[Byte]): WitResult[Unit, StreamError] = js.native
+
+    /** Perform a write of up to 4096 bytes, and then flush the stream. Block
+      * until all of these operations are complete, or an error occurs.
+      *
+      * @param contents `list<u8>`
+      * @return `result<_, stream-error>`
+      */
+    def blockingWriteAndFlush(
+        contents: WitList[Byte]
+    ): WitResult[Unit, StreamError] = js.native
+
+    /** Request to flush buffered output. This function never blocks.
+      *
+      * @return `result<_, stream-error>`
+      */
+    def flush(): WitResult[Unit, StreamError] = js.native
+
+    /** Request to flush buffered output, and block until flush completes
+      * and stream is ready for writing again.
+      *
+      * @return `result<_, stream-error>`
+      */
+    def blockingFlush(): WitResult[Unit, StreamError] = js.native
+
+    /** Create a `pollable` which will resolve once the output-stream
+      * is ready for more writing, or an error has occurred.
+      *
+      * @return `own<pollable>`
+      */
+    def subscribe(): Pollable = js.native
+
+    /** Write zeroes to a stream.
+      *
+      * @param len `u64`
+      * @return `result<_, stream-error>`
+      */
+    def writeZeroes(len: Long): WitResult[Unit, StreamError] = js.native
+
+    /** Perform a write of up to 4096 zeroes, and then flush the stream.
+      * Block until all of these operations are complete, or an error
+      * occurs.
+      *
+      * @param len `u64`
+      * @return `result<_, stream-error>`
+      */
+    def blockingWriteZeroesAndFlush(len: Long): WitResult[Unit, StreamError] =
+      js.native
+
+    /** Read from one stream and write to another.
+      *
+      * @param src `borrow<input-stream>`
+      * @param len `u64`
+      * @return `result<u64, stream-error>`
+      */
+    def splice(src: InputStream, len: Long): WitResult[Long, StreamError] =
+      js.native
+
+    /** Read from one stream and write to another, with blocking.
+      *
+      * @param src `borrow<input-stream>`
+      * @param len `u64`
+      * @return `result<u64, stream-error>`
+      */
+    def blockingSplice(
+        src: InputStream,
+        len: Long
+    ): WitResult[Long, StreamError] = js.native
+  }
+
+  /** This type corresponds to HTTP standard Methods. */
+  sealed trait Method extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Method {
+    val get = new Method {
+      type Type = Nothing
+      val tag: String = "get"
+    }
+
+    val head = new Method {
+      type Type = Nothing
+      val tag: String = "head"
+    }
+
+    val post = new Method {
+      type Type = Nothing
+      val tag: String = "post"
+    }
+
+    val put = new Method {
+      type Type = Nothing
+      val tag: String = "put"
+    }
+
+    val delete = new Method {
+      type Type = Nothing
+      val tag: String = "delete"
+    }
+
+    val connect = new Method {
+      type Type = Nothing
+      val tag: String = "connect"
+    }
+
+    val options = new Method {
+      type Type = Nothing
+      val tag: String = "options"
+    }
+
+    val trace = new Method {
+      type Type = Nothing
+      val tag: String = "trace"
+    }
+
+    val patch = new Method {
+      type Type = Nothing
+      val tag: String = "patch"
+    }
+
+    def other(value: String) = new Method {
+      type Type = String
+      val tag: String = "other"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `Method`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object Get extends Case
+      case object Head extends Case
+      case object Post extends Case
+      case object Put extends Case
+      case object Delete extends Case
+      case object Connect extends Case
+      case object Options extends Case
+      case object Trace extends Case
+      case object Patch extends Case
+      final case class Other(value: String) extends Case
+    }
+
+    /** The pattern matching and structural methods of `Method` */
+    implicit final class Ops(self: Method) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          get: => A,
+          head: => A,
+          post: => A,
+          put: => A,
+          delete: => A,
+          connect: => A,
+          options: => A,
+          trace: => A,
+          patch: => A,
+          other: String => A
+      ): A = self.tag match {
+        case "get" => get
+        case "head" => head
+        case "post" => post
+        case "put" => put
+        case "delete" => delete
+        case "connect" => connect
+        case "options" => options
+        case "trace" => trace
+        case "patch" => patch
+        case "other" => other(self.`val`.asInstanceOf[String])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Method")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case =
+        fold(Case.Get, Case.Head, Case.Post, Case.Put, Case.Delete, Case.Connect, Case.Options, Case.Trace, Case.Patch, Case.Other(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Method): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Method` */
+      def show: String = "Method." + Structural.show(self)
+    }
+  }
+
+  /** This type corresponds to HTTP standard Related Schemes. */
+  sealed trait Scheme extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object Scheme {
+    val http = new Scheme {
+      type Type = Nothing
+      val tag: String = "HTTP"
+    }
+
+    val https = new Scheme {
+      type Type = Nothing
+      val tag: String = "HTTPS"
+    }
+
+    def other(value: String) = new Scheme {
+      type Type = String
+      val tag: String = "other"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `Scheme`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object Http extends Case
+      case object Https extends Case
+      final case class Other(value: String) extends Case
+    }
+
+    /** The pattern matching and structural methods of `Scheme` */
+    implicit final class Ops(self: Scheme) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](http: => A, https: => A, other: String => A): A = self.tag match {
+        case "HTTP" => http
+        case "HTTPS" => https
+        case "other" => other(self.`val`.asInstanceOf[String])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of Scheme")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case = fold(Case.Http, Case.Https, Case.Other(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: Scheme): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `Scheme` */
+      def show: String = "Scheme." + Structural.show(self)
+    }
+  }
+
+  /** Defines the case payload type for `DNS-error` above. */
+  sealed trait DnsErrorPayload extends js.Object {
+    val rcode: WitOption[String]
+    val infoCode: WitOption[Short]
+  }
+
+  object DnsErrorPayload {
+    def apply(
+        rcode: WitOption[String],
+        infoCode: WitOption[Short]
+    ): DnsErrorPayload = {
+      val rcode0: WitOption[String] = rcode
+      val infoCode0: WitOption[Short] = infoCode
+
+      new DnsErrorPayload {
+        val rcode: WitOption[String] = rcode0
+        val infoCode: WitOption[Short] = infoCode0
+      }
+    }
+
+    /** The structural methods of `DnsErrorPayload`, which leave its JS representation untouched */
+    implicit final class Ops(self: DnsErrorPayload) extends AnyVal {
+      /** A new `DnsErrorPayload` with the given fields replaced */
+      def copy(
+          rcode: WitOption[String] = self.rcode,
+          infoCode: WitOption[Short] = self.infoCode
+      ): DnsErrorPayload = DnsErrorPayload(rcode, infoCode)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: DnsErrorPayload): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `DnsErrorPayload` */
+      def show: String = "DnsErrorPayload" + Structural.show(self)
+    }
+  }
+
+  /** Defines the case payload type for `TLS-alert-received` above. */
+  sealed trait TlsAlertReceivedPayload extends js.Object {
+    val alertId: WitOption[Byte]
+    val alertMessage: WitOption[String]
+  }
+
+  object TlsAlertReceivedPayload {
+    def apply(
+        alertId: WitOption[Byte],
+        alertMessage: WitOption[String]
+    ): TlsAlertReceivedPayload = {
+      val alertId0: WitOption[Byte] = alertId
+      val alertMessage0: WitOption[String] = alertMessage
+
+      new TlsAlertReceivedPayload {
+        val alertId: WitOption[Byte] = alertId0
+        val alertMessage: WitOption[String] = alertMessage0
+      }
+    }
+
+    /** The structural methods of `TlsAlertReceivedPayload`, which leave its JS representation untouched */
+    implicit final class Ops(self: TlsAlertReceivedPayload) extends AnyVal {
+      /** A new `TlsAlertReceivedPayload` with the given fields replaced */
+      def copy(
+          alertId: WitOption[Byte] = self.alertId,
+          alertMessage: WitOption[String] = self.alertMessage
+      ): TlsAlertReceivedPayload =
+        TlsAlertReceivedPayload(alertId, alertMessage)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: TlsAlertReceivedPayload): Boolean =
+        Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `TlsAlertReceivedPayload` */
+      def show: String = "TlsAlertReceivedPayload" + Structural.show(self)
+    }
+  }
+
+  /** Defines the case payload type for `HTTP-response-{header,trailer}-size` above. */
+  sealed trait FieldSizePayload extends js.Object {
+    val fieldName: WitOption[String]
+    val fieldSize: WitOption[Integer]
+  }
+
+  object FieldSizePayload {
+    def apply(
+        fieldName: WitOption[String],
+        fieldSize: WitOption[Integer]
+    ): FieldSizePayload = {
+      val fieldName0: WitOption[String] = fieldName
+      val fieldSize0: WitOption[Integer] = fieldSize
+
+      new FieldSizePayload {
+        val fieldName: WitOption[String] = fieldName0
+        val fieldSize: WitOption[Integer] = fieldSize0
+      }
+    }
+
+    /** The structural methods of `FieldSizePayload`, which leave its JS representation untouched */
+    implicit final class Ops(self: FieldSizePayload) extends AnyVal {
+      /** A new `FieldSizePayload` with the given fields replaced */
+      def copy(
+          fieldName: WitOption[String] = self.fieldName,
+          fieldSize: WitOption[Integer] = self.fieldSize
+      ): FieldSizePayload = FieldSizePayload(fieldName, fieldSize)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: FieldSizePayload): Boolean =
+        Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `FieldSizePayload` */
+      def show: String = "FieldSizePayload" + Structural.show(self)
+    }
+  }
+
+  /** These cases are inspired by the IANA HTTP Proxy Error Types. */
+  sealed trait ErrorCode extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object ErrorCode {
+    val dnsTimeout = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "DNS-timeout"
+    }
+
+    def dnsError(value: DnsErrorPayload) = new ErrorCode {
+      type Type = DnsErrorPayload
+      val tag: String = "DNS-error"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val destinationNotFound = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "destination-not-found"
+    }
+
+    val destinationUnavailable = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "destination-unavailable"
+    }
+
+    val destinationIpProhibited = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "destination-IP-prohibited"
+    }
+
+    val destinationIpUnroutable = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "destination-IP-unroutable"
+    }
+
+    val connectionRefused = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "connection-refused"
+    }
+
+    val connectionTerminated = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "connection-terminated"
+    }
+
+    val connectionTimeout = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "connection-timeout"
+    }
+
+    val connectionReadTimeout = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "connection-read-timeout"
+    }
+
+    val connectionWriteTimeout = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "connection-write-timeout"
+    }
+
+    val connectionLimitReached = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "connection-limit-reached"
+    }
+
+    val tlsProtocolError = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "TLS-protocol-error"
+    }
+
+    val tlsCertificateError = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "TLS-certificate-error"
+    }
+
+    def tlsAlertReceived(value: TlsAlertReceivedPayload) = new ErrorCode {
+      type Type = TlsAlertReceivedPayload
+      val tag: String = "TLS-alert-received"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val httpRequestDenied = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "HTTP-request-denied"
+    }
+
+    val httpRequestLengthRequired = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "HTTP-request-length-required"
+    }
+
+    def httpRequestBodySize(value: WitOption[Long]) = new ErrorCode {
+      type Type = WitOption[Long]
+      val tag: String = "HTTP-request-body-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val httpRequestMethodInvalid = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "HTTP-request-method-invalid"
+    }
+
+    val httpRequestUriInvalid = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "HTTP-request-URI-invalid"
+    }
+
+    val httpRequestUriTooLong = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "HTTP-request-URI-too-long"
+    }
+
+    def httpRequestHeaderSectionSize(value: WitOption[Integer]) = new ErrorCode {
+      type Type = WitOption[Integer]
+      val tag: String = "HTTP-request-header-section-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def httpRequestHeaderSize(value: WitOption[FieldSizePayload]) = new ErrorCode {
+      type Type = WitOption[FieldSizePayload]
+      val tag: String = "HTTP-request-header-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def httpRequestTrailerSectionSize(value: WitOption[Integer]) = new ErrorCode {
+      type Type = WitOption[Integer]
+      val tag: String = "HTTP-request-trailer-section-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def httpRequestTrailerSize(value: FieldSizePayload) = new ErrorCode {
+      type Type = FieldSizePayload
+      val tag: String = "HTTP-request-trailer-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val httpResponseIncomplete = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "HTTP-response-incomplete"
+    }
+
+    def httpResponseHeaderSectionSize(value: WitOption[Integer]) = new ErrorCode {
+      type Type = WitOption[Integer]
+      val tag: String = "HTTP-response-header-section-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def httpResponseHeaderSize(value: FieldSizePayload) = new ErrorCode {
+      type Type = FieldSizePayload
+      val tag: String = "HTTP-response-header-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def httpResponseBodySize(value: WitOption[Long]) = new ErrorCode {
+      type Type = WitOption[Long]
+      val tag: String = "HTTP-response-body-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def httpResponseTrailerSectionSize(value: WitOption[Integer]) = new ErrorCode {
+      type Type = WitOption[Integer]
+      val tag: String = "HTTP-response-trailer-section-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def httpResponseTrailerSize(value: FieldSizePayload) = new ErrorCode {
+      type Type = FieldSizePayload
+      val tag: String = "HTTP-response-trailer-size"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def httpResponseTransferCoding(value: WitOption[String]) = new ErrorCode {
+      type Type = WitOption[String]
+      val tag: String = "HTTP-response-transfer-coding"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    def httpResponseContentCoding(value: WitOption[String]) = new ErrorCode {
+      type Type = WitOption[String]
+      val tag: String = "HTTP-response-content-coding"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    val httpResponseTimeout = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "HTTP-response-timeout"
+    }
+
+    val httpUpgradeFailed = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "HTTP-upgrade-failed"
+    }
+
+    val httpProtocolError = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "HTTP-protocol-error"
+    }
+
+    val loopDetected = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "loop-detected"
+    }
+
+    val configurationError = new ErrorCode {
+      type Type = Nothing
+      val tag: String = "configuration-error"
+    }
+
+    /** This is a catch-all error for anything that doesn't fit cleanly into a
+      * more specific case.
+      */
+    def internalError(value: WitOption[String]) = new ErrorCode {
+      type Type = WitOption[String]
+      val tag: String = "internal-error"
+      override val `val`: js.UndefOr[Type] = value
+    }
+
+    /** A case of `ErrorCode`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      case object DnsTimeout extends Case
+      final case class DnsError(value: DnsErrorPayload) extends Case
+      case object DestinationNotFound extends Case
+      case object DestinationUnavailable extends Case
+      case object DestinationIpProhibited extends Case
+      case object DestinationIpUnroutable extends Case
+      case object ConnectionRefused extends Case
+      case object ConnectionTerminated extends Case
+      case object ConnectionTimeout extends Case
+      case object ConnectionReadTimeout extends Case
+      case object ConnectionWriteTimeout extends Case
+      case object ConnectionLimitReached extends Case
+      case object TlsProtocolError extends Case
+      case object TlsCertificateError extends Case
+
+      final case class TlsAlertReceived(
+          value: TlsAlertReceivedPayload
+      ) extends Case
+
+      case object HttpRequestDenied extends Case
+      case object HttpRequestLengthRequired extends Case
+
+      final case class HttpRequestBodySize(
+          value: WitOption[Long]
+      ) extends Case
+
+      case object HttpRequestMethodInvalid extends Case
+      case object HttpRequestUriInvalid extends Case
+      case object HttpRequestUriTooLong extends Case
+
+      final case class HttpRequestHeaderSectionSize(
+          value: WitOption[Integer]
+      ) extends Case
+
+      final case class HttpRequestHeaderSize(
+          value: WitOption[FieldSizePayload]
+      ) extends Case
+
+      final case class HttpRequestTrailerSectionSize(
+          value: WitOption[Integer]
+      ) extends Case
+
+      final case class HttpRequestTrailerSize(
+          value: FieldSizePayload
+      ) extends Case
+
+      case object HttpResponseIncomplete extends Case
+
+      final case class HttpResponseHeaderSectionSize(
+          value: WitOption[Integer]
+      ) extends Case
+
+      final case class HttpResponseHeaderSize(
+          value: FieldSizePayload
+      ) extends Case
+
+      final case class HttpResponseBodySize(
+          value: WitOption[Long]
+      ) extends Case
+
+      final case class HttpResponseTrailerSectionSize(
+          value: WitOption[Integer]
+      ) extends Case
+
+      final case class HttpResponseTrailerSize(
+          value: FieldSizePayload
+      ) extends Case
+
+      final case class HttpResponseTransferCoding(
+          value: WitOption[String]
+      ) extends Case
+
+      final case class HttpResponseContentCoding(
+          value: WitOption[String]
+      ) extends Case
+
+      case object HttpResponseTimeout extends Case
+      case object HttpUpgradeFailed extends Case
+      case object HttpProtocolError extends Case
+      case object LoopDetected extends Case
+      case object ConfigurationError extends Case
+
+      /** This is a catch-all error for anything that doesn't fit cleanly into a
+        * more specific case.
+        */
+      final case class InternalError(value: WitOption[String]) extends Case
+    }
+
+    /** The pattern matching and structural methods of `ErrorCode` */
+    implicit final class Ops(self: ErrorCode) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](
+          dnsTimeout: => A,
+          dnsError: DnsErrorPayload => A,
+          destinationNotFound: => A,
+          destinationUnavailable: => A,
+          destinationIpProhibited: => A,
+          destinationIpUnroutable: => A,
+          connectionRefused: => A,
+          connectionTerminated: => A,
+          connectionTimeout: => A,
+          connectionReadTimeout: => A,
+          connectionWriteTimeout: => A,
+          connectionLimitReached: => A,
+          tlsProtocolError: => A,
+          tlsCertificateError: => A,
+          tlsAlertReceived: TlsAlertReceivedPayload => A,
+          httpRequestDenied: => A,
+          httpRequestLengthRequired: => A,
+          httpRequestBodySize: WitOption[Long] => A,
+          httpRequestMethodInvalid: => A,
+          httpRequestUriInvalid: => A,
+          httpRequestUriTooLong: => A,
+          httpRequestHeaderSectionSize: WitOption[Integer] => A,
+          httpRequestHeaderSize: WitOption[FieldSizePayload] => A,
+          httpRequestTrailerSectionSize: WitOption[Integer] => A,
+          httpRequestTrailerSize: FieldSizePayload => A,
+          httpResponseIncomplete: => A,
+          httpResponseHeaderSectionSize: WitOption[Integer] => A,
+          httpResponseHeaderSize: FieldSizePayload => A,
+          httpResponseBodySize: WitOption[Long] => A,
+          httpResponseTrailerSectionSize: WitOption[Integer] => A,
+          httpResponseTrailerSize: FieldSizePayload => A,
+          httpResponseTransferCoding: WitOption[String] => A,
+          httpResponseContentCoding: WitOption[String] => A,
+          httpResponseTimeout: => A,
+          httpUpgradeFailed: => A,
+          httpProtocolError: => A,
+          loopDetected: => A,
+          configurationError: => A,
+          internalError: WitOption[String] => A
+      ): A = self.tag match {
+        case "DNS-timeout" => dnsTimeout
+        case "DNS-error" => dnsError(self.`val`.asInstanceOf[DnsErrorPayload])
+        case "destination-not-found" => destinationNotFound
+        case "destination-unavailable" => destinationUnavailable
+        case "destination-IP-prohibited" => destinationIpProhibited
+        case "destination-IP-unroutable" => destinationIpUnroutable
+        case "connection-refused" => connectionRefused
+        case "connection-terminated" => connectionTerminated
+        case "connection-timeout" => connectionTimeout
+        case "connection-read-timeout" => connectionReadTimeout
+        case "connection-write-timeout" => connectionWriteTimeout
+        case "connection-limit-reached" => connectionLimitReached
+        case "TLS-protocol-error" => tlsProtocolError
+        case "TLS-certificate-error" => tlsCertificateError
+        case "TLS-alert-received" =>
+          tlsAlertReceived(self.`val`.asInstanceOf[TlsAlertReceivedPayload])
+        case "HTTP-request-denied" => httpRequestDenied
+        case "HTTP-request-length-required" => httpRequestLengthRequired
+        case "HTTP-request-body-size" =>
+          httpRequestBodySize(self.`val`.asInstanceOf[WitOption[Long]])
+        case "HTTP-request-method-invalid" => httpRequestMethodInvalid
+        case "HTTP-request-URI-invalid" => httpRequestUriInvalid
+        case "HTTP-request-URI-too-long" => httpRequestUriTooLong
+        case "HTTP-request-header-section-size" =>
+          httpRequestHeaderSectionSize(self.`val`.asInstanceOf[WitOption[Integer]])
+        case "HTTP-request-header-size" =>
+          httpRequestHeaderSize(self.`val`.asInstanceOf[WitOption[FieldSizePayload]])
+        case "HTTP-request-trailer-section-size" =>
+          httpRequestTrailerSectionSize(self.`val`.asInstanceOf[WitOption[Integer]])
+        case "HTTP-request-trailer-size" =>
+          httpRequestTrailerSize(self.`val`.asInstanceOf[FieldSizePayload])
+        case "HTTP-response-incomplete" => httpResponseIncomplete
+        case "HTTP-response-header-section-size" =>
+          httpResponseHeaderSectionSize(self.`val`.asInstanceOf[WitOption[Integer]])
+        case "HTTP-response-header-size" =>
+          httpResponseHeaderSize(self.`val`.asInstanceOf[FieldSizePayload])
+        case "HTTP-response-body-size" =>
+          httpResponseBodySize(self.`val`.asInstanceOf[WitOption[Long]])
+        case "HTTP-response-trailer-section-size" =>
+          httpResponseTrailerSectionSize(self.`val`.asInstanceOf[WitOption[Integer]])
+        case "HTTP-response-trailer-size" =>
+          httpResponseTrailerSize(self.`val`.asInstanceOf[FieldSizePayload])
+        case "HTTP-response-transfer-coding" =>
+          httpResponseTransferCoding(self.`val`.asInstanceOf[WitOption[String]])
+        case "HTTP-response-content-coding" =>
+          httpResponseContentCoding(self.`val`.asInstanceOf[WitOption[String]])
+        case "HTTP-response-timeout" => httpResponseTimeout
+        case "HTTP-upgrade-failed" => httpUpgradeFailed
+        case "HTTP-protocol-error" => httpProtocolError
+        case "loop-detected" => loopDetected
+        case "configuration-error" => configurationError
+        case "internal-error" =>
+          internalError(self.`val`.asInstanceOf[WitOption[String]])
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of ErrorCode")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case =
+        fold(Case.DnsTimeout, Case.DnsError(_), Case.DestinationNotFound, Case.DestinationUnavailable, Case.DestinationIpProhibited, Case.DestinationIpUnroutable, Case.ConnectionRefused, Case.ConnectionTerminated, Case.ConnectionTimeout, Case.ConnectionReadTimeout, Case.ConnectionWriteTimeout, Case.ConnectionLimitReached, Case.TlsProtocolError, Case.TlsCertificateError, Case.TlsAlertReceived(_), Case.HttpRequestDenied, Case.HttpRequestLengthRequired, Case.HttpRequestBodySize(_), Case.HttpRequestMethodInvalid, Case.HttpRequestUriInvalid, Case.HttpRequestUriTooLong, Case.HttpRequestHeaderSectionSize(_), Case.HttpRequestHeaderSize(_), Case.HttpRequestTrailerSectionSize(_), Case.HttpRequestTrailerSize(_), Case.HttpResponseIncomplete, Case.HttpResponseHeaderSectionSize(_), Case.HttpResponseHeaderSize(_), Case.HttpResponseBodySize(_), Case.HttpResponseTrailerSectionSize(_), Case.HttpResponseTrailerSize(_), Case.HttpResponseTransferCoding(_), Case.HttpResponseContentCoding(_), Case.HttpResponseTimeout, Case.HttpUpgradeFailed, Case.HttpProtocolError, Case.LoopDetected, Case.ConfigurationError, Case.InternalError(_))
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: ErrorCode): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `ErrorCode` */
+      def show: String = "ErrorCode." + Structural.show(self)
+    }
+  }
+
+  /** This type enumerates the different kinds of errors that may occur when
+    * setting or appending to a `fields` resource.
+    */
+  sealed trait HeaderError extends js.Object { self =>
+    type Type
+    val tag: String
+    val `val`: js.UndefOr[Type] = js.undefined
+  }
+
+  object HeaderError {
+    /** This error indicates that a `field-key` or `field-value` was
+      * syntactically invalid when used with an operation that sets headers in a
+      * `fields`.
+      */
+    val invalidSyntax = new HeaderError {
+      type Type = Nothing
+      val tag: String = "invalid-syntax"
+    }
+
+    /** This error indicates that a forbidden `field-key` was used when trying
+      * to set a header in a `fields`.
+      */
+    val forbidden = new HeaderError {
+      type Type = Nothing
+      val tag: String = "forbidden"
+    }
+
+    /** This error indicates that the operation on the `fields` was not
+      * permitted because the fields are immutable.
+      */
+    val immutable = new HeaderError {
+      type Type = Nothing
+      val tag: String = "immutable"
+    }
+
+    /** A case of `HeaderError`, matches on which are checked for exhaustiveness */
+    sealed trait Case
+
+    object Case {
+      /** This error indicates that a `field-key` or `field-value` was
+        * syntactically invalid when used with an operation that sets headers in a
+        * `fields`.
+        */
+      case object InvalidSyntax extends Case
+
+      /** This error indicates that a forbidden `field-key` was used when trying
+        * to set a header in a `fields`.
+        */
+      case object Forbidden extends Case
+
+      /** This error indicates that the operation on the `fields` was not
+        * permitted because the fields are immutable.
+        */
+      case object Immutable extends Case
+    }
+
+    /** The pattern matching and structural methods of `HeaderError` */
+    implicit final class Ops(self: HeaderError) extends AnyVal {
+      /** Applies the function of the case of the variant to its payload */
+      def fold[A](invalidSyntax: => A, forbidden: => A, immutable: => A): A = self.tag match {
+        case "invalid-syntax" => invalidSyntax
+        case "forbidden" => forbidden
+        case "immutable" => immutable
+        case tag =>
+          throw new IllegalStateException(s"Unknown case $tag of HeaderError")
+      }
+
+      /** The case of the variant, which can be matched exhaustively */
+      def toCase: Case =
+        fold(Case.InvalidSyntax, Case.Forbidden, Case.Immutable)
+
+      /** Whether `other` has the same members, compared structurally */
+      def equal(other: HeaderError): Boolean = Structural.equal(self, other)
+
+      /** The hash of the members, consistent with `equal` */
+      def hash: Int = Structural.hash(self)
+
+      /** A readable representation of the `HeaderError` */
+      def show: String = "HeaderError." + Structural.show(self)
+    }
+  }
+
+  /** This following block defines the `fields` resource which corresponds to
+    * HTTP standard Fields. Fields are a common representation used for both
+    * Headers and Trailers.
+    */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "Fields")
+  class Fields() extends js.Object {
+    /** Get all of the values corresponding to a key.
+      *
+      * @param name `field-key`
+      * @return `list<field-value>`
+      */
+    def get(name: FieldKey): WitList[FieldValue] = js.native
+
+    /** Returns `true` when the key is present in this `fields`.
+      *
+      * @param name `field-key`
+      * @return `bool`
+      */
+    def has(name: FieldKey): Boolean = js.native
+
+    /** Set all of the values for a key.
+      *
+      * @param name `field-key`
+      * @param value `list<field-value>`
+      * @return `result<_, header-error>`
+      */
+    def set(
+        name: FieldKey,
+        value: WitList[FieldValue]
+    ): WitResult[Unit, HeaderError] = js.native
+
+    /** Delete all values for a key.
+      *
+      * @param name `field-key`
+      * @return `result<_, header-error>`
+      */
+    def delete(name: FieldKey): WitResult[Unit, HeaderError] = js.native
+
+    /** Append a value for a key.
+      *
+      * @param name `field-key`
+      * @param value `field-value`
+      * @return `result<_, header-error>`
+      */
+    def append(
+        name: FieldKey,
+        value: FieldValue
+    ): WitResult[Unit, HeaderError] = js.native
+
+    /** Retrieve the full set of keys and values in the Fields.
+      *
+      * @return `list<tuple<field-key, field-value>>`
+      */
+    def entries(): WitList[js.Tuple2[FieldKey, FieldValue]] = js.native
+
+    /** Make a deep copy of the Fields. The resulting `fields` is mutable.
+      *
+      * @return `own<fields>`
+      */
+    @JSName("clone")
+    def clone_(): Fields = js.native
+  }
+
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "Fields")
+  object Fields extends js.Object {
+    /** Construct an HTTP Fields.
+      *
+      * @param entries `list<tuple<field-key, field-value>>`
+      * @return `result<own<fields>, header-error>`
+      */
+    def fromList(
+        entries: WitList[js.Tuple2[FieldKey, FieldValue]]
+    ): WitResult[Fields, HeaderError] = js.native
+  }
+
+  /** Represents an incoming HTTP Request. */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "IncomingRequest")
+  class IncomingRequest extends js.Object {
+    /** Returns the method of the incoming request.
+      *
+      * @return `method`
+      */
+    def method(): Method = js.native
+
+    /** Returns the path with query parameters from the request, as a string.
+      *
+      * @return `option<string>`
+      */
+    def pathWithQuery(): WitOption[String] = js.native
+
+    /** Returns the protocol scheme from the request.
+      *
+      * @return `option<scheme>`
+      */
+    def scheme(): WitOption[Scheme] = js.native
+
+    /** Returns the authority from the request, if it was present.
+      *
+      * @return `option<string>`
+      */
+    def authority(): WitOption[String] = js.native
+
+    /** Get the `headers` associated with the request.
+      *
+      * @return `own<headers>`
+      */
+    def headers(): Headers = js.native
+
+    /** Gives the `incoming-body` associated with this request. Will only
+      * return success at most once, and subsequent calls will return error.
+      *
+      * @return `result<own<incoming-body>>`
+      */
+    def consume(): WitResult[IncomingBody, Unit] = js.native
+  }
+
+  /** Represents an outgoing HTTP Request. */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "OutgoingRequest")
+  class OutgoingRequest(headers: Headers) extends js.Object {
+    /** Returns the resource corresponding to the outgoing Body for this
+      * Request.
+      *
+      * @return `result<own<outgoing-body>>`
+      */
+    def body(): WitResult[OutgoingBody, Unit] = js.native
+
+    /** Get the Method for the Request.
+      *
+      * @return `method`
+      */
+    def method(): Method = js.native
+
+    /** Set the Method for the Request. Fails if the string present in a
+      * `method.other` argument is not a syntactically valid method.
+      *
+      * @param method `method`
+      * @return `result`
+      */
+    def setMethod(method: Method): WitResult[Unit, Unit] = js.native
+
+    /** Get the combination of the HTTP Path and Query for the Request.
+      *
+      * @return `option<string>`
+      */
+    def pathWithQuery(): WitOption[String] = js.native
+
+    /** Set the combination of the HTTP Path and Query for the Request.
+      *
+      * @param pathWithQuery `option<string>`
+      * @return `result`
+      */
+    def setPathWithQuery(
+        pathWithQuery: WitOption[String]
+    ): WitResult[Unit, Unit] = js.native
+
+    /** Get the HTTP Related Scheme for the Request.
+      *
+      * @return `option<scheme>`
+      */
+    def scheme(): WitOption[Scheme] = js.native
+
+    /** Set the HTTP Related Scheme for the Request.
+      *
+      * @param scheme `option<scheme>`
+      * @return `result`
+      */
+    def setScheme(scheme: WitOption[Scheme]): WitResult[Unit, Unit] = js.native
+
+    /** Get the HTTP Authority for the Request.
+      *
+      * @return `option<string>`
+      */
+    def authority(): WitOption[String] = js.native
+
+    /** Set the HTTP Authority for the Request.
+      *
+      * @param authority `option<string>`
+      * @return `result`
+      */
+    def setAuthority(authority: WitOption[String]): WitResult[Unit, Unit] =
+      js.native
+
+    /** Get the headers associated with the Request.
+      *
+      * @return `own<headers>`
+      */
+    def headers(): Headers = js.native
+  }
+
+  /** Parameters for making an HTTP Request. Each of these parameters is
+    * currently an optional timeout applicable to the transport layer of the
+    * HTTP protocol.
+    */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "RequestOptions")
+  class RequestOptions() extends js.Object {
+    /** The timeout for the initial connect to the HTTP Server.
+      *
+      * @return `option<duration>`
+      */
+    def connectTimeout(): WitOption[Duration] = js.native
+
+    /** Set the timeout for the initial connect to the HTTP Server.
+      *
+      * @param duration `option<duration>`
+      * @return `result`
+      */
+    def setConnectTimeout(
+        duration: WitOption[Duration]
+    ): WitResult[Unit, Unit] = js.native
+
+    /** The timeout for receiving the first byte of the Response body.
+      *
+      * @return `option<duration>`
+      */
+    def firstByteTimeout(): WitOption[Duration] = js.native
+
+    /** Set the timeout for receiving the first byte of the Response body.
+      *
+      * @param duration `option<duration>`
+      * @return `result`
+      */
+    def setFirstByteTimeout(
+        duration: WitOption[Duration]
+    ): WitResult[Unit, Unit] = js.native
+
+    /** The timeout for receiving subsequent chunks of bytes in the Response
+      * body stream.
+      *
+      * @return `option<duration>`
+      */
+    def betweenBytesTimeout(): WitOption[Duration] = js.native
+
+    /** Set the timeout for receiving subsequent chunks of bytes in the Response
+      * body stream.
+      *
+      * @param duration `option<duration>`
+      * @return `result`
+      */
+    def setBetweenBytesTimeout(
+        duration: WitOption[Duration]
+    ): WitResult[Unit, Unit] = js.native
+  }
+
+  /** Represents the ability to send an HTTP Response. */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "ResponseOutparam")
+  class ResponseOutparam extends js.Object
+
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "ResponseOutparam")
+  object ResponseOutparam extends js.Object {
+    /** Set the value of the `response-outparam` to either send a response,
+      * or indicate an error.
+      *
+      * @param param `own<response-outparam>`
+      * @param response `result<own<outgoing-response>, error-code>`
+      */
+    def set(
+        param: ResponseOutparam,
+        response: WitResult[OutgoingResponse, ErrorCode]
+    ): Unit = js.native
+  }
+
+  /** Represents an incoming HTTP Response. */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "IncomingResponse")
+  class IncomingResponse extends js.Object {
+    /** Returns the status code from the incoming response.
+      *
+      * @return `status-code`
+      */
+    def status(): StatusCode = js.native
+
+    /** Returns the headers from the incoming response.
+      *
+      * @return `own<headers>`
+      */
+    def headers(): Headers = js.native
+
+    /** Returns the incoming body. May be called at most once. Returns error
+      * if called additional times.
+      *
+      * @return `result<own<incoming-body>>`
+      */
+    def consume(): WitResult[IncomingBody, Unit] = js.native
+  }
+
+  /** Represents an incoming HTTP Request or Response's Body. */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "IncomingBody")
+  class IncomingBody extends js.Object {
+    /** Returns the contents of the body, as a stream of bytes.
+      *
+      * @return `result<own<input-stream>>`
+      */
+    def stream(): WitResult[InputStream, Unit] = js.native
+  }
+
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "IncomingBody")
+  object IncomingBody extends js.Object {
+    /** Takes ownership of `incoming-body`, and returns a `future-trailers`.
+      *
+      * @param `this` `own<incoming-body>`
+      * @return `own<future-trailers>`
+      */
+    def finish(`this`: IncomingBody): FutureTrailers = js.native
+  }
+
+  /** Represents a future which may eventaully return trailers, or an error. */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "FutureTrailers")
+  class FutureTrailers extends js.Object {
+    /** Returns a pollable which becomes ready when either the trailers have
+      * been received, or an error has occured.
+      *
+      * @return `own<pollable>`
+      */
+    def subscribe(): Pollable = js.native
+
+    /** Returns the contents of the trailers, or an error which occured,
+      * once the future is ready.
+      *
+      * @return `option<result<result<option<own<trailers>>, error-code>>>`
+      */
+    def get(): WitOption[WitResult[WitResult[WitOption[Trailers], ErrorCode], Unit]] =
+      js.native
+  }
+
+  /** Represents an outgoing HTTP Response. */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "OutgoingResponse")
+  class OutgoingResponse(headers: Headers) extends js.Object {
+    /** Get the HTTP Status Code for the Response.
+      *
+      * @return `status-code`
+      */
+    def statusCode(): StatusCode = js.native
+
+    /** Set the HTTP Status Code for the Response.
+      *
+      * @param statusCode `status-code`
+      * @return `result`
+      */
+    def setStatusCode(statusCode: StatusCode): WitResult[Unit, Unit] = js.native
+
+    /** Get the headers associated with the Request.
+      *
+      * @return `own<headers>`
+      */
+    def headers(): Headers = js.native
+
+    /** Returns the resource corresponding to the outgoing Body for this Response.
+      *
+      * @return `result<own<outgoing-body>>`
+      */
+    def body(): WitResult[OutgoingBody, Unit] = js.native
+  }
+
+  /** Represents an outgoing HTTP Request or Response's Body. */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "OutgoingBody")
+  class OutgoingBody extends js.Object {
+    /** Returns a stream for writing the body contents.
+      *
+      * @return `result<own<output-stream>>`
+      */
+    def write(): WitResult[OutputStream, Unit] = js.native
+  }
+
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "OutgoingBody")
+  object OutgoingBody extends js.Object {
+    /** Finalize an outgoing body, optionally providing trailers.
+      *
+      * @param `this` `own<outgoing-body>`
+      * @param trailers `option<own<trailers>>`
+      * @return `result<_, error-code>`
+      */
+    def finish(
+        `this`: OutgoingBody,
+        trailers: WitOption[Trailers]
+    ): WitResult[Unit, ErrorCode] = js.native
+  }
+
+  /** Represents a future which may eventaully return an incoming HTTP
+    * Response, or an error.
+    */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", "FutureIncomingResponse")
+  class FutureIncomingResponse extends js.Object {
+    /** Returns a pollable which becomes ready when either the Response has
+      * been received, or an error has occured.
+      *
+      * @return `own<pollable>`
+      */
+    def subscribe(): Pollable = js.native
+
+    /** Returns the incoming HTTP Response, or an error, once one is ready.
+      *
+      * @return `option<result<result<own<incoming-response>, error-code>>>`
+      */
+    def get(): WitOption[WitResult[WitResult[IncomingResponse, ErrorCode], Unit]] =
+      js.native
+  }
+
+  /** This interface defines all of the types and methods for implementing
+    * HTTP Requests and Responses, both incoming and outgoing, as well as
+    * their headers, trailers, and bodies.
+    */
+  @js.native
+  @JSImport("wasi:http/types@0.2.0", JSImport.Namespace)
+  object Types extends js.Object {
+    /** Attempts to extract a http-related `error` from the wasi:io `error`
+      * provided.
+      *
+      * @param err `borrow<io-error>`
+      * @return `option<error-code>`
+      */
+    def httpErrorCode(err: IoError): WitOption[ErrorCode] = js.native
+  }
+
+  /** This interface defines a handler of outgoing HTTP Requests. It should be
+    * imported by components which wish to make HTTP Requests.
+    */
+  @js.native
+  @JSImport("wasi:http/outgoing-handler@0.2.0", JSImport.Namespace)
+  object OutgoingHandler extends js.Object {
+    /** This function is invoked with an outgoing HTTP Request, and it returns
+      * a resource `future-incoming-response` which represents an HTTP Response
+      * which may arrive in the future.
+      *
+      * @param request `own<outgoing-request>`
+      * @param options `option<own<request-options>>`
+      * @return `result<own<future-incoming-response>, error-code>`
+      */
+    def handle(
+        request: OutgoingRequest,
+        options: WitOption[RequestOptions]
+    ): WitResult[FutureIncomingResponse, ErrorCode] = js.native
+  }
+
+  /** Compares, hashes and prints WIT values through their JS representation: objects by
+    * their members that are not undefined, and arrays by their items
+    */
+  object Structural {
+    /** Whether a value is a JS object that is not an array, such as a record, flags
+      * or a variant
+      */
+    private def isObject(value: Any): Boolean =
+      js.typeOf(value) == "object" &&
+        value != null &&
+        !value.isInstanceOf[Long] &&
+        !value.isInstanceOf[Char] &&
+        !js.Array.isArray(value)
+
+    /** The members of an object that are not undefined, in order */
+    private def members(value: Any): List[(String, Any)] = {
+      val dictionary = value.asInstanceOf[js.Dictionary[Any]]
+
+      js.Object.keys(value.asInstanceOf[js.Object])
+        .toList.map(key => key -> dictionary(key))
+        .filterNot(member => js.isUndefined(member._2))
+    }
+
+    /** The items of an array */
+    private def items(value: Any): List[Any] =
+      value.asInstanceOf[js.Array[Any]].toList
+
+    /** Whether two values are structurally equal */
+    def equal(left: Any, right: Any): Boolean = left match {
+      case _ if js.Array.isArray(left) && js.Array.isArray(right) =>
+        items(left).length == items(right).length &&
+          items(left).zip(items(right)).forall { case (l, r) => equal(l, r) }
+      case _ if isObject(left) && isObject(right) => {
+        val l = members(left).toMap
+        val r = members(right).toMap
+
+        l.keySet == r.keySet &&
+          l.forall { case (key, member) => equal(member, r(key)) }
+      }
+      case _ => left == right
+    }
+
+    /** The hash of a value, consistent with `equal` */
+    def hash(value: Any): Int = value match {
+      case _ if js.Array.isArray(value) =>
+        scala.util.hashing.MurmurHash3.orderedHash(items(value).map(hash))
+      case _ if isObject(value) => scala.util.hashing.MurmurHash3.unorderedHash(
+        members(value).map { case (key, member) => (key, hash(member)) }
+      )
+      case _ => value.##
+    }
+
+    /** A readable representation of a value, in which records and flags are
+      * `(name = value, ...)`, variants are `case(payload)` and lists are `[item, ...]`
+      */
+    def show(value: Any): String = value match {
+      case text: String => "\"" + text + "\""
+      case char: Char => "'" + char + "'"
+      case _ if js.Array.isArray(value) =>
+        items(value).map(show).mkString("[", ", ", "]")
+      case _ if isObject(value) => showMembers(members(value))
+      case _ => value.toString
+    }
+
+    /** A readable representation of the members of an object */
+    private def showMembers(fields: List[(String, Any)]): String = fields match {
+      case List(("tag", tag: String)) => tag
+      case List(("tag", tag: String), ("val", payload)) =>
+        if (isObject(payload) && !members(payload).exists(_._1 == "tag"))
+          tag + show(payload)
+        else
+          tag + "(" + show(payload) + ")"
+      case _ =>
+        fields.map { case (key, member) => key + " = " + show(member) }
+          .mkString("(", ", ", ")")
+    }
+  }
+}